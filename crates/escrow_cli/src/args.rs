@@ -0,0 +1,88 @@
+use crate::error::{usage, CliError};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// `--name value` pairs following a command name
+#[derive(Debug, Default)]
+pub(crate) struct Flags {
+    values: BTreeMap<String, String>,
+}
+
+impl Flags {
+    pub(crate) fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut values = BTreeMap::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| usage(format!("unexpected argument: {arg}")))?;
+            let value = args
+                .next()
+                .ok_or_else(|| usage(format!("missing value for --{name}")))?;
+            if values.insert(name.to_string(), value.clone()).is_some() {
+                return Err(usage(format!("--{name} given more than once")));
+            }
+        }
+        Ok(Flags { values })
+    }
+
+    pub(crate) fn take(&mut self, name: &str) -> Option<String> {
+        self.values.remove(name)
+    }
+
+    pub(crate) fn required(&mut self, name: &str) -> Result<String, CliError> {
+        self.take(name)
+            .ok_or_else(|| usage(format!("missing required flag --{name}")))
+    }
+
+    pub(crate) fn parse_required<T: FromStr>(&mut self, name: &str) -> Result<T, CliError> {
+        let value = self.required(name)?;
+        parse_value(name, &value)
+    }
+
+    pub(crate) fn parse_optional<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, CliError> {
+        self.take(name)
+            .map(|value| parse_value(name, &value))
+            .transpose()
+    }
+
+    /// Fail on flags the command did not consume
+    pub(crate) fn finish(self) -> Result<(), CliError> {
+        match self.values.keys().next() {
+            Some(name) => Err(usage(format!("unknown flag --{name}"))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, CliError> {
+    value
+        .parse()
+        .map_err(|_| usage(format!("invalid value for --{name}: {value}")))
+}
+
+/// Split a scenario line into arguments, honouring double quotes
+#[cfg(feature = "dry-run")]
+pub(crate) fn split_line(line: &str) -> Result<Vec<String>, CliError> {
+    let mut args = Vec::new();
+    let mut current: Option<String> = None;
+    let mut quoted = false;
+
+    for c in line.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !quoted => {
+                args.extend(current.take());
+            }
+            c => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if quoted {
+        return Err(usage(format!("unterminated quote in: {line}")));
+    }
+    args.extend(current);
+    Ok(args)
+}
@@ -0,0 +1,317 @@
+use crate::args::Flags;
+use crate::error::{usage, CliError};
+use devasign_escrow_client::{
+    Address, Config, Difficulty, DisputePayout, DisputeResolution, DisputeTimeoutPolicy,
+    EscrowClient, EscrowOptions, Invocation, Symbol,
+};
+
+/// Commands that invoke a contract entrypoint, with their flags
+pub(crate) const ENTRYPOINT_COMMANDS: &[(&str, &str)] = &[
+    ("initialize", "--admin ADDR --usdc-token ADDR"),
+    ("set-admin", "--new-admin ADDR"),
+    ("pause", "--reason TEXT"),
+    ("unpause", "--reason TEXT"),
+    ("update-token", "--usdc-token ADDR"),
+    ("set-dispute-timeout", "--timeout SECONDS --policy POLICY"),
+    ("set-arbitration-fee", "--fee-bps BPS"),
+    (
+        "update-config",
+        "[--min-amount STROOPS] [--max-amount STROOPS] [--task-id-min-length N] [--task-id-max-length N] [--reason-min-length N] [--reason-max-length N] [--issue-url-max-length N]",
+    ),
+    (
+        "create-escrow",
+        "--creator ADDR --task-id ID --issue-url URL --amount STROOPS [--labels-hash HEX] [--tags TAG,TAG...] [--difficulty LEVEL]",
+    ),
+    (
+        "update-task-metadata",
+        "--creator ADDR --task-id ID --issue-url URL [--labels-hash HEX]",
+    ),
+    (
+        "update-task-tags",
+        "--creator ADDR --task-id ID [--tags TAG,TAG...] [--difficulty LEVEL]",
+    ),
+    (
+        "increase-bounty",
+        "--creator ADDR --task-id ID --amount STROOPS",
+    ),
+    (
+        "decrease-bounty",
+        "--creator ADDR --task-id ID --amount STROOPS",
+    ),
+    (
+        "transfer-task-ownership",
+        "--task-id ID --new-creator ADDR",
+    ),
+    ("accept-task-ownership", "--task-id ID"),
+    ("cancel-task-ownership-transfer", "--task-id ID"),
+    ("assign", "--task-id ID --contributor ADDR"),
+    ("approve", "--task-id ID"),
+    ("dispute", "--party ADDR --task-id ID --reason TEXT"),
+    ("withdraw-dispute", "--party ADDR --task-id ID"),
+    ("resolve", "--task-id ID --resolution RESOLUTION"),
+    ("resolve-stale", "--task-id ID"),
+    ("claim-fees", "--arbiter ADDR"),
+    ("refund", "--task-id ID"),
+    ("upgrade", "--wasm-hash HEX"),
+    ("migrate-escrows", "--task-ids ID,ID..."),
+    ("get-escrow", "--task-id ID"),
+    ("get-escrow-by-issue", "--issue-url URL"),
+    ("list-escrows-by-repo", "--owner NAME --repo NAME [--page N]"),
+    ("list-escrows-by-tag", "--tag TAG [--page N]"),
+    ("get-pending-task-owner", "--task-id ID"),
+    ("get-dispute-info", "--task-id ID"),
+    ("get-dispute-history", "--task-id ID"),
+    ("get-arbiter-balance", "--arbiter ADDR"),
+    ("get-usdc-balance", "--address ADDR"),
+    ("get-contract-balance", ""),
+    ("get-admin", ""),
+    ("get-usdc-token", ""),
+    ("get-dispute-timeout", ""),
+    ("get-arbitration-fee", ""),
+    ("get-config", ""),
+    ("version", ""),
+];
+
+/// Turns an address flag into a strkey address
+pub(crate) type ResolveAddress<'a> = dyn FnMut(&str) -> Result<Address, CliError> + 'a;
+
+/// Build the invocation for an entrypoint command, or `None` for other commands
+pub(crate) fn invocation(
+    client: &EscrowClient,
+    command: &str,
+    flags: &mut Flags,
+    resolve: &mut ResolveAddress,
+) -> Result<Option<Invocation>, CliError> {
+    let mut address = |flags: &mut Flags, name: &str| resolve(&flags.required(name)?);
+
+    let invocation = match command {
+        "initialize" => {
+            client.initialize(&address(flags, "admin")?, &address(flags, "usdc-token")?)
+        }
+        "set-admin" => client.set_admin(&address(flags, "new-admin")?),
+        "pause" => client.set_paused(true, &flags.required("reason")?),
+        "unpause" => client.set_paused(false, &flags.required("reason")?),
+        "update-token" => client.update_usdc_token(&address(flags, "usdc-token")?),
+        "set-dispute-timeout" => client.set_dispute_timeout(
+            flags.parse_required("timeout")?,
+            parse_policy(&flags.required("policy")?)?,
+        )?,
+        "set-arbitration-fee" => client.set_arbitration_fee(flags.parse_required("fee-bps")?)?,
+        "update-config" => client.update_config(&parse_config(flags)?)?,
+        "create-escrow" => {
+            let creator = address(flags, "creator")?;
+            let task_id = flags.required("task-id")?;
+            let issue_url = flags.required("issue-url")?;
+            let amount = flags.parse_required("amount")?;
+            let labels_hash = flags.take("labels-hash");
+            let tags = flags.take("tags");
+            let difficulty = flags.take("difficulty");
+            if labels_hash.is_none() && tags.is_none() && difficulty.is_none() {
+                client.create_escrow(&creator, &task_id, &issue_url, amount)?
+            } else {
+                let options = EscrowOptions {
+                    labels_hash: labels_hash.as_deref().map(parse_hash).transpose()?,
+                    tags: parse_tags(tags.as_deref())?,
+                    difficulty: parse_difficulty(difficulty.as_deref())?,
+                };
+                client
+                    .create_escrow_with_options(&creator, &task_id, &issue_url, amount, &options)?
+            }
+        }
+        "update-task-metadata" => {
+            let creator = address(flags, "creator")?;
+            let task_id = flags.required("task-id")?;
+            let issue_url = flags.required("issue-url")?;
+            let labels_hash = flags
+                .take("labels-hash")
+                .as_deref()
+                .map(parse_hash)
+                .transpose()?;
+            client.update_task_metadata(&creator, &task_id, &issue_url, labels_hash)?
+        }
+        "update-task-tags" => {
+            let creator = address(flags, "creator")?;
+            let task_id = flags.required("task-id")?;
+            let tags = parse_tags(flags.take("tags").as_deref())?;
+            let difficulty = parse_difficulty(flags.take("difficulty").as_deref())?;
+            client.update_task_tags(&creator, &task_id, &tags, difficulty)?
+        }
+        "increase-bounty" => client.increase_bounty(
+            &address(flags, "creator")?,
+            &flags.required("task-id")?,
+            flags.parse_required("amount")?,
+        )?,
+        "decrease-bounty" => client.decrease_bounty(
+            &address(flags, "creator")?,
+            &flags.required("task-id")?,
+            flags.parse_required("amount")?,
+        )?,
+        "transfer-task-ownership" => client.transfer_task_ownership(
+            &flags.required("task-id")?,
+            &address(flags, "new-creator")?,
+        )?,
+        "accept-task-ownership" => client.accept_task_ownership(&flags.required("task-id")?)?,
+        "cancel-task-ownership-transfer" => {
+            client.cancel_task_ownership_transfer(&flags.required("task-id")?)?
+        }
+        "assign" => client
+            .assign_contributor(&flags.required("task-id")?, &address(flags, "contributor")?)?,
+        "approve" => client.approve_completion(&flags.required("task-id")?)?,
+        "dispute" => client.dispute_task(
+            &address(flags, "party")?,
+            &flags.required("task-id")?,
+            &flags.required("reason")?,
+        )?,
+        "withdraw-dispute" => {
+            client.withdraw_dispute(&address(flags, "party")?, &flags.required("task-id")?)?
+        }
+        "resolve" => {
+            let task_id = flags.required("task-id")?;
+            let resolution = parse_resolution(&flags.required("resolution")?, resolve)?;
+            client.resolve_dispute(&task_id, &resolution)?
+        }
+        "resolve-stale" => client.resolve_stale_dispute(&flags.required("task-id")?)?,
+        "claim-fees" => client.claim_arbiter_fees(&address(flags, "arbiter")?),
+        "refund" => client.refund(&flags.required("task-id")?)?,
+        "upgrade" => client.upgrade(parse_hash(&flags.required("wasm-hash")?)?),
+        "migrate-escrows" => {
+            let task_ids = flags.required("task-ids")?;
+            client.migrate_escrows(&task_ids.split(',').collect::<Vec<_>>())?
+        }
+        "get-escrow" => client.get_escrow(&flags.required("task-id")?)?,
+        "get-pending-task-owner" => client.get_pending_task_owner(&flags.required("task-id")?)?,
+        "get-escrow-by-issue" => client.get_escrow_by_issue(&flags.required("issue-url")?)?,
+        "list-escrows-by-repo" => client.list_escrows_by_repo(
+            &flags.required("owner")?,
+            &flags.required("repo")?,
+            flags.parse_optional("page")?.unwrap_or(0),
+        )?,
+        "list-escrows-by-tag" => client.list_escrows_by_tag(
+            &parse_tag(&flags.required("tag")?)?,
+            flags.parse_optional("page")?.unwrap_or(0),
+        )?,
+        "get-dispute-info" => client.get_dispute_info(&flags.required("task-id")?)?,
+        "get-dispute-history" => client.get_dispute_history(&flags.required("task-id")?)?,
+        "get-arbiter-balance" => client.get_arbiter_balance(&address(flags, "arbiter")?),
+        "get-usdc-balance" => client.get_usdc_balance(&address(flags, "address")?),
+        "get-contract-balance" => client.get_contract_usdc_balance(),
+        "get-admin" => client.get_admin(),
+        "get-usdc-token" => client.get_usdc_token(),
+        "get-dispute-timeout" => client.get_dispute_timeout(),
+        "get-arbitration-fee" => client.get_arbitration_fee(),
+        "get-config" => client.get_config(),
+        "version" => client.version(),
+        _ => return Ok(None),
+    };
+    Ok(Some(invocation))
+}
+
+/// Validation limits given as flags, with the defaults for omitted ones
+fn parse_config(flags: &mut Flags) -> Result<Config, CliError> {
+    let defaults = Config::default();
+    Ok(Config {
+        min_amount: flags
+            .parse_optional("min-amount")?
+            .unwrap_or(defaults.min_amount),
+        max_amount: flags
+            .parse_optional("max-amount")?
+            .unwrap_or(defaults.max_amount),
+        task_id_min_length: flags
+            .parse_optional("task-id-min-length")?
+            .unwrap_or(defaults.task_id_min_length),
+        task_id_max_length: flags
+            .parse_optional("task-id-max-length")?
+            .unwrap_or(defaults.task_id_max_length),
+        dispute_reason_min_length: flags
+            .parse_optional("reason-min-length")?
+            .unwrap_or(defaults.dispute_reason_min_length),
+        dispute_reason_max_length: flags
+            .parse_optional("reason-max-length")?
+            .unwrap_or(defaults.dispute_reason_max_length),
+        issue_url_max_length: flags
+            .parse_optional("issue-url-max-length")?
+            .unwrap_or(defaults.issue_url_max_length),
+    })
+}
+
+/// `pay-contributor`, `refund-creator` or `split-evenly`
+fn parse_policy(value: &str) -> Result<DisputeTimeoutPolicy, CliError> {
+    match value {
+        "pay-contributor" => Ok(DisputeTimeoutPolicy::PayContributor),
+        "refund-creator" => Ok(DisputeTimeoutPolicy::RefundCreator),
+        "split-evenly" => Ok(DisputeTimeoutPolicy::SplitEvenly),
+        _ => Err(usage(format!("invalid dispute timeout policy: {value}"))),
+    }
+}
+
+/// `pay-contributor`, `refund-creator`, `partial:AMOUNT`, `split:BPS`,
+/// `split-fee:BPS:FEE` or `multi:ADDR=AMOUNT,ADDR=AMOUNT...`
+fn parse_resolution(
+    value: &str,
+    resolve: &mut ResolveAddress,
+) -> Result<DisputeResolution, CliError> {
+    let invalid = || usage(format!("invalid resolution: {value}"));
+    let number = |value: &str| value.parse().map_err(|_| invalid());
+
+    let (kind, params) = value.split_once(':').unwrap_or((value, ""));
+    let resolution = match (kind, params.split(':').collect::<Vec<_>>().as_slice()) {
+        ("pay-contributor", [""]) => DisputeResolution::PayContributor,
+        ("refund-creator", [""]) => DisputeResolution::RefundCreator,
+        ("partial", [amount]) => DisputeResolution::PartialPayment(number(amount)?),
+        ("split", [bps]) => DisputeResolution::PercentageSplit(bps.parse().map_err(|_| invalid())?),
+        ("split-fee", [bps, fee]) => DisputeResolution::SplitWithArbiterFee(
+            bps.parse().map_err(|_| invalid())?,
+            number(fee)?,
+        ),
+        ("multi", [payouts]) => DisputeResolution::MultiPayout(
+            payouts
+                .split(',')
+                .map(|payout| {
+                    let (recipient, amount) = payout.split_once('=').ok_or_else(invalid)?;
+                    Ok(DisputePayout {
+                        recipient: resolve(recipient)?,
+                        amount: number(amount)?,
+                    })
+                })
+                .collect::<Result<_, CliError>>()?,
+        ),
+        _ => return Err(invalid()),
+    };
+    Ok(resolution)
+}
+
+/// Comma-separated skill tags, none when omitted or empty
+fn parse_tags(value: Option<&str>) -> Result<Vec<Symbol>, CliError> {
+    match value {
+        None | Some("") => Ok(Vec::new()),
+        Some(value) => value.split(',').map(parse_tag).collect(),
+    }
+}
+
+fn parse_tag(value: &str) -> Result<Symbol, CliError> {
+    Symbol::new(value).map_err(|_| usage(format!("invalid tag: {value}")))
+}
+
+/// `unrated`, `beginner`, `intermediate` or `advanced`, unrated when omitted
+fn parse_difficulty(value: Option<&str>) -> Result<Difficulty, CliError> {
+    match value {
+        None | Some("unrated") => Ok(Difficulty::Unrated),
+        Some("beginner") => Ok(Difficulty::Beginner),
+        Some("intermediate") => Ok(Difficulty::Intermediate),
+        Some("advanced") => Ok(Difficulty::Advanced),
+        Some(value) => Err(usage(format!("invalid difficulty: {value}"))),
+    }
+}
+
+fn parse_hash(value: &str) -> Result<[u8; 32], CliError> {
+    let invalid = || usage(format!("invalid hash: {value}"));
+    if value.len() != 64 || !value.is_ascii() {
+        return Err(invalid());
+    }
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(value.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+    }
+    Ok(hash)
+}
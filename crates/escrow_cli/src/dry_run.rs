@@ -0,0 +1,224 @@
+use crate::args::{split_line, Flags};
+use crate::commands;
+use crate::error::{usage, CliError};
+use crate::format;
+use devasign_escrow_client::{
+    xdr::{ScError, ScVal},
+    Address, ClientError, Config, ContractError, EscrowClient, Invocation,
+};
+use devasign_escrow_events::ScValConvert;
+use devasign_task_escrow::TaskEscrowContract;
+use soroban_sdk::{
+    testutils::{Address as _, EnvTestConfig, Ledger},
+    token::StellarAssetClient,
+    Env, Symbol, TryFromVal, Val,
+};
+use std::collections::BTreeMap;
+
+/// Ledger timestamp at the start of every dry run
+pub const DRY_RUN_START_TIME: u64 = 1_700_000_000;
+
+/// USDC minted to every account the first time a scenario names it
+pub const DRY_RUN_BALANCE: i128 = 1000000_0000000;
+
+/// Local contract deployment that scenario steps run against
+///
+/// Authorization is mocked, the contract is initialized with the `admin`
+/// account, and any name that is not a strkey address becomes a funded
+/// account the first time it is used.
+pub(crate) struct DryRun {
+    env: Env,
+    contract_id: soroban_sdk::Address,
+    usdc_token: StellarAssetClient<'static>,
+    client: EscrowClient,
+    accounts: BTreeMap<String, soroban_sdk::Address>,
+}
+
+impl DryRun {
+    pub(crate) fn new() -> Self {
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        env.ledger().set_timestamp(DRY_RUN_START_TIME);
+
+        let admin = soroban_sdk::Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let usdc_token = StellarAssetClient::new(&env, &usdc_address);
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let client = EscrowClient::new(strkey(&env, &contract_id).as_str())
+            .expect("registered contracts have contract addresses");
+
+        let dry_run = DryRun {
+            env,
+            contract_id,
+            usdc_token,
+            client,
+            accounts: BTreeMap::from([("admin".to_string(), admin.clone())]),
+        };
+        let init = dry_run.client.initialize(
+            &strkey(&dry_run.env, &admin),
+            &strkey(&dry_run.env, &usdc_address),
+        );
+        if dry_run.invoke(&init).is_err() {
+            panic!("fresh contract failed to initialize");
+        }
+        dry_run
+    }
+
+    /// Run every line of a scenario and report the outcome of each step
+    pub(crate) fn run_scenario(&mut self, scenario: &str) -> Result<String, CliError> {
+        let mut report = String::new();
+        let mut failures = 0;
+
+        for (index, line) in scenario.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let step = self
+                .run_step(line)
+                .map_err(|err| usage(format!("line {}: {err}", index + 1)))?;
+            if !step.passed {
+                failures += 1;
+            }
+            report.push_str(&format!("{:>4}: {}\n", index + 1, step.summary));
+        }
+
+        if failures > 0 {
+            return Err(CliError::ScenarioFailed { failures, report });
+        }
+        Ok(report)
+    }
+
+    fn run_step(&mut self, line: &str) -> Result<Step, CliError> {
+        let args = split_line(line)?;
+        let (command, rest) = args.split_first().expect("line is not empty");
+        let mut flags = Flags::parse(rest)?;
+        let expected_error = flags
+            .take("expect-error")
+            .map(|name| parse_error_name(&name))
+            .transpose()?;
+
+        if command == "advance-time" {
+            let seconds: u64 = flags.parse_required("seconds")?;
+            flags.finish()?;
+            self.env
+                .ledger()
+                .set_timestamp(self.env.ledger().timestamp() + seconds);
+            return Ok(Step {
+                passed: expected_error.is_none(),
+                summary: format!("advanced time by {seconds}s"),
+            });
+        }
+
+        let client = self.client.clone();
+        let invocation = commands::invocation(&client, command, &mut flags, &mut |name| {
+            Ok(self.resolve(name))
+        });
+        let outcome = match invocation {
+            Ok(Some(invocation)) => {
+                flags.finish()?;
+                let outcome = self.invoke(&invocation);
+                // Later steps are checked against the limits now in force
+                if outcome.is_ok() && invocation.function() == "update_config" {
+                    let config = Config::from_sc_val(&invocation.args()[0])
+                        .expect("update_config takes a Config");
+                    self.client = client.with_config(config);
+                }
+                outcome
+            }
+            Ok(None) => return Err(usage(format!("unknown command: {command}"))),
+            Err(CliError::Client(err)) => Err(StepError::Client(err)),
+            Err(err) => return Err(err),
+        };
+
+        let passed = match &outcome {
+            Ok(_) => expected_error.is_none(),
+            Err(StepError::Client(err)) => {
+                expected_error.is_some() && err.contract_error() == expected_error
+            }
+            Err(StepError::Host(_)) => false,
+        };
+
+        let result = match &outcome {
+            Ok(val) => format!("ok {}", format::sc_val(val)),
+            Err(StepError::Client(err)) => format!("error {err}"),
+            Err(StepError::Host(err)) => format!("host error {err}"),
+        };
+        let summary = match (passed, expected_error) {
+            (true, _) => format!("{command}: {result}"),
+            (false, Some(expected)) => {
+                format!("{command}: {result} [FAILED: expected {expected:?}]")
+            }
+            (false, None) => format!("{command}: {result} [FAILED]"),
+        };
+        Ok(Step { passed, summary })
+    }
+
+    /// Address of a named account, creating and funding it on first use
+    fn resolve(&mut self, name: &str) -> Address {
+        if let Ok(address) = Address::from_strkey(name) {
+            return address;
+        }
+        let account = self
+            .accounts
+            .entry(name.to_string())
+            .or_insert_with(|| {
+                let account = soroban_sdk::Address::generate(&self.env);
+                self.usdc_token.mint(&account, &DRY_RUN_BALANCE);
+                account
+            })
+            .clone();
+        strkey(&self.env, &account)
+    }
+
+    fn invoke(&self, invocation: &Invocation) -> Result<ScVal, StepError> {
+        let args = invocation
+            .args()
+            .iter()
+            .map(|arg| Val::try_from_val(&self.env, arg).expect("argument converts to a Val"))
+            .collect::<Vec<_>>();
+        let result = self.env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &self.contract_id,
+            &Symbol::new(&self.env, invocation.function()),
+            soroban_sdk::Vec::from_slice(&self.env, &args),
+        );
+        match result {
+            Ok(Ok(val)) => Ok(ScVal::try_from_val(&self.env, &val).expect("Val converts to ScVal")),
+            Ok(Err(_)) => unreachable!("any Val converts to a Val"),
+            Err(Ok(err)) => Err(ScError::try_from(err)
+                .ok()
+                .and_then(|err| ClientError::from_sc_error(&err))
+                .map(StepError::Client)
+                .unwrap_or_else(|| StepError::Host(format!("{err:?}")))),
+            Err(Err(err)) => Err(StepError::Host(format!("{err:?}"))),
+        }
+    }
+}
+
+struct Step {
+    passed: bool,
+    summary: String,
+}
+
+/// Why a step did not return a value
+enum StepError {
+    Client(ClientError),
+    Host(String),
+}
+
+fn parse_error_name(name: &str) -> Result<ContractError, CliError> {
+    ContractError::ALL
+        .iter()
+        .copied()
+        .find(|err| format!("{err:?}") == name)
+        .ok_or_else(|| usage(format!("unknown contract error: {name}")))
+}
+
+fn strkey(env: &Env, address: &soroban_sdk::Address) -> Address {
+    let val = ScVal::try_from_val(env, &address.to_val()).expect("address converts to ScVal");
+    Address::from_sc_val(&val).expect("ScVal is an address")
+}
@@ -0,0 +1,47 @@
+use core::fmt;
+use devasign_escrow_client::ClientError;
+
+/// Errors returned by the command-line tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// Command line or scenario line could not be parsed
+    Usage(String),
+    /// Client rejected the input or the contract returned an error
+    Client(ClientError),
+    /// Value could not be decoded from XDR
+    InvalidXdr(String),
+    /// Scenario file could not be read
+    Io(String),
+    /// Scenario steps did not have the expected outcome
+    ScenarioFailed { failures: usize, report: String },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(msg) => write!(f, "{msg}"),
+            CliError::Client(err) => write!(f, "{err}"),
+            CliError::InvalidXdr(msg) => write!(f, "invalid XDR: {msg}"),
+            CliError::Io(msg) => write!(f, "{msg}"),
+            CliError::ScenarioFailed { failures, report } => {
+                write!(
+                    f,
+                    "{failures} scenario step(s) did not match expectations\n{}",
+                    report.trim_end()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<ClientError> for CliError {
+    fn from(err: ClientError) -> Self {
+        CliError::Client(err)
+    }
+}
+
+pub(crate) fn usage(msg: impl Into<String>) -> CliError {
+    CliError::Usage(msg.into())
+}
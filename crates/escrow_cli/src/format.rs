@@ -0,0 +1,66 @@
+use crate::error::{usage, CliError};
+use devasign_escrow_client::{
+    xdr::{Limits, ReadXdr, ScError, ScVal},
+    Address, ContractError,
+};
+use devasign_escrow_events::ScValConvert;
+
+/// Render a contract return value compactly
+pub(crate) fn sc_val(val: &ScVal) -> String {
+    match val {
+        ScVal::Void => "()".into(),
+        ScVal::Bool(value) => value.to_string(),
+        ScVal::U32(value) => value.to_string(),
+        ScVal::I32(value) => value.to_string(),
+        ScVal::U64(value) => value.to_string(),
+        ScVal::I64(value) => value.to_string(),
+        ScVal::I128(_) => i128::from_sc_val(val).expect("ScVal is I128").to_string(),
+        ScVal::String(value) => format!("{:?}", value.to_utf8_string_lossy()),
+        ScVal::Symbol(value) => value.to_utf8_string_lossy(),
+        ScVal::Address(_) => Address::from_sc_val(val)
+            .expect("ScVal is an address")
+            .to_string(),
+        ScVal::Bytes(bytes) => bytes.iter().map(|byte| format!("{byte:02x}")).collect(),
+        ScVal::Vec(Some(items)) => list(items.iter().map(sc_val), "[", "]"),
+        ScVal::Map(Some(entries)) => list(
+            entries
+                .iter()
+                .map(|entry| format!("{}: {}", sc_val(&entry.key), sc_val(&entry.val))),
+            "{",
+            "}",
+        ),
+        ScVal::Error(ScError::Contract(code)) => contract_error(*code),
+        other => serde_json::to_string(other).expect("ScVal serializes to JSON"),
+    }
+}
+
+fn list(items: impl Iterator<Item = String>, open: &str, close: &str) -> String {
+    format!("{open}{}{close}", items.collect::<Vec<_>>().join(", "))
+}
+
+/// Decode a base64 XDR `ScVal`, as returned by simulation or `getTransaction`
+pub(crate) fn decode_result(xdr: &str) -> Result<String, CliError> {
+    let val = ScVal::from_xdr_base64(xdr.trim(), Limits::none())
+        .map_err(|err| CliError::InvalidXdr(err.to_string()))?;
+    Ok(sc_val(&val))
+}
+
+/// Describe a contract error given as `13`, `#13` or `Error(Contract, #13)`
+pub(crate) fn decode_error(value: &str) -> Result<String, CliError> {
+    let code = value
+        .rsplit('#')
+        .next()
+        .unwrap_or(value)
+        .trim_end_matches(')')
+        .trim()
+        .parse()
+        .map_err(|_| usage(format!("invalid error code: {value}")))?;
+    Ok(contract_error(code))
+}
+
+fn contract_error(code: u32) -> String {
+    match ContractError::from_code(code) {
+        Some(err) => format!("{err:?} (#{code}): {}", err.description()),
+        None => format!("unknown contract error #{code}"),
+    }
+}
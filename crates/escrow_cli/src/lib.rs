@@ -0,0 +1,131 @@
+//! Command-line tool for the DevAsign task escrow contract.
+//!
+//! Entrypoint commands build unsigned transaction envelopes offline with the
+//! typed client, `decode-result` and `decode-error` explain what the contract
+//! returned, and `dry-run` executes a scenario file against a local Soroban
+//! test environment when the `dry-run` feature is enabled.
+
+mod args;
+mod commands;
+#[cfg(feature = "dry-run")]
+mod dry_run;
+mod error;
+mod format;
+
+#[cfg(feature = "dry-run")]
+pub use dry_run::{DRY_RUN_BALANCE, DRY_RUN_START_TIME};
+pub use error::CliError;
+
+use args::Flags;
+use devasign_escrow_client::{
+    xdr::{Limits, ReadXdr, ScVal, SorobanAuthorizationEntry, SorobanTransactionData},
+    Address, Config, EscrowClient, TransactionOptions,
+};
+use devasign_escrow_events::ScValConvert;
+use error::usage;
+
+const USAGE: &str = "\
+Usage:
+  devasign_escrow_cli <COMMAND> --contract C... --source G... --sequence N [--fee STROOPS]
+                      [--soroban-data XDR] [--auth XDR,XDR...] [--config XDR] [COMMAND FLAGS]
+      Print the unsigned transaction envelope invoking COMMAND as base64 XDR.
+      --soroban-data and --auth attach the results of simulateTransaction.
+      --config takes the get-config result so input is checked against the
+      deployment's validation limits instead of the defaults.
+  devasign_escrow_cli decode-result <XDR>
+      Render a base64 XDR return value.
+  devasign_escrow_cli decode-error <CODE>
+      Describe a contract error code such as 13 or \"Error(Contract, #13)\".
+  devasign_escrow_cli dry-run <SCENARIO_FILE>
+      Run one command per line against a local deployment. Accounts are
+      referred to by name, `advance-time --seconds N` moves the ledger clock
+      and `--expect-error NAME` marks a step that must fail. Only available
+      when built with the dry-run feature.
+
+Amounts are in stroops. Resolutions are pay-contributor, refund-creator,
+partial:AMOUNT, split:BPS, split-fee:BPS:FEE or multi:ADDR=AMOUNT,...
+Dispute timeout policies are pay-contributor, refund-creator or split-evenly.
+
+Commands:
+";
+
+/// Run the tool with the arguments following the program name
+pub fn run(args: &[String]) -> Result<String, CliError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(usage_text());
+    };
+
+    match command.as_str() {
+        "help" | "--help" | "-h" => Ok(usage_text()),
+        "decode-result" => format::decode_result(single_arg(command, rest)?),
+        "decode-error" => format::decode_error(single_arg(command, rest)?),
+        "dry-run" => dry_run(single_arg(command, rest)?),
+        _ => build_transaction(command, Flags::parse(rest)?),
+    }
+}
+
+#[cfg(feature = "dry-run")]
+fn dry_run(path: &str) -> Result<String, CliError> {
+    let scenario = std::fs::read_to_string(path)
+        .map_err(|err| CliError::Io(format!("cannot read {path}: {err}")))?;
+    dry_run::DryRun::new().run_scenario(&scenario)
+}
+
+#[cfg(not(feature = "dry-run"))]
+fn dry_run(_path: &str) -> Result<String, CliError> {
+    Err(usage(
+        "dry-run is not available in this build; rebuild with --features dry-run",
+    ))
+}
+
+fn build_transaction(command: &str, mut flags: Flags) -> Result<String, CliError> {
+    let mut client = EscrowClient::new(&flags.required("contract")?)?;
+    if let Some(config) = flags.take("config") {
+        let config = Config::from_sc_val(&decode_xdr::<ScVal>(&config)?)
+            .map_err(|err| CliError::InvalidXdr(err.to_string()))?;
+        client = client.with_config(config);
+    }
+    let mut options = TransactionOptions::new(
+        &flags.required("source")?,
+        flags.parse_required("sequence")?,
+    )?;
+    if let Some(fee) = flags.parse_optional("fee")? {
+        options.fee = fee;
+    }
+    if let Some(data) = flags.take("soroban-data") {
+        options.soroban_data = Some(decode_xdr::<SorobanTransactionData>(&data)?);
+    }
+    if let Some(auth) = flags.take("auth") {
+        options.auth = auth
+            .split(',')
+            .map(decode_xdr::<SorobanAuthorizationEntry>)
+            .collect::<Result<_, _>>()?;
+    }
+
+    let invocation = commands::invocation(&client, command, &mut flags, &mut |strkey| {
+        Address::from_strkey(strkey).map_err(|_| usage(format!("invalid address: {strkey}")))
+    })?
+    .ok_or_else(|| usage(format!("unknown command: {command}\n\n{}", usage_text())))?;
+    flags.finish()?;
+
+    Ok(invocation.build_transaction(&options)?.to_base64()?)
+}
+
+fn single_arg<'a>(command: &str, args: &'a [String]) -> Result<&'a str, CliError> {
+    match args {
+        [arg] => Ok(arg),
+        _ => Err(usage(format!("{command} takes exactly one argument"))),
+    }
+}
+
+fn decode_xdr<T: ReadXdr>(xdr: &str) -> Result<T, CliError> {
+    T::from_xdr_base64(xdr, Limits::none()).map_err(|err| CliError::InvalidXdr(err.to_string()))
+}
+
+fn usage_text() -> String {
+    let mut text = USAGE.to_string();
+    for (command, flags) in commands::ENTRYPOINT_COMMANDS {
+        text.push_str(&format!("  {command:<22}{flags}\n"));
+    }
+    text
+}
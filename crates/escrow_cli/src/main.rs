@@ -0,0 +1,19 @@
+//! Build, decode and dry-run DevAsign task escrow transactions.
+//!
+//! Run `devasign_escrow_cli help` for the list of commands.
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match devasign_escrow_cli::run(&args) {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
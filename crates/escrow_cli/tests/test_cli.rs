@@ -0,0 +1,521 @@
+use devasign_escrow_cli::{run, CliError};
+use devasign_escrow_client::{
+    xdr::{HostFunction, Limits, OperationBody, ReadXdr, ScVal, TransactionEnvelope, WriteXdr},
+    Address, ClientError, Config, ContractError, Difficulty, DisputeResolution, EscrowOptions,
+    Symbol,
+};
+use devasign_escrow_events::ScValConvert;
+
+const CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAK3IM";
+const SOURCE: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+const CREATOR: &str = "GBVOL67TMUQBGL4TZYNMY3ZQ5WGQYFPFD5VJRWXR72VA33VFNL225PL5";
+const CONTRIBUTOR: &str = "GBBEEQSCIJBEEQSCIJBEEQSCIJBEEQSCIJBEEQSCIJBEEQSCIJBEFZSP";
+const TASK_ID: &str = "clclitask0000000000000001";
+const SCENARIO: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scenarios/dispute_lifecycle.txt"
+);
+
+fn cli(args: &[&str]) -> Result<String, CliError> {
+    run(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+}
+
+fn build(command: &str, flags: &[&str]) -> Result<String, CliError> {
+    let mut args = vec![
+        command,
+        "--contract",
+        CONTRACT,
+        "--source",
+        SOURCE,
+        "--sequence",
+        "7",
+    ];
+    args.extend(flags);
+    cli(&args)
+}
+
+/// Function name and arguments of the single invocation in an envelope
+fn invocation(envelope: &str) -> (String, Vec<ScVal>) {
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(envelope, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 envelope");
+    };
+    assert!(envelope.signatures.is_empty());
+    assert_eq!(envelope.tx.seq_num.0, 7);
+    let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+        panic!("expected an invoke host function operation");
+    };
+    let HostFunction::InvokeContract(args) = &op.host_function else {
+        panic!("expected a contract invocation");
+    };
+    assert_eq!(args.contract_address.to_string(), CONTRACT);
+    (
+        args.function_name.to_utf8_string_lossy(),
+        args.args.to_vec(),
+    )
+}
+
+fn encode(val: &ScVal) -> String {
+    val.to_xdr_base64(Limits::none()).unwrap()
+}
+
+#[test]
+fn test_build_create_escrow_envelope() {
+    let envelope = build(
+        "create-escrow",
+        &[
+            "--creator",
+            CREATOR,
+            "--task-id",
+            TASK_ID,
+            "--issue-url",
+            "https://github.com/owner/repo/issues/1",
+            "--amount",
+            "1000000000",
+            "--fee",
+            "250000",
+        ],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "create_escrow");
+    assert_eq!(String::from_sc_val(&args[1]).unwrap(), TASK_ID);
+    assert_eq!(i128::from_sc_val(&args[3]).unwrap(), 1000000000);
+
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(&envelope, Limits::none()).unwrap()
+    else {
+        unreachable!();
+    };
+    assert_eq!(envelope.tx.fee, 250000);
+}
+
+#[test]
+fn test_build_create_escrow_with_labels_hash() {
+    let labels_hash = "ab".repeat(32);
+    let envelope = build(
+        "create-escrow",
+        &[
+            "--creator",
+            CREATOR,
+            "--task-id",
+            TASK_ID,
+            "--issue-url",
+            "https://github.com/owner/repo/issues/1",
+            "--amount",
+            "1000000000",
+            "--labels-hash",
+            &labels_hash,
+        ],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "create_escrow_with_options");
+    assert_eq!(
+        EscrowOptions::from_sc_val(&args[4]).unwrap().labels_hash,
+        Some([0xab; 32])
+    );
+}
+
+#[test]
+fn test_build_create_escrow_with_tags() {
+    let envelope = build(
+        "create-escrow",
+        &[
+            "--creator",
+            CREATOR,
+            "--task-id",
+            TASK_ID,
+            "--issue-url",
+            "https://github.com/owner/repo/issues/1",
+            "--amount",
+            "1000000000",
+            "--tags",
+            "rust,wasm",
+            "--difficulty",
+            "advanced",
+        ],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "create_escrow_with_options");
+    let options = EscrowOptions::from_sc_val(&args[4]).unwrap();
+    assert_eq!(options.labels_hash, None);
+    assert_eq!(
+        options.tags,
+        [Symbol::new("rust").unwrap(), Symbol::new("wasm").unwrap()]
+    );
+    assert_eq!(options.difficulty, Difficulty::Advanced);
+
+    assert_eq!(
+        build(
+            "update-task-tags",
+            &["--creator", CREATOR, "--task-id", TASK_ID, "--tags", "Rust"]
+        ),
+        Err(CliError::Client(ClientError::Rejected(
+            ContractError::InvalidTags
+        )))
+    );
+    assert!(matches!(
+        build(
+            "update-task-tags",
+            &[
+                "--creator",
+                CREATOR,
+                "--task-id",
+                TASK_ID,
+                "--difficulty",
+                "expert"
+            ]
+        ),
+        Err(CliError::Usage(_))
+    ));
+}
+
+#[test]
+fn test_build_update_task_metadata() {
+    let labels_hash = "cd".repeat(32);
+    let envelope = build(
+        "update-task-metadata",
+        &[
+            "--creator",
+            CREATOR,
+            "--task-id",
+            TASK_ID,
+            "--issue-url",
+            "https://github.com/owner/repo/issues/2",
+            "--labels-hash",
+            &labels_hash,
+        ],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "update_task_metadata");
+    assert_eq!(
+        String::from_sc_val(&args[2]).unwrap(),
+        "https://github.com/owner/repo/issues/2"
+    );
+    assert_eq!(
+        Option::<[u8; 32]>::from_sc_val(&args[3]).unwrap(),
+        Some([0xcd; 32])
+    );
+}
+
+#[test]
+fn test_build_task_ownership_transfer() {
+    let envelope = build(
+        "transfer-task-ownership",
+        &["--task-id", TASK_ID, "--new-creator", CONTRIBUTOR],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "transfer_task_ownership");
+    assert_eq!(String::from_sc_val(&args[0]).unwrap(), TASK_ID);
+    assert_eq!(
+        Address::from_sc_val(&args[1]).unwrap().to_string(),
+        CONTRIBUTOR
+    );
+
+    let envelope = build("accept-task-ownership", &["--task-id", TASK_ID]).unwrap();
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "accept_task_ownership");
+    assert_eq!(String::from_sc_val(&args[0]).unwrap(), TASK_ID);
+
+    let envelope = build("cancel-task-ownership-transfer", &["--task-id", TASK_ID]).unwrap();
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "cancel_task_ownership_transfer");
+    assert_eq!(String::from_sc_val(&args[0]).unwrap(), TASK_ID);
+}
+
+#[test]
+fn test_build_list_escrows_by_tag() {
+    let envelope = build("list-escrows-by-tag", &["--tag", "rust", "--page", "1"]).unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "list_escrows_by_tag");
+    assert_eq!(Symbol::from_sc_val(&args[0]).unwrap().as_str(), "rust");
+    assert_eq!(u32::from_sc_val(&args[1]).unwrap(), 1);
+}
+
+#[test]
+fn test_build_list_escrows_by_repo() {
+    let envelope = build(
+        "list-escrows-by-repo",
+        &["--owner", "DevAsign", "--repo", "contracts", "--page", "2"],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "list_escrows_by_repo");
+    assert_eq!(String::from_sc_val(&args[0]).unwrap(), "DevAsign");
+    assert_eq!(u32::from_sc_val(&args[2]).unwrap(), 2);
+
+    assert_eq!(
+        build(
+            "list-escrows-by-repo",
+            &["--owner", "dev_asign", "--repo", "contracts"]
+        ),
+        Err(CliError::Client(ClientError::Rejected(
+            ContractError::InvalidRepository
+        )))
+    );
+}
+
+#[test]
+fn test_build_resolve_with_multi_payout() {
+    let envelope = build(
+        "resolve",
+        &[
+            "--task-id",
+            TASK_ID,
+            "--resolution",
+            &format!("multi:{CREATOR}=600,{CONTRIBUTOR}=400"),
+        ],
+    )
+    .unwrap();
+
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "resolve_dispute");
+    let DisputeResolution::MultiPayout(payouts) = DisputeResolution::from_sc_val(&args[1]).unwrap()
+    else {
+        panic!("expected a multi payout");
+    };
+    assert_eq!(payouts.len(), 2);
+    assert_eq!(payouts[0].recipient.as_str(), CREATOR);
+    assert_eq!(payouts[1].amount, 400);
+}
+
+#[test]
+fn test_build_rejects_invalid_input() {
+    assert_eq!(
+        build(
+            "create-escrow",
+            &[
+                "--creator",
+                CREATOR,
+                "--task-id",
+                "cl/short",
+                "--issue-url",
+                "https://github.com/owner/repo/issues/1",
+                "--amount",
+                "1000000000",
+            ],
+        ),
+        Err(CliError::Client(ClientError::Rejected(
+            ContractError::InvalidTaskIdCharacters
+        )))
+    );
+    assert_eq!(
+        build("set-arbitration-fee", &["--fee-bps", "5000"]),
+        Err(CliError::Client(ClientError::Rejected(
+            ContractError::InvalidBasisPoints
+        )))
+    );
+    assert_eq!(
+        build("assign", &["--task-id", TASK_ID, "--contributor", "bob"]),
+        Err(CliError::Usage("invalid address: bob".into()))
+    );
+    assert_eq!(
+        build("approve", &[]),
+        Err(CliError::Usage("missing required flag --task-id".into()))
+    );
+    assert_eq!(
+        build("approve", &["--task-id", TASK_ID, "--amount", "1"]),
+        Err(CliError::Usage("unknown flag --amount".into()))
+    );
+    assert_eq!(
+        build("resolve", &["--task-id", TASK_ID, "--resolution", "split"]),
+        Err(CliError::Usage("invalid resolution: split".into()))
+    );
+    assert!(matches!(
+        build("launch", &[]),
+        Err(CliError::Usage(msg)) if msg.starts_with("unknown command: launch")
+    ));
+    assert_eq!(
+        cli(&[
+            "version",
+            "--contract",
+            SOURCE,
+            "--source",
+            SOURCE,
+            "--sequence",
+            "1"
+        ]),
+        Err(CliError::Client(ClientError::InvalidAddress(SOURCE.into())))
+    );
+}
+
+#[test]
+fn test_build_update_config_and_check_custom_limits() {
+    let envelope = build(
+        "update-config",
+        &["--task-id-min-length", "8", "--task-id-max-length", "32"],
+    )
+    .unwrap();
+    let (function, args) = invocation(&envelope);
+    assert_eq!(function, "update_config");
+    let config = Config::from_sc_val(&args[0]).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            task_id_min_length: 8,
+            task_id_max_length: 32,
+            ..Config::default()
+        }
+    );
+
+    assert_eq!(
+        build("update-config", &["--min-amount", "0"]),
+        Err(CliError::Client(ClientError::Rejected(
+            ContractError::InvalidConfig
+        )))
+    );
+
+    // New task IDs outside the default length pass once the deployed limits are given
+    let create = |config: Option<&str>| {
+        let mut args = vec![
+            "--creator",
+            CREATOR,
+            "--task-id",
+            "clcustom01",
+            "--issue-url",
+            "https://github.com/owner/repo/issues/1",
+            "--amount",
+            "1000000000",
+        ];
+        if let Some(config) = config {
+            args.extend(["--config", config]);
+        }
+        build("create-escrow", &args)
+    };
+    assert_eq!(
+        create(None),
+        Err(CliError::Client(ClientError::Rejected(
+            ContractError::TaskIdTooShort
+        )))
+    );
+    let config = encode(&config.to_sc_val());
+    let envelope = create(Some(&config)).unwrap();
+    assert_eq!(invocation(&envelope).0, "create_escrow");
+
+    // Existing tasks are looked up whatever their length
+    let envelope = build("approve", &["--task-id", "clcustom01"]).unwrap();
+    assert_eq!(invocation(&envelope).0, "approve_completion");
+    assert!(matches!(
+        build("approve", &["--config", "AAAA", "--task-id", TASK_ID]),
+        Err(CliError::InvalidXdr(_))
+    ));
+}
+
+#[test]
+fn test_decode_error() {
+    let expected = "NotAdmin (#13): the caller is not the contract admin";
+    assert_eq!(cli(&["decode-error", "13"]).unwrap(), expected);
+    assert_eq!(cli(&["decode-error", "#13"]).unwrap(), expected);
+    assert_eq!(
+        cli(&["decode-error", "Error(Contract, #13)"]).unwrap(),
+        expected
+    );
+    assert_eq!(
+        cli(&["decode-error", "99"]).unwrap(),
+        "unknown contract error #99"
+    );
+    assert!(matches!(
+        cli(&["decode-error", "oops"]),
+        Err(CliError::Usage(_))
+    ));
+}
+
+#[test]
+fn test_decode_result() {
+    assert_eq!(
+        cli(&["decode-result", &encode(&1000000000i128.to_sc_val())]).unwrap(),
+        "1000000000"
+    );
+    let resolution = DisputeResolution::SplitWithArbiterFee(5000, 100);
+    assert_eq!(
+        cli(&["decode-result", &encode(&resolution.to_sc_val())]).unwrap(),
+        "[SplitWithArbiterFee, 5000, 100]"
+    );
+    assert!(matches!(
+        cli(&["decode-result", "not xdr"]),
+        Err(CliError::InvalidXdr(_))
+    ));
+}
+
+#[test]
+fn test_dry_run_scenario() {
+    let report = cli(&["dry-run", SCENARIO]).unwrap();
+    assert!(!report.contains("FAILED"));
+    assert!(report.contains("claim-fees: ok 30000000"));
+    assert!(report.contains(
+        "resolve-stale: error contract error: the dispute timeout has not elapsed (#27)"
+    ));
+}
+
+#[test]
+fn test_dry_run_reports_unexpected_outcomes() {
+    let path = std::env::temp_dir().join("devasign_escrow_cli_unexpected.txt");
+    std::fs::write(
+        &path,
+        "approve --task-id clclitask0000000000000001\n\
+         get-admin --expect-error NotAdmin\n\
+         refund --task-id clclitask0000000000000001 --expect-error TaskNotFound\n",
+    )
+    .unwrap();
+
+    let Err(CliError::ScenarioFailed { failures, report }) =
+        cli(&["dry-run", path.to_str().unwrap()])
+    else {
+        panic!("expected the scenario to fail");
+    };
+    assert_eq!(failures, 2);
+    assert!(report
+        .contains("1: approve: error contract error: no escrow exists for the task (#1) [FAILED]"));
+    assert!(report.contains("get-admin: ok "));
+    assert!(report.contains("[FAILED: expected NotAdmin]"));
+    assert!(!report
+        .contains("refund: error contract error: no escrow exists for the task (#1) [FAILED"));
+}
+
+#[test]
+fn test_dry_run_rejects_malformed_lines() {
+    let path = std::env::temp_dir().join("devasign_escrow_cli_malformed.txt");
+    std::fs::write(&path, "# comment\n\ndispute --reason \"unterminated\n").unwrap();
+    assert_eq!(
+        cli(&["dry-run", path.to_str().unwrap()]),
+        Err(CliError::Usage(
+            "line 3: unterminated quote in: dispute --reason \"unterminated".into()
+        ))
+    );
+
+    assert!(matches!(
+        cli(&["dry-run", "/nonexistent/scenario.txt"]),
+        Err(CliError::Io(_))
+    ));
+}
+
+#[test]
+fn test_help_lists_entrypoint_commands() {
+    let help = cli(&[]).unwrap();
+    assert_eq!(cli(&["help"]).unwrap(), help);
+    for command in [
+        "create-escrow",
+        "assign",
+        "approve",
+        "dispute",
+        "resolve",
+        "refund",
+    ] {
+        assert!(
+            help.contains(&format!("\n  {command} ")),
+            "{command} missing"
+        );
+    }
+}
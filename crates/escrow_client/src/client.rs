@@ -0,0 +1,420 @@
+use crate::error::ClientError;
+use crate::transaction::Invocation;
+use crate::validation;
+use devasign_escrow_events::{
+    xdr::{ScAddress, ScVal},
+    Address, Config, Difficulty, DisputeResolution, DisputeTimeoutPolicy, EscrowOptions,
+    ScValConvert, Symbol,
+};
+
+/// Typed client for the task escrow contract
+///
+/// Every method validates its input with the contract's own rules and
+/// returns the encoded call, ready to be simulated or wrapped in a
+/// transaction with [`Invocation::build_transaction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscrowClient {
+    contract_id: Address,
+    config: Config,
+}
+
+impl EscrowClient {
+    /// Client for the contract deployed at `contract_id` (`C...`)
+    pub fn new(contract_id: &str) -> Result<Self, ClientError> {
+        match contract_id.parse::<ScAddress>() {
+            Ok(ScAddress::Contract(_)) => Ok(EscrowClient {
+                contract_id: Address::from_strkey(contract_id)
+                    .map_err(|_| ClientError::InvalidAddress(contract_id.into()))?,
+                config: Config::default(),
+            }),
+            _ => Err(ClientError::InvalidAddress(contract_id.into())),
+        }
+    }
+
+    /// Validate with the limits returned by the contract's `get_config`
+    /// instead of the defaults set on initialization
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn contract_id(&self) -> &Address {
+        &self.contract_id
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn initialize(&self, admin: &Address, usdc_token: &Address) -> Invocation {
+        self.call(
+            "initialize",
+            vec![admin.to_sc_val(), usdc_token.to_sc_val()],
+        )
+    }
+
+    pub fn set_admin(&self, new_admin: &Address) -> Invocation {
+        self.call("set_admin", vec![new_admin.to_sc_val()])
+    }
+
+    pub fn set_paused(&self, paused: bool, reason: &str) -> Invocation {
+        self.call("set_paused", vec![paused.to_sc_val(), string(reason)])
+    }
+
+    pub fn update_usdc_token(&self, new_usdc_token: &Address) -> Invocation {
+        self.call("update_usdc_token", vec![new_usdc_token.to_sc_val()])
+    }
+
+    pub fn set_dispute_timeout(
+        &self,
+        timeout: u64,
+        policy: DisputeTimeoutPolicy,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_dispute_timeout(timeout)?;
+        Ok(self.call(
+            "set_dispute_timeout",
+            vec![timeout.to_sc_val(), policy.to_sc_val()],
+        ))
+    }
+
+    pub fn get_dispute_timeout(&self) -> Invocation {
+        self.call("get_dispute_timeout", vec![])
+    }
+
+    pub fn set_arbitration_fee(&self, fee_basis_points: u32) -> Result<Invocation, ClientError> {
+        validation::validate_arbitration_fee(fee_basis_points)?;
+        Ok(self.call("set_arbitration_fee", vec![fee_basis_points.to_sc_val()]))
+    }
+
+    pub fn get_arbitration_fee(&self) -> Invocation {
+        self.call("get_arbitration_fee", vec![])
+    }
+
+    pub fn update_config(&self, config: &Config) -> Result<Invocation, ClientError> {
+        validation::validate_config(config)?;
+        Ok(self.call("update_config", vec![config.to_sc_val()]))
+    }
+
+    pub fn get_config(&self) -> Invocation {
+        self.call("get_config", vec![])
+    }
+
+    pub fn get_admin(&self) -> Invocation {
+        self.call("get_admin", vec![])
+    }
+
+    pub fn get_usdc_token(&self) -> Invocation {
+        self.call("get_usdc_token", vec![])
+    }
+
+    pub fn create_escrow(
+        &self,
+        creator: &Address,
+        task_id: &str,
+        issue_url: &str,
+        bounty_amount: i128,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_participant(creator, &self.contract_id)?;
+        validation::validate_new_task_id(task_id, &self.config)?;
+        validation::validate_issue_url(issue_url, &self.config)?;
+        validation::validate_amount(bounty_amount, &self.config)?;
+        Ok(self.call(
+            "create_escrow",
+            vec![
+                creator.to_sc_val(),
+                string(task_id),
+                string(issue_url),
+                bounty_amount.to_sc_val(),
+            ],
+        ))
+    }
+
+    pub fn create_escrow_with_options(
+        &self,
+        creator: &Address,
+        task_id: &str,
+        issue_url: &str,
+        bounty_amount: i128,
+        options: &EscrowOptions,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_participant(creator, &self.contract_id)?;
+        validation::validate_new_task_id(task_id, &self.config)?;
+        validation::validate_issue_url(issue_url, &self.config)?;
+        validation::validate_amount(bounty_amount, &self.config)?;
+        validation::validate_tags(&options.tags)?;
+        Ok(self.call(
+            "create_escrow_with_options",
+            vec![
+                creator.to_sc_val(),
+                string(task_id),
+                string(issue_url),
+                bounty_amount.to_sc_val(),
+                options.to_sc_val(),
+            ],
+        ))
+    }
+
+    pub fn get_escrow(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("get_escrow", task_id)
+    }
+
+    pub fn get_escrow_by_issue(&self, issue_url: &str) -> Result<Invocation, ClientError> {
+        validation::validate_issue_url(issue_url, &self.config)?;
+        Ok(self.call("get_escrow_by_issue", vec![string(issue_url)]))
+    }
+
+    pub fn list_escrows_by_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_repository(owner, repo)?;
+        Ok(self.call(
+            "list_escrows_by_repo",
+            vec![string(owner), string(repo), page.to_sc_val()],
+        ))
+    }
+
+    pub fn list_escrows_by_tag(&self, tag: &Symbol, page: u32) -> Result<Invocation, ClientError> {
+        validation::validate_tag(tag)?;
+        Ok(self.call(
+            "list_escrows_by_tag",
+            vec![tag.to_sc_val(), page.to_sc_val()],
+        ))
+    }
+
+    pub fn update_task_metadata(
+        &self,
+        creator: &Address,
+        task_id: &str,
+        issue_url: &str,
+        labels_hash: Option<[u8; 32]>,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_issue_url(issue_url, &self.config)?;
+        validation::validate_task_id(task_id)?;
+        Ok(self.call(
+            "update_task_metadata",
+            vec![
+                creator.to_sc_val(),
+                string(task_id),
+                string(issue_url),
+                labels_hash.to_sc_val(),
+            ],
+        ))
+    }
+
+    pub fn update_task_tags(
+        &self,
+        creator: &Address,
+        task_id: &str,
+        tags: &[Symbol],
+        difficulty: Difficulty,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_tags(tags)?;
+        validation::validate_task_id(task_id)?;
+        Ok(self.call(
+            "update_task_tags",
+            vec![
+                creator.to_sc_val(),
+                string(task_id),
+                tags.to_vec().to_sc_val(),
+                difficulty.to_sc_val(),
+            ],
+        ))
+    }
+
+    pub fn increase_bounty(
+        &self,
+        creator: &Address,
+        task_id: &str,
+        amount: i128,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_bounty_adjustment(amount)?;
+        validation::validate_task_id(task_id)?;
+        Ok(self.call(
+            "increase_bounty",
+            vec![creator.to_sc_val(), string(task_id), amount.to_sc_val()],
+        ))
+    }
+
+    pub fn decrease_bounty(
+        &self,
+        creator: &Address,
+        task_id: &str,
+        amount: i128,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_bounty_adjustment(amount)?;
+        validation::validate_task_id(task_id)?;
+        Ok(self.call(
+            "decrease_bounty",
+            vec![creator.to_sc_val(), string(task_id), amount.to_sc_val()],
+        ))
+    }
+
+    pub fn assign_contributor(
+        &self,
+        task_id: &str,
+        contributor: &Address,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_task_id(task_id)?;
+        validation::validate_participant(contributor, &self.contract_id)?;
+        Ok(self.call(
+            "assign_contributor",
+            vec![string(task_id), contributor.to_sc_val()],
+        ))
+    }
+
+    pub fn transfer_task_ownership(
+        &self,
+        task_id: &str,
+        new_creator: &Address,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_task_id(task_id)?;
+        validation::validate_participant(new_creator, &self.contract_id)?;
+        Ok(self.call(
+            "transfer_task_ownership",
+            vec![string(task_id), new_creator.to_sc_val()],
+        ))
+    }
+
+    pub fn accept_task_ownership(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("accept_task_ownership", task_id)
+    }
+
+    pub fn cancel_task_ownership_transfer(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("cancel_task_ownership_transfer", task_id)
+    }
+
+    pub fn get_pending_task_owner(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("get_pending_task_owner", task_id)
+    }
+
+    pub fn approve_completion(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("approve_completion", task_id)
+    }
+
+    pub fn dispute_task(
+        &self,
+        disputing_party: &Address,
+        task_id: &str,
+        reason: &str,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_task_id(task_id)?;
+        validation::validate_address(disputing_party)?;
+        validation::validate_dispute_reason(reason, &self.config)?;
+        Ok(self.call(
+            "dispute_task",
+            vec![disputing_party.to_sc_val(), string(task_id), string(reason)],
+        ))
+    }
+
+    pub fn withdraw_dispute(
+        &self,
+        party: &Address,
+        task_id: &str,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_task_id(task_id)?;
+        validation::validate_address(party)?;
+        Ok(self.call("withdraw_dispute", vec![party.to_sc_val(), string(task_id)]))
+    }
+
+    pub fn resolve_dispute(
+        &self,
+        task_id: &str,
+        resolution: &DisputeResolution,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_task_id(task_id)?;
+        if let DisputeResolution::MultiPayout(payouts) = resolution {
+            for payout in payouts {
+                validation::validate_participant(&payout.recipient, &self.contract_id)?;
+            }
+        }
+        Ok(self.call(
+            "resolve_dispute",
+            vec![string(task_id), resolution.to_sc_val()],
+        ))
+    }
+
+    pub fn resolve_stale_dispute(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("resolve_stale_dispute", task_id)
+    }
+
+    pub fn claim_arbiter_fees(&self, arbiter: &Address) -> Invocation {
+        self.call("claim_arbiter_fees", vec![arbiter.to_sc_val()])
+    }
+
+    pub fn get_arbiter_balance(&self, arbiter: &Address) -> Invocation {
+        self.call("get_arbiter_balance", vec![arbiter.to_sc_val()])
+    }
+
+    pub fn refund(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("refund", task_id)
+    }
+
+    pub fn get_dispute_info(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("get_dispute_info", task_id)
+    }
+
+    pub fn get_dispute_history(&self, task_id: &str) -> Result<Invocation, ClientError> {
+        self.task_call("get_dispute_history", task_id)
+    }
+
+    pub fn get_usdc_balance(&self, address: &Address) -> Invocation {
+        self.call("get_usdc_balance", vec![address.to_sc_val()])
+    }
+
+    pub fn has_sufficient_usdc_balance(
+        &self,
+        address: &Address,
+        required_amount: i128,
+    ) -> Result<Invocation, ClientError> {
+        validation::validate_amount(required_amount, &self.config)?;
+        Ok(self.call(
+            "has_sufficient_usdc_balance",
+            vec![address.to_sc_val(), required_amount.to_sc_val()],
+        ))
+    }
+
+    pub fn get_contract_usdc_balance(&self) -> Invocation {
+        self.call("get_contract_usdc_balance", vec![])
+    }
+
+    pub fn validate_usdc_token_contract(&self) -> Invocation {
+        self.call("validate_usdc_token_contract", vec![])
+    }
+
+    pub fn get_usdc_token_info(&self) -> Invocation {
+        self.call("get_usdc_token_info", vec![])
+    }
+
+    pub fn upgrade(&self, new_wasm_hash: [u8; 32]) -> Invocation {
+        self.call("upgrade", vec![new_wasm_hash.to_sc_val()])
+    }
+
+    pub fn migrate_escrows(&self, task_ids: &[&str]) -> Result<Invocation, ClientError> {
+        let mut ids = Vec::with_capacity(task_ids.len());
+        for task_id in task_ids {
+            validation::validate_task_id(task_id)?;
+            ids.push(task_id.to_string());
+        }
+        Ok(self.call("migrate_escrows", vec![ids.to_sc_val()]))
+    }
+
+    pub fn version(&self) -> Invocation {
+        self.call("version", vec![])
+    }
+
+    fn call(&self, function: &'static str, args: Vec<ScVal>) -> Invocation {
+        Invocation::new(&self.contract_id, function, args)
+    }
+
+    fn task_call(&self, function: &'static str, task_id: &str) -> Result<Invocation, ClientError> {
+        validation::validate_task_id(task_id)?;
+        Ok(self.call(function, vec![string(task_id)]))
+    }
+}
+
+fn string(value: &str) -> ScVal {
+    value.to_string().to_sc_val()
+}
@@ -0,0 +1,165 @@
+use core::fmt;
+use stellar_xdr::curr::ScError;
+
+/// Declare the mirror of the contract's `Error` together with a description
+/// of every code.
+macro_rules! contract_errors {
+    ($($variant:ident = $code:literal => $description:literal,)*) => {
+        /// Mirror of the contract's `Error`
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(u32)]
+        pub enum ContractError {
+            $($variant = $code,)*
+        }
+
+        impl ContractError {
+            /// Every error the contract can return, in code order
+            pub const ALL: &'static [ContractError] = &[$(ContractError::$variant,)*];
+
+            /// Look up the error with the given contract error code
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some(ContractError::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Contract error code
+            pub fn code(self) -> u32 {
+                self as u32
+            }
+
+            /// Human readable description of the error
+            pub fn description(self) -> &'static str {
+                match self {
+                    $(ContractError::$variant => $description,)*
+                }
+            }
+        }
+    };
+}
+
+contract_errors! {
+    // General errors (1-9)
+    TaskNotFound = 1 => "no escrow exists for the task",
+    TaskAlreadyExists = 2 => "an escrow already exists for the task",
+    InvalidTaskStatus = 3 => "the task is not in a status that allows this action",
+    ContractNotInitialized = 4 => "the contract has not been initialized",
+    AlreadyInitialized = 5 => "the contract has already been initialized",
+
+    // Permission errors (10-19)
+    Unauthorized = 10 => "the caller is not authorized",
+    NotTaskCreator = 11 => "the caller is not the task creator",
+    NotTaskContributor = 12 => "the caller is not the task contributor",
+    NotAdmin = 13 => "the caller is not the contract admin",
+    OnlyCreatorOrContributor = 14 => "only the task creator or contributor may do this",
+    NotDisputingParty = 15 => "the caller did not open the dispute",
+
+    // Business logic errors (20-29)
+    ContributorAlreadyAssigned = 20 => "a contributor is already assigned to the task",
+    NoContributorAssigned = 21 => "no contributor is assigned to the task",
+    InsufficientBalance = 22 => "the USDC balance is insufficient",
+    TaskNotCompleted = 23 => "the task has not been completed",
+    TaskNotDisputed = 24 => "the task is not disputed",
+    TaskAlreadyResolved = 25 => "the task has already been resolved",
+    CannotRefundWithContributor = 26 => "a task with an assigned contributor cannot be refunded",
+    DisputeNotExpired = 27 => "the dispute timeout has not elapsed",
+    NoFeesToClaim = 28 => "the arbiter has no fees to claim",
+    IssueAlreadyEscrowed = 29 => "the issue is already funded by an active escrow",
+
+    // Token errors (30-39)
+    TokenTransferFailed = 30 => "the USDC transfer failed",
+    InvalidTokenAmount = 31 => "the token amount is out of range",
+    TokenContractNotSet = 32 => "the USDC token contract is not set",
+
+    // Validation errors (40-49)
+    InvalidTaskId = 40 => "the task ID is invalid",
+    InvalidAddress = 41 => "the address is not allowed",
+    InvalidAmount = 42 => "the amount must be positive",
+    InvalidDisputeReason = 43 => "the dispute reason is longer than the configured maximum",
+    EmptyTaskId = 44 => "the task ID is empty",
+    TaskIdTooShort = 45 => "the task ID is shorter than the configured minimum",
+    TaskIdTooLong = 46 => "the task ID is longer than the configured maximum",
+    InvalidTaskIdCharacters = 47 => "the task ID may only contain letters, digits, '-' and '_'",
+    AmountTooSmall = 48 => "the amount is below the configured minimum",
+    DisputeReasonTooShort = 49 => "the dispute reason is shorter than the configured minimum",
+    InvalidIssueUrl = 50 => "the issue URL must be a GitHub issue link within the configured length",
+    ContractPaused = 51 => "the contract is paused",
+    InvalidDisputeTimeout = 52 => "the dispute timeout must be non-zero",
+    InvalidBasisPoints = 53 => "the basis points are out of range",
+    InvalidPayoutSplit = 54 => "the payouts do not add up to the bounty",
+    InvalidConfig = 55 => "the validation limits are inconsistent",
+    InvalidRepository = 56 => "the owner or repository name is not a valid GitHub name",
+    InvalidTags = 57 => "the skill tags must be at most 5 distinct lowercase symbols of up to 9 characters",
+    NoPendingOwnershipTransfer = 58 => "no new creator has been nominated for the task",
+}
+
+impl fmt::Display for ContractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (#{})", self.description(), self.code())
+    }
+}
+
+impl std::error::Error for ContractError {}
+
+/// Errors returned by the escrow client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientError {
+    /// Input was rejected before submission with the error the contract would return
+    Rejected(ContractError),
+    /// Contract returned an error
+    Contract(ContractError),
+    /// Contract returned an error code this crate does not know
+    UnknownContractError(u32),
+    /// Value is not a valid strkey address for its role
+    InvalidAddress(String),
+    /// Transaction could not be encoded as XDR
+    InvalidXdr(String),
+}
+
+impl ClientError {
+    /// Map an error code returned by the contract
+    pub fn from_contract_code(code: u32) -> Self {
+        ContractError::from_code(code)
+            .map(ClientError::Contract)
+            .unwrap_or(ClientError::UnknownContractError(code))
+    }
+
+    /// Map a contract error raised during an invocation; host errors yield `None`
+    pub fn from_sc_error(error: &ScError) -> Option<Self> {
+        match error {
+            ScError::Contract(code) => Some(Self::from_contract_code(*code)),
+            _ => None,
+        }
+    }
+
+    /// Contract error behind a rejection or a failed invocation
+    pub fn contract_error(&self) -> Option<ContractError> {
+        match self {
+            ClientError::Rejected(err) | ClientError::Contract(err) => Some(*err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Rejected(err) => write!(f, "rejected before submission: {err}"),
+            ClientError::Contract(err) => write!(f, "contract error: {err}"),
+            ClientError::UnknownContractError(code) => {
+                write!(f, "unknown contract error code: {code}")
+            }
+            ClientError::InvalidAddress(address) => write!(f, "invalid address: {address}"),
+            ClientError::InvalidXdr(msg) => write!(f, "invalid XDR: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ContractError> for ClientError {
+    fn from(err: ContractError) -> Self {
+        ClientError::Rejected(err)
+    }
+}
@@ -0,0 +1,24 @@
+//! Typed client for the DevAsign task escrow contract.
+//!
+//! [`EscrowClient`] encodes the arguments of every contract function after
+//! checking them with the same rules the contract applies, so invalid input
+//! is rejected before anything is simulated or submitted. The resulting
+//! [`Invocation`] can be wrapped in an unsigned transaction envelope for
+//! offline signing, and error codes returned by the contract map to
+//! [`ContractError`].
+
+mod client;
+mod error;
+mod transaction;
+pub mod validation;
+
+pub use client::EscrowClient;
+pub use devasign_escrow_events::{
+    xdr, Address, Config, Difficulty, DisputePayout, DisputeResolution, DisputeTimeoutPolicy,
+    EscrowOptions, Symbol,
+};
+pub use error::{ClientError, ContractError};
+pub use transaction::{
+    network_id, Invocation, TransactionOptions, UnsignedTransaction, BASE_FEE, PUBLIC_PASSPHRASE,
+    TESTNET_PASSPHRASE,
+};
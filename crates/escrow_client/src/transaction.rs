@@ -0,0 +1,178 @@
+use crate::error::ClientError;
+use devasign_escrow_events::{xdr, Address, ScValConvert};
+use sha2::{Digest, Sha256};
+use xdr::{
+    HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount, Operation,
+    OperationBody, Preconditions, ScSymbol, ScVal, SequenceNumber, SorobanAuthorizationEntry,
+    SorobanTransactionData, TimeBounds, TimePoint, Transaction, TransactionEnvelope,
+    TransactionExt, TransactionV1Envelope, WriteXdr,
+};
+
+/// Network passphrase of the Stellar test network
+pub const TESTNET_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+/// Network passphrase of the Stellar public network
+pub const PUBLIC_PASSPHRASE: &str = "Public Global Stellar Network ; September 2015";
+
+/// Minimum inclusion fee of a transaction in stroops
+pub const BASE_FEE: u32 = 100;
+
+/// Call of a single contract function with its encoded arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    contract_id: Address,
+    function: &'static str,
+    args: Vec<ScVal>,
+}
+
+impl Invocation {
+    pub(crate) fn new(contract_id: &Address, function: &'static str, args: Vec<ScVal>) -> Self {
+        Invocation {
+            contract_id: contract_id.clone(),
+            function,
+            args,
+        }
+    }
+
+    pub fn contract_id(&self) -> &Address {
+        &self.contract_id
+    }
+
+    pub fn function(&self) -> &'static str {
+        self.function
+    }
+
+    pub fn args(&self) -> &[ScVal] {
+        &self.args
+    }
+
+    /// Encode the call as `InvokeContractArgs`
+    pub fn to_invoke_contract_args(&self) -> Result<InvokeContractArgs, ClientError> {
+        let contract_address = match self.contract_id.to_sc_val() {
+            ScVal::Address(address) => address,
+            _ => unreachable!("addresses encode as ScVal::Address"),
+        };
+        Ok(InvokeContractArgs {
+            contract_address,
+            function_name: ScSymbol(self.function.try_into().map_err(xdr_error)?),
+            args: self.args.clone().try_into().map_err(xdr_error)?,
+        })
+    }
+
+    /// Build an unsigned transaction containing only this call
+    ///
+    /// Soroban transactions must carry resource data and authorization
+    /// entries obtained from `simulateTransaction` before they are accepted;
+    /// pass them through `options` once known.
+    pub fn build_transaction(
+        &self,
+        options: &TransactionOptions,
+    ) -> Result<UnsignedTransaction, ClientError> {
+        let operation = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(self.to_invoke_contract_args()?),
+                auth: options.auth.clone().try_into().map_err(xdr_error)?,
+            }),
+        };
+        let cond = match options.time_bounds {
+            Some((min_time, max_time)) => Preconditions::Time(TimeBounds {
+                min_time: TimePoint(min_time),
+                max_time: TimePoint(max_time),
+            }),
+            None => Preconditions::None,
+        };
+        let ext = match &options.soroban_data {
+            Some(data) => TransactionExt::V1(data.clone()),
+            None => TransactionExt::V0,
+        };
+
+        let tx = Transaction {
+            source_account: options.source_account.clone(),
+            fee: options.fee,
+            seq_num: SequenceNumber(options.sequence),
+            cond,
+            memo: Memo::None,
+            operations: vec![operation].try_into().map_err(xdr_error)?,
+            ext,
+        };
+        Ok(UnsignedTransaction {
+            envelope: TransactionEnvelope::Tx(TransactionV1Envelope {
+                tx,
+                signatures: Default::default(),
+            }),
+        })
+    }
+}
+
+/// Source account, sequence and fee settings of a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionOptions {
+    pub source_account: MuxedAccount,
+    /// Sequence number of the transaction, one more than the account's current sequence
+    pub sequence: i64,
+    /// Total fee in stroops, including the resource fee reported by simulation
+    pub fee: u32,
+    /// Earliest and latest ledger close time, in seconds, at which the transaction is valid
+    pub time_bounds: Option<(u64, u64)>,
+    /// Resource footprint and fee reported by simulation
+    pub soroban_data: Option<SorobanTransactionData>,
+    /// Authorization entries reported by simulation
+    pub auth: Vec<SorobanAuthorizationEntry>,
+}
+
+impl TransactionOptions {
+    /// Options for a transaction sent by `source_account` (`G...` or `M...`)
+    pub fn new(source_account: &str, sequence: i64) -> Result<Self, ClientError> {
+        let source_account = source_account
+            .parse()
+            .map_err(|_| ClientError::InvalidAddress(source_account.into()))?;
+        Ok(TransactionOptions {
+            source_account,
+            sequence,
+            fee: BASE_FEE,
+            time_bounds: None,
+            soroban_data: None,
+            auth: Vec::new(),
+        })
+    }
+}
+
+/// Transaction envelope without signatures, ready for offline signing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedTransaction {
+    envelope: TransactionEnvelope,
+}
+
+impl UnsignedTransaction {
+    pub fn envelope(&self) -> &TransactionEnvelope {
+        &self.envelope
+    }
+
+    pub fn into_envelope(self) -> TransactionEnvelope {
+        self.envelope
+    }
+
+    /// Encode the envelope as base64 XDR
+    pub fn to_base64(&self) -> Result<String, ClientError> {
+        self.envelope
+            .to_xdr_base64(Limits::none())
+            .map_err(xdr_error)
+    }
+
+    /// Hash that signers sign for the network with the given passphrase
+    pub fn hash(&self, network_passphrase: &str) -> Result<[u8; 32], ClientError> {
+        self.envelope
+            .hash(network_id(network_passphrase))
+            .map_err(xdr_error)
+    }
+}
+
+/// Network ID derived from a network passphrase
+pub fn network_id(network_passphrase: &str) -> [u8; 32] {
+    Sha256::digest(network_passphrase.as_bytes()).into()
+}
+
+fn xdr_error(err: xdr::Error) -> ClientError {
+    ClientError::InvalidXdr(err.to_string())
+}
@@ -0,0 +1,231 @@
+//! Client-side copies of the contract's input validation.
+//!
+//! Each check returns the same error the contract would, so invalid input can
+//! be rejected before a transaction is built, simulated or paid for. Limits
+//! the admin can change are read from a [`Config`], which defaults to the
+//! values the contract sets on initialization.
+
+use crate::error::ContractError;
+use devasign_escrow_events::{Address, Config, Symbol};
+
+/// Largest arbitration fee in basis points
+pub const MAX_ARBITRATION_FEE: u32 = 1_000;
+
+/// All-zero account the contract never accepts as a party
+pub const NULL_ADDRESS: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+/// Same rules as the contract's `validate_address`
+pub fn validate_address(address: &Address) -> Result<(), ContractError> {
+    if address.as_str() == NULL_ADDRESS {
+        return Err(ContractError::InvalidAddress);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract's `validate_participant`, which also rejects
+/// the contract's own address for the admin, creator, contributor and payout
+/// recipients
+pub fn validate_participant(address: &Address, contract_id: &Address) -> Result<(), ContractError> {
+    validate_address(address)?;
+    if address == contract_id {
+        return Err(ContractError::InvalidAddress);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract's `validate_new_task_id`, which adds the
+/// character set and the configured length range when an escrow is created
+pub fn validate_new_task_id(task_id: &str, config: &Config) -> Result<(), ContractError> {
+    validate_task_id(task_id)?;
+    if !task_id
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+    {
+        return Err(ContractError::InvalidTaskIdCharacters);
+    }
+    if task_id.len() < config.task_id_min_length as usize {
+        return Err(ContractError::TaskIdTooShort);
+    }
+    if task_id.len() > config.task_id_max_length as usize {
+        return Err(ContractError::TaskIdTooLong);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract's `validate_task_id`
+pub fn validate_task_id(task_id: &str) -> Result<(), ContractError> {
+    if task_id.is_empty() {
+        return Err(ContractError::EmptyTaskId);
+    }
+    Ok(())
+}
+
+/// Largest owner name GitHub allows
+pub const MAX_GITHUB_OWNER_LENGTH: usize = 39;
+
+/// Largest repository name GitHub allows
+pub const MAX_GITHUB_REPO_LENGTH: usize = 100;
+
+/// Most digits the contract accepts in an issue number
+pub const MAX_ISSUE_NUMBER_DIGITS: usize = 10;
+
+/// Longest issue URL the contract can parse
+pub const MAX_ISSUE_URL_LENGTH: u32 = 192;
+
+/// Same rules as the contract's `validate_issue_url`, which only accepts
+/// GitHub issue links
+pub fn validate_issue_url(url: &str, config: &Config) -> Result<(), ContractError> {
+    if url.is_empty() || url.len() > config.issue_url_max_length as usize {
+        return Err(ContractError::InvalidIssueUrl);
+    }
+    if canonical_issue_url(url).is_none() {
+        return Err(ContractError::InvalidIssueUrl);
+    }
+    Ok(())
+}
+
+/// The form the contract stores an issue URL in:
+/// `https://github.com/{owner}/{repo}/issues/{number}` in lowercase, accepting
+/// an `http` scheme, a `www.` host and a trailing slash
+pub fn canonical_issue_url(url: &str) -> Option<String> {
+    let url = url.to_ascii_lowercase();
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let path = rest.strip_prefix("github.com/")?;
+    let path = path.strip_suffix('/').unwrap_or(path);
+
+    let segments: Vec<&str> = path.split('/').collect();
+    let [owner, repo, "issues", number] = segments.as_slice() else {
+        return None;
+    };
+    let valid_number = (1..=MAX_ISSUE_NUMBER_DIGITS).contains(&number.len())
+        && !number.starts_with('0')
+        && number.bytes().all(|byte| byte.is_ascii_digit());
+    if !(is_valid_owner(owner) && is_valid_repo(repo) && valid_number) {
+        return None;
+    }
+    Some(format!("https://github.com/{path}"))
+}
+
+/// Same rules as the contract applies to the names passed to
+/// `list_escrows_by_repo`, which are matched case-insensitively
+pub fn validate_repository(owner: &str, repo: &str) -> Result<(), ContractError> {
+    if !is_valid_owner(owner) || !is_valid_repo(repo) {
+        return Err(ContractError::InvalidRepository);
+    }
+    Ok(())
+}
+
+fn is_valid_owner(owner: &str) -> bool {
+    (1..=MAX_GITHUB_OWNER_LENGTH).contains(&owner.len())
+        && owner
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+}
+
+fn is_valid_repo(repo: &str) -> bool {
+    (1..=MAX_GITHUB_REPO_LENGTH).contains(&repo.len())
+        && repo
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.'))
+}
+
+/// Most skill tags on a task
+pub const MAX_TASK_TAGS: usize = 5;
+
+/// Longest skill tag
+pub const MAX_TAG_LENGTH: usize = 9;
+
+/// Same rules as the contract's `validate_tags`
+pub fn validate_tags(tags: &[Symbol]) -> Result<(), ContractError> {
+    if tags.len() > MAX_TASK_TAGS {
+        return Err(ContractError::InvalidTags);
+    }
+    for (index, tag) in tags.iter().enumerate() {
+        validate_tag(tag)?;
+        if tags[..index].contains(tag) {
+            return Err(ContractError::InvalidTags);
+        }
+    }
+    Ok(())
+}
+
+/// Same rules as the contract's `validate_tag`
+pub fn validate_tag(tag: &Symbol) -> Result<(), ContractError> {
+    let name = tag.as_str();
+    let valid = (1..=MAX_TAG_LENGTH).contains(&name.len())
+        && name
+            .bytes()
+            .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_');
+    if !valid {
+        return Err(ContractError::InvalidTags);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract's `validate_amount`
+pub fn validate_amount(amount: i128, config: &Config) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if amount < config.min_amount {
+        return Err(ContractError::AmountTooSmall);
+    }
+    if amount > config.max_amount {
+        return Err(ContractError::InvalidTokenAmount);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract applies in `increase_bounty` and `decrease_bounty`
+pub fn validate_bounty_adjustment(amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract's `validate_dispute_reason`
+pub fn validate_dispute_reason(reason: &str, config: &Config) -> Result<(), ContractError> {
+    if reason.len() < config.dispute_reason_min_length as usize {
+        return Err(ContractError::DisputeReasonTooShort);
+    }
+    if reason.len() > config.dispute_reason_max_length as usize {
+        return Err(ContractError::InvalidDisputeReason);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract applies in `set_dispute_timeout`
+pub fn validate_dispute_timeout(timeout: u64) -> Result<(), ContractError> {
+    if timeout == 0 {
+        return Err(ContractError::InvalidDisputeTimeout);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract applies in `set_arbitration_fee`
+pub fn validate_arbitration_fee(fee_basis_points: u32) -> Result<(), ContractError> {
+    if fee_basis_points > MAX_ARBITRATION_FEE {
+        return Err(ContractError::InvalidBasisPoints);
+    }
+    Ok(())
+}
+
+/// Same rules as the contract applies in `update_config`
+pub fn validate_config(config: &Config) -> Result<(), ContractError> {
+    if config.min_amount <= 0
+        || config.min_amount > config.max_amount
+        || config.task_id_min_length == 0
+        || config.task_id_min_length > config.task_id_max_length
+        || config.dispute_reason_min_length == 0
+        || config.dispute_reason_min_length > config.dispute_reason_max_length
+        || config.issue_url_max_length == 0
+        || config.issue_url_max_length > MAX_ISSUE_URL_LENGTH
+    {
+        return Err(ContractError::InvalidConfig);
+    }
+    Ok(())
+}
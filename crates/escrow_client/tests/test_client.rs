@@ -0,0 +1,867 @@
+use devasign_escrow_client::{
+    network_id, validation, xdr, Address, ClientError, Config, ContractError, Difficulty,
+    DisputeResolution, EscrowClient, EscrowOptions, Invocation, TransactionOptions, BASE_FEE,
+    PUBLIC_PASSPHRASE, TESTNET_PASSPHRASE,
+};
+use devasign_escrow_events::ScValConvert;
+use devasign_task_escrow::{self as contract, TaskEscrowContract, TaskEscrowContractClient};
+use sha2::{Digest, Sha256};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Env, String, Symbol, TryFromVal, Val,
+};
+use xdr::{
+    HostFunction, Limits, OperationBody, Preconditions, ReadXdr, ScError, ScVal,
+    TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
+    TransactionSignaturePayloadTaggedTransaction, WriteXdr,
+};
+
+const BOUNTY: i128 = 1000_0000000;
+const TASK_ID: &str = "clclienttask0000000000001";
+const ISSUE_URL: &str = "https://github.com/owner/repo/issues/1";
+const SOURCE_ACCOUNT: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+struct Harness {
+    env: Env,
+    admin: soroban_sdk::Address,
+    usdc_address: soroban_sdk::Address,
+    contract: TaskEscrowContractClient<'static>,
+    usdc_token: StellarAssetClient<'static>,
+    client: EscrowClient,
+}
+
+impl Harness {
+    /// Deploy the contract without initializing it
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let admin = soroban_sdk::Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let usdc_token = StellarAssetClient::new(&env, &usdc_address);
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let contract = TaskEscrowContractClient::new(&env, &contract_id);
+        let client = EscrowClient::new(strkey(&env, &contract_id).as_str()).unwrap();
+
+        Harness {
+            env,
+            admin,
+            usdc_address,
+            contract,
+            usdc_token,
+            client,
+        }
+    }
+
+    fn initialized() -> Self {
+        let harness = Self::new();
+        harness
+            .invoke(&harness.client.initialize(
+                &harness.address(&harness.admin),
+                &harness.address(&harness.usdc_address),
+            ))
+            .unwrap();
+        harness
+    }
+
+    fn address(&self, address: &soroban_sdk::Address) -> Address {
+        strkey(&self.env, address)
+    }
+
+    fn funded_account(&self) -> (soroban_sdk::Address, Address) {
+        let account = soroban_sdk::Address::generate(&self.env);
+        self.usdc_token.mint(&account, &(BOUNTY * 10));
+        let strkey = self.address(&account);
+        (account, strkey)
+    }
+
+    /// Execute an invocation built by the client against the deployed contract
+    fn invoke(&self, invocation: &Invocation) -> Result<ScVal, ClientError> {
+        assert_eq!(invocation.contract_id(), self.client.contract_id());
+        let args = invocation
+            .args()
+            .iter()
+            .map(|arg| Val::try_from_val(&self.env, arg).unwrap())
+            .collect::<std::vec::Vec<_>>();
+        let result = self.env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &self.contract.address,
+            &Symbol::new(&self.env, invocation.function()),
+            soroban_sdk::Vec::from_slice(&self.env, &args),
+        );
+        match result {
+            Ok(Ok(val)) => Ok(ScVal::try_from_val(&self.env, &val).unwrap()),
+            Err(Ok(err)) => Err(ClientError::from_sc_error(&ScError::try_from(err).unwrap())
+                .expect("contract errors map to a client error")),
+            other => panic!("unexpected invocation result: {other:?}"),
+        }
+    }
+}
+
+fn strkey(env: &Env, address: &soroban_sdk::Address) -> Address {
+    Address::from_sc_val(&ScVal::try_from_val(env, &address.to_val()).unwrap()).unwrap()
+}
+
+fn tag(name: &str) -> devasign_escrow_client::Symbol {
+    devasign_escrow_client::Symbol::new(name).unwrap()
+}
+
+#[test]
+fn test_contract_error_codes_match_contract() {
+    use contract::Error;
+
+    let errors = [
+        Error::TaskNotFound,
+        Error::TaskAlreadyExists,
+        Error::InvalidTaskStatus,
+        Error::ContractNotInitialized,
+        Error::AlreadyInitialized,
+        Error::Unauthorized,
+        Error::NotTaskCreator,
+        Error::NotTaskContributor,
+        Error::NotAdmin,
+        Error::OnlyCreatorOrContributor,
+        Error::NotDisputingParty,
+        Error::ContributorAlreadyAssigned,
+        Error::NoContributorAssigned,
+        Error::InsufficientBalance,
+        Error::TaskNotCompleted,
+        Error::TaskNotDisputed,
+        Error::TaskAlreadyResolved,
+        Error::CannotRefundWithContributor,
+        Error::DisputeNotExpired,
+        Error::NoFeesToClaim,
+        Error::IssueAlreadyEscrowed,
+        Error::TokenTransferFailed,
+        Error::InvalidTokenAmount,
+        Error::TokenContractNotSet,
+        Error::InvalidTaskId,
+        Error::InvalidAddress,
+        Error::InvalidAmount,
+        Error::InvalidDisputeReason,
+        Error::EmptyTaskId,
+        Error::TaskIdTooShort,
+        Error::TaskIdTooLong,
+        Error::InvalidTaskIdCharacters,
+        Error::AmountTooSmall,
+        Error::DisputeReasonTooShort,
+        Error::InvalidIssueUrl,
+        Error::ContractPaused,
+        Error::InvalidDisputeTimeout,
+        Error::InvalidBasisPoints,
+        Error::InvalidPayoutSplit,
+        Error::InvalidConfig,
+        Error::InvalidRepository,
+        Error::InvalidTags,
+        Error::NoPendingOwnershipTransfer,
+    ];
+
+    assert_eq!(ContractError::ALL.len(), errors.len());
+    for (error, mirror) in errors.iter().zip(ContractError::ALL) {
+        assert_eq!(mirror.code(), *error as u32);
+        assert_eq!(format!("{mirror:?}"), format!("{error:?}"));
+        assert_eq!(ContractError::from_code(*error as u32), Some(*mirror));
+        assert!(!mirror.description().is_empty());
+    }
+}
+
+#[test]
+fn test_unknown_error_code_is_preserved() {
+    assert_eq!(ContractError::from_code(0), None);
+    assert_eq!(ContractError::from_code(99), None);
+    assert_eq!(
+        ClientError::from_contract_code(99),
+        ClientError::UnknownContractError(99)
+    );
+    assert_eq!(
+        ClientError::from_contract_code(1),
+        ClientError::Contract(ContractError::TaskNotFound)
+    );
+    assert_eq!(
+        ClientError::from_sc_error(&ScError::Context(xdr::ScErrorCode::InvalidAction)),
+        None
+    );
+}
+
+#[test]
+fn test_error_display_is_descriptive() {
+    assert_eq!(
+        ContractError::NotAdmin.to_string(),
+        "the caller is not the contract admin (#13)"
+    );
+    assert_eq!(
+        ClientError::Rejected(ContractError::EmptyTaskId).to_string(),
+        "rejected before submission: the task ID is empty (#44)"
+    );
+}
+
+#[test]
+fn test_create_escrow_validation_matches_contract() {
+    let harness = Harness::initialized();
+    let (creator, creator_strkey) = harness.funded_account();
+    let long_url = format!("https://github.com/{}", "a".repeat(490));
+
+    let cases: [(&str, &str, i128); 17] = [
+        ("", ISSUE_URL, BOUNTY),
+        ("clshort", ISSUE_URL, BOUNTY),
+        ("clclienttask00000000000001", ISSUE_URL, BOUNTY),
+        ("clclienttask000000000!010", ISSUE_URL, BOUNTY),
+        ("clclienttask0000000000002", "", BOUNTY),
+        ("clclienttask0000000000003", &long_url, BOUNTY),
+        (
+            "clclienttask0000000000010",
+            "ftp://github.com/owner/repo/issues/1",
+            BOUNTY,
+        ),
+        (
+            "clclienttask0000000000011",
+            "https://github.com/owner/repo/pull/1",
+            BOUNTY,
+        ),
+        (
+            "clclienttask0000000000012",
+            "https://github.com/owner/repo/issues/01",
+            BOUNTY,
+        ),
+        (
+            "clclienttask0000000000013",
+            "https://github.com/owner/repo/issues/1?tab=1",
+            BOUNTY,
+        ),
+        ("clclienttask0000000000004", ISSUE_URL, 0),
+        ("clclienttask0000000000005", ISSUE_URL, -1),
+        (
+            "clclienttask0000000000006",
+            ISSUE_URL,
+            Config::default().min_amount - 1,
+        ),
+        (
+            "clclienttask0000000000007",
+            ISSUE_URL,
+            Config::default().min_amount,
+        ),
+        (
+            "clclienttask0000000000008",
+            ISSUE_URL,
+            Config::default().max_amount + 1,
+        ),
+        (
+            "clclienttask0000000000009",
+            "HTTP://WWW.GitHub.com/Owner/Repo/issues/2/",
+            BOUNTY,
+        ),
+        ("", "", 0),
+    ];
+
+    for (task_id, issue_url, amount) in cases {
+        let expected = harness
+            .contract
+            .try_create_escrow(
+                &creator,
+                &String::from_str(&harness.env, task_id),
+                &String::from_str(&harness.env, issue_url),
+                &amount,
+            )
+            .err()
+            .map(|err| ContractError::from_code(err.unwrap() as u32).unwrap());
+
+        let built = harness
+            .client
+            .create_escrow(&creator_strkey, task_id, issue_url, amount);
+        match expected {
+            Some(error) => assert_eq!(built, Err(ClientError::Rejected(error))),
+            None => assert!(built.is_ok(), "{task_id} was rejected: {built:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_dispute_reason_validation_matches_contract() {
+    let harness = Harness::initialized();
+    let (party, party_strkey) = harness.funded_account();
+
+    for reason in [
+        "",
+        "too short",
+        "long enough",
+        &"r".repeat(500),
+        &"r".repeat(501),
+    ] {
+        let contract_error = harness
+            .contract
+            .try_dispute_task(
+                &party,
+                &String::from_str(&harness.env, TASK_ID),
+                &String::from_str(&harness.env, reason),
+            )
+            .unwrap_err()
+            .unwrap() as u32;
+
+        // The task does not exist, so valid reasons fail only once storage is read
+        match harness.client.dispute_task(&party_strkey, TASK_ID, reason) {
+            Ok(_) => assert_eq!(contract_error, ContractError::TaskNotFound.code()),
+            Err(err) => assert_eq!(err.contract_error().unwrap().code(), contract_error),
+        }
+    }
+}
+
+#[test]
+fn test_repository_validation_matches_contract() {
+    let harness = Harness::initialized();
+    let long_owner = "o".repeat(40);
+    let long_repo = "r".repeat(101);
+
+    for (owner, repo) in [
+        ("DevAsign", "Soroban-Contract"),
+        ("owner", "repo.rs"),
+        ("", "repo"),
+        ("owner", ""),
+        ("own_er", "repo"),
+        ("owner", "re po"),
+        (long_owner.as_str(), "repo"),
+        ("owner", long_repo.as_str()),
+    ] {
+        let expected = harness
+            .contract
+            .try_list_escrows_by_repo(
+                &String::from_str(&harness.env, owner),
+                &String::from_str(&harness.env, repo),
+                &0,
+            )
+            .err()
+            .map(|err| ContractError::from_code(err.unwrap() as u32).unwrap());
+
+        let built = harness.client.list_escrows_by_repo(owner, repo, 0);
+        match expected {
+            Some(error) => assert_eq!(built, Err(ClientError::Rejected(error))),
+            None => assert!(built.is_ok(), "{owner}/{repo} was rejected: {built:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_create_escrow_with_options_drives_contract() {
+    let harness = Harness::initialized();
+    let client = &harness.client;
+    let (_, creator) = harness.funded_account();
+    let options = EscrowOptions {
+        labels_hash: Some([9; 32]),
+        tags: vec![tag("rust"), tag("soroban")],
+        difficulty: Difficulty::Beginner,
+    };
+
+    harness
+        .invoke(
+            &client
+                .create_escrow_with_options(&creator, TASK_ID, ISSUE_URL, BOUNTY, &options)
+                .unwrap(),
+        )
+        .unwrap();
+    let escrow = harness
+        .contract
+        .get_escrow(&String::from_str(&harness.env, TASK_ID));
+    assert_eq!(
+        escrow.issue.labels_hash.map(|hash| hash.to_array()),
+        options.labels_hash
+    );
+
+    let listed = harness
+        .invoke(&client.list_escrows_by_repo("Owner", "Repo", 0).unwrap())
+        .unwrap();
+    let ScVal::Vec(Some(escrows)) = listed else {
+        panic!("expected a vec, got {listed:?}");
+    };
+    assert_eq!(escrows.len(), 1);
+
+    harness
+        .invoke(
+            &client
+                .update_task_tags(&creator, TASK_ID, &[tag("wasm")], Difficulty::Advanced)
+                .unwrap(),
+        )
+        .unwrap();
+    let escrow = harness
+        .contract
+        .get_escrow(&String::from_str(&harness.env, TASK_ID));
+    assert_eq!(escrow.difficulty, contract::Difficulty::Advanced);
+    for (listed_tag, count) in [("wasm", 1), ("rust", 0)] {
+        let listed = harness
+            .invoke(&client.list_escrows_by_tag(&tag(listed_tag), 0).unwrap())
+            .unwrap();
+        let ScVal::Vec(Some(escrows)) = listed else {
+            panic!("expected a vec, got {listed:?}");
+        };
+        assert_eq!(escrows.len(), count, "escrows tagged {listed_tag}");
+    }
+
+    harness
+        .invoke(
+            &client
+                .update_task_metadata(
+                    &creator,
+                    TASK_ID,
+                    "https://github.com/owner/other/issues/2",
+                    None,
+                )
+                .unwrap(),
+        )
+        .unwrap();
+    let escrow = harness
+        .contract
+        .get_escrow(&String::from_str(&harness.env, TASK_ID));
+    assert_eq!(escrow.issue.repo.to_string(), "other");
+    assert_eq!(escrow.issue.labels_hash, None);
+
+    assert_eq!(
+        client.create_escrow_with_options(&creator, TASK_ID, "", BOUNTY, &options),
+        Err(ClientError::Rejected(ContractError::InvalidIssueUrl))
+    );
+    assert_eq!(
+        client.update_task_metadata(&creator, TASK_ID, "https://gitlab.com/o/r/issues/1", None),
+        Err(ClientError::Rejected(ContractError::InvalidIssueUrl))
+    );
+}
+
+#[test]
+fn test_tag_validation_matches_contract() {
+    let harness = Harness::initialized();
+    let (creator, creator_strkey) = harness.funded_account();
+
+    for (index, tags) in [
+        vec!["rust", "wasm"],
+        vec!["c_plus2"],
+        vec![],
+        vec!["Rust"],
+        vec!["toolongtag"],
+        vec!["rust", "rust"],
+        vec!["a", "b", "c", "d", "e", "f"],
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let task_id = format!("clclienttag{index:014}");
+        let issue_url = format!("https://github.com/owner/repo/issues/{}", index + 1);
+        let mut contract_tags = soroban_sdk::Vec::new(&harness.env);
+        for name in &tags {
+            contract_tags.push_back(Symbol::new(&harness.env, name));
+        }
+        let expected = harness
+            .contract
+            .try_create_escrow_with_options(
+                &creator,
+                &String::from_str(&harness.env, &task_id),
+                &String::from_str(&harness.env, &issue_url),
+                &BOUNTY,
+                &contract::EscrowOptions {
+                    labels_hash: None,
+                    tags: contract_tags,
+                    difficulty: contract::Difficulty::Unrated,
+                },
+            )
+            .err()
+            .map(|err| ContractError::from_code(err.unwrap() as u32).unwrap());
+
+        let options = EscrowOptions {
+            tags: tags.iter().map(|name| tag(name)).collect(),
+            ..EscrowOptions::default()
+        };
+        let built = harness.client.create_escrow_with_options(
+            &creator_strkey,
+            &task_id,
+            &issue_url,
+            BOUNTY,
+            &options,
+        );
+        match expected {
+            Some(error) => assert_eq!(built, Err(ClientError::Rejected(error))),
+            None => assert!(built.is_ok(), "{tags:?} was rejected: {built:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_config_validation_matches_contract() {
+    let harness = Harness::initialized();
+    let (creator, creator_strkey) = harness.funded_account();
+
+    // The defaults mirror the limits set on initialization
+    let config = harness.invoke(&harness.client.get_config()).unwrap();
+    assert_eq!(Config::from_sc_val(&config).unwrap(), Config::default());
+
+    let custom = Config {
+        min_amount: BOUNTY,
+        task_id_min_length: 10,
+        task_id_max_length: 30,
+        ..Config::default()
+    };
+    for invalid in [
+        Config {
+            min_amount: 0,
+            ..custom.clone()
+        },
+        Config {
+            task_id_min_length: 31,
+            ..custom.clone()
+        },
+        Config {
+            dispute_reason_max_length: 0,
+            ..custom.clone()
+        },
+    ] {
+        assert_eq!(
+            harness.client.update_config(&invalid),
+            Err(ClientError::Rejected(ContractError::InvalidConfig))
+        );
+        let contract_config = Val::try_from_val(&harness.env, &invalid.to_sc_val()).unwrap();
+        let result = harness.env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &harness.contract.address,
+            &Symbol::new(&harness.env, "update_config"),
+            soroban_sdk::vec![&harness.env, contract_config],
+        );
+        let error = soroban_sdk::Error::from_contract_error(ContractError::InvalidConfig.code());
+        assert!(matches!(result, Err(Ok(err)) if err == error));
+    }
+    harness
+        .invoke(&harness.client.update_config(&custom).unwrap())
+        .unwrap();
+
+    // A client built with the deployed limits agrees with the contract
+    let config = harness.invoke(&harness.client.get_config()).unwrap();
+    let client = harness
+        .client
+        .clone()
+        .with_config(Config::from_sc_val(&config).unwrap());
+    assert_eq!(client.config(), &custom);
+    for (task_id, amount) in [
+        ("clshort", BOUNTY),
+        ("clclienttask", BOUNTY),
+        ("clclienttask0000000000000000001", BOUNTY),
+        ("clclienttask0000000000000001", BOUNTY - 1),
+    ] {
+        let expected = harness
+            .contract
+            .try_create_escrow(
+                &creator,
+                &String::from_str(&harness.env, task_id),
+                &String::from_str(&harness.env, ISSUE_URL),
+                &amount,
+            )
+            .err()
+            .map(|err| ContractError::from_code(err.unwrap() as u32).unwrap());
+        let built = client.create_escrow(&creator_strkey, task_id, ISSUE_URL, amount);
+        match expected {
+            Some(error) => assert_eq!(built, Err(ClientError::Rejected(error))),
+            None => assert!(built.is_ok(), "{task_id} was rejected: {built:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_address_validation_matches_contract() {
+    let harness = Harness::initialized();
+    let client = &harness.client;
+    let null = soroban_sdk::Address::from_str(&harness.env, validation::NULL_ADDRESS);
+    let contract_id = harness.contract.address.clone();
+
+    for address in [&null, &contract_id] {
+        let error = harness
+            .contract
+            .try_create_escrow(
+                address,
+                &String::from_str(&harness.env, TASK_ID),
+                &String::from_str(&harness.env, ISSUE_URL),
+                &BOUNTY,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(
+            client.create_escrow(&harness.address(address), TASK_ID, ISSUE_URL, BOUNTY),
+            Err(ClientError::Rejected(
+                ContractError::from_code(error as u32).unwrap()
+            ))
+        );
+        assert_eq!(
+            client.assign_contributor(TASK_ID, &harness.address(address)),
+            Err(ClientError::Rejected(ContractError::InvalidAddress))
+        );
+    }
+
+    let null = harness.address(&null);
+    assert_eq!(
+        client.dispute_task(&null, TASK_ID, "long enough"),
+        Err(ClientError::Rejected(ContractError::InvalidAddress))
+    );
+    assert_eq!(
+        client.withdraw_dispute(&null, TASK_ID),
+        Err(ClientError::Rejected(ContractError::InvalidAddress))
+    );
+    let payout = devasign_escrow_client::DisputePayout {
+        recipient: client.contract_id().clone(),
+        amount: BOUNTY,
+    };
+    assert_eq!(
+        client.resolve_dispute(TASK_ID, &DisputeResolution::MultiPayout(vec![payout])),
+        Err(ClientError::Rejected(ContractError::InvalidAddress))
+    );
+}
+
+#[test]
+fn test_invalid_input_is_rejected_before_building() {
+    let harness = Harness::new();
+    let creator = harness.address(&harness.admin);
+    let client = &harness.client;
+
+    assert_eq!(
+        client.get_escrow(""),
+        Err(ClientError::Rejected(ContractError::EmptyTaskId))
+    );
+    assert_eq!(
+        client.increase_bounty(&creator, TASK_ID, 0),
+        Err(ClientError::Rejected(ContractError::InvalidAmount))
+    );
+    assert_eq!(
+        client.create_escrow(&creator, "cl/short", ISSUE_URL, BOUNTY),
+        Err(ClientError::Rejected(
+            ContractError::InvalidTaskIdCharacters
+        ))
+    );
+    assert_eq!(
+        client.set_arbitration_fee(validation::MAX_ARBITRATION_FEE + 1),
+        Err(ClientError::Rejected(ContractError::InvalidBasisPoints))
+    );
+    assert_eq!(
+        client.set_dispute_timeout(0, devasign_escrow_client::DisputeTimeoutPolicy::SplitEvenly),
+        Err(ClientError::Rejected(ContractError::InvalidDisputeTimeout))
+    );
+    assert_eq!(
+        client.get_escrow_by_issue("https://github.com/owner/repo/pull/1"),
+        Err(ClientError::Rejected(ContractError::InvalidIssueUrl))
+    );
+    assert_eq!(
+        client.has_sufficient_usdc_balance(&creator, 1),
+        Err(ClientError::Rejected(ContractError::AmountTooSmall))
+    );
+}
+
+#[test]
+fn test_invocations_drive_contract() {
+    let harness = Harness::initialized();
+    let client = &harness.client;
+    let (_, creator) = harness.funded_account();
+    let (_, contributor) = harness.funded_account();
+
+    harness
+        .invoke(
+            &client
+                .create_escrow(&creator, TASK_ID, ISSUE_URL, BOUNTY)
+                .unwrap(),
+        )
+        .unwrap();
+    harness
+        .invoke(&client.increase_bounty(&creator, TASK_ID, BOUNTY).unwrap())
+        .unwrap();
+    harness
+        .invoke(&client.assign_contributor(TASK_ID, &contributor).unwrap())
+        .unwrap();
+    harness
+        .invoke(
+            &client
+                .dispute_task(&creator, TASK_ID, "Work does not match the issue")
+                .unwrap(),
+        )
+        .unwrap();
+    harness
+        .invoke(
+            &client
+                .resolve_dispute(TASK_ID, &DisputeResolution::PercentageSplit(5_000))
+                .unwrap(),
+        )
+        .unwrap();
+
+    let escrow = harness
+        .contract
+        .get_escrow(&String::from_str(&harness.env, TASK_ID));
+    assert_eq!(escrow.bounty_amount, BOUNTY * 2);
+    assert_eq!(escrow.status, contract::TaskStatus::Completed);
+
+    let balance = harness
+        .invoke(&client.get_usdc_balance(&contributor))
+        .unwrap();
+    assert_eq!(i128::from_sc_val(&balance).unwrap(), BOUNTY * 11);
+
+    let admin = harness.invoke(&client.get_admin()).unwrap();
+    assert_eq!(
+        Address::from_sc_val(&admin).unwrap(),
+        harness.address(&harness.admin)
+    );
+
+    let version = harness.invoke(&client.version()).unwrap();
+    assert_eq!(
+        u64::from_sc_val(&version).unwrap(),
+        TaskEscrowContract::version()
+    );
+}
+
+#[test]
+fn test_task_ownership_transfer_drives_contract() {
+    let harness = Harness::initialized();
+    let client = &harness.client;
+    let (_, creator) = harness.funded_account();
+    let (new_creator_account, new_creator) = harness.funded_account();
+
+    harness
+        .invoke(
+            &client
+                .create_escrow(&creator, TASK_ID, ISSUE_URL, BOUNTY)
+                .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        harness.invoke(&client.get_pending_task_owner(TASK_ID).unwrap()),
+        Err(ClientError::Contract(
+            ContractError::NoPendingOwnershipTransfer
+        ))
+    );
+    let nominate = client
+        .transfer_task_ownership(TASK_ID, &new_creator)
+        .unwrap();
+    harness.invoke(&nominate).unwrap();
+
+    // A withdrawn nomination can be made again
+    harness
+        .invoke(&client.cancel_task_ownership_transfer(TASK_ID).unwrap())
+        .unwrap();
+    assert_eq!(
+        harness.invoke(&client.get_pending_task_owner(TASK_ID).unwrap()),
+        Err(ClientError::Contract(
+            ContractError::NoPendingOwnershipTransfer
+        ))
+    );
+    harness.invoke(&nominate).unwrap();
+    let pending = harness
+        .invoke(&client.get_pending_task_owner(TASK_ID).unwrap())
+        .unwrap();
+    assert_eq!(Address::from_sc_val(&pending).unwrap(), new_creator);
+    harness
+        .invoke(&client.accept_task_ownership(TASK_ID).unwrap())
+        .unwrap();
+
+    // Refunds go to the new creator
+    harness.invoke(&client.refund(TASK_ID).unwrap()).unwrap();
+    let escrow = harness
+        .contract
+        .get_escrow(&String::from_str(&harness.env, TASK_ID));
+    assert_eq!(escrow.creator, new_creator_account);
+    let balance = harness
+        .invoke(&client.get_usdc_balance(&new_creator))
+        .unwrap();
+    assert_eq!(i128::from_sc_val(&balance).unwrap(), BOUNTY * 11);
+
+    assert_eq!(
+        client.transfer_task_ownership(TASK_ID, client.contract_id()),
+        Err(ClientError::Rejected(ContractError::InvalidAddress))
+    );
+}
+
+#[test]
+fn test_contract_errors_are_mapped() {
+    let harness = Harness::new();
+    assert_eq!(
+        harness.invoke(&harness.client.get_admin()),
+        Err(ClientError::Contract(ContractError::ContractNotInitialized))
+    );
+
+    let harness = Harness::initialized();
+    assert_eq!(
+        harness.invoke(&harness.client.approve_completion(TASK_ID).unwrap()),
+        Err(ClientError::Contract(ContractError::TaskNotFound))
+    );
+    assert_eq!(
+        harness.invoke(
+            &harness
+                .client
+                .claim_arbiter_fees(&harness.address(&harness.admin))
+        ),
+        Err(ClientError::Contract(ContractError::NoFeesToClaim))
+    );
+}
+
+#[test]
+fn test_client_requires_contract_address() {
+    assert_eq!(
+        EscrowClient::new(SOURCE_ACCOUNT),
+        Err(ClientError::InvalidAddress(SOURCE_ACCOUNT.into()))
+    );
+    assert_eq!(
+        EscrowClient::new("not an address"),
+        Err(ClientError::InvalidAddress("not an address".into()))
+    );
+
+    let harness = Harness::new();
+    let contract_id = harness.client.contract_id().as_str();
+    assert_eq!(
+        TransactionOptions::new(contract_id, 1),
+        Err(ClientError::InvalidAddress(contract_id.into()))
+    );
+}
+
+#[test]
+fn test_unsigned_transaction_envelope() {
+    let harness = Harness::new();
+    let (_, creator) = harness.funded_account();
+    let invocation = harness
+        .client
+        .create_escrow(&creator, TASK_ID, ISSUE_URL, BOUNTY)
+        .unwrap();
+
+    let mut options = TransactionOptions::new(SOURCE_ACCOUNT, 42).unwrap();
+    assert_eq!(options.fee, BASE_FEE);
+    options.fee = 50_000;
+    options.time_bounds = Some((0, 1_700_000_000));
+    options.soroban_data = Some(xdr::SorobanTransactionData::default());
+
+    let transaction = invocation.build_transaction(&options).unwrap();
+    let encoded = transaction.to_base64().unwrap();
+    let envelope = TransactionEnvelope::from_xdr_base64(&encoded, Limits::none()).unwrap();
+    assert_eq!(&envelope, transaction.envelope());
+
+    let TransactionEnvelope::Tx(envelope) = envelope else {
+        panic!("expected a v1 envelope");
+    };
+    assert!(envelope.signatures.is_empty());
+    let tx = &envelope.tx;
+    assert_eq!(tx.source_account.to_string(), SOURCE_ACCOUNT);
+    assert_eq!(tx.seq_num.0, 42);
+    assert_eq!(tx.fee, 50_000);
+    assert!(matches!(&tx.cond, Preconditions::Time(bounds) if bounds.max_time.0 == 1_700_000_000));
+    assert!(matches!(tx.ext, TransactionExt::V1(_)));
+
+    let [operation] = tx.operations.as_slice() else {
+        panic!("expected a single operation");
+    };
+    let OperationBody::InvokeHostFunction(op) = &operation.body else {
+        panic!("expected an invoke host function operation");
+    };
+    assert!(op.auth.is_empty());
+    let HostFunction::InvokeContract(args) = &op.host_function else {
+        panic!("expected a contract invocation");
+    };
+    assert_eq!(args, &invocation.to_invoke_contract_args().unwrap());
+    assert_eq!(args.function_name.to_utf8_string_lossy(), "create_escrow");
+    assert_eq!(args.args.as_slice(), invocation.args());
+
+    // Signers sign the hash of the network ID and the transaction
+    let payload = TransactionSignaturePayload {
+        network_id: xdr::Hash(network_id(TESTNET_PASSPHRASE)),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+    };
+    let expected: [u8; 32] = Sha256::digest(payload.to_xdr(Limits::none()).unwrap()).into();
+    assert_eq!(transaction.hash(TESTNET_PASSPHRASE).unwrap(), expected);
+    assert_ne!(
+        transaction.hash(TESTNET_PASSPHRASE).unwrap(),
+        transaction.hash(PUBLIC_PASSPHRASE).unwrap()
+    );
+}
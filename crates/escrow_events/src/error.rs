@@ -0,0 +1,38 @@
+use core::fmt;
+
+/// Errors returned when decoding raw contract events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Base64 or binary XDR could not be parsed
+    InvalidXdr(String),
+    /// JSON input could not be parsed or has an unexpected shape
+    InvalidJson(String),
+    /// Topic list is missing the event name or version topics
+    InvalidTopics,
+    /// Event name is not emitted by the escrow contract
+    UnknownEvent(String),
+    /// Event was published with a schema version this crate does not support
+    UnsupportedSchemaVersion(u32),
+    /// A data field required by the event is missing
+    MissingField(&'static str),
+    /// A value does not have the expected XDR type
+    TypeMismatch(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidXdr(msg) => write!(f, "invalid XDR: {msg}"),
+            DecodeError::InvalidJson(msg) => write!(f, "invalid JSON: {msg}"),
+            DecodeError::InvalidTopics => write!(f, "event topics are malformed"),
+            DecodeError::UnknownEvent(name) => write!(f, "unknown event: {name}"),
+            DecodeError::UnsupportedSchemaVersion(version) => {
+                write!(f, "unsupported event schema version: {version}")
+            }
+            DecodeError::MissingField(field) => write!(f, "missing field: {field}"),
+            DecodeError::TypeMismatch(expected) => write!(f, "expected {expected}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
@@ -0,0 +1,285 @@
+use crate::error::DecodeError;
+use crate::scval::{self, ScValConvert};
+use crate::types::{Address, Config, Difficulty, DisputeResolution, DisputeTimeoutPolicy, Symbol};
+use serde::{Deserialize, Serialize};
+use stellar_xdr::curr::ScVal;
+
+/// Declare the mirror struct of each contract event together with its
+/// variant in `EscrowEvent` and the XDR conversions for its data map.
+macro_rules! contract_events {
+    ($(
+        $(#[$doc:meta])*
+        $variant:ident($name:literal) => $event:ident {
+            $($field:ident: $ty:ty),* $(,)?
+        }
+    )*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+            pub struct $event {
+                $(pub $field: $ty,)*
+            }
+
+            impl $event {
+                /// Event name published as the first topic
+                pub const NAME: &'static str = $name;
+
+                fn from_data(data: &ScVal) -> Result<Self, DecodeError> {
+                    let fields = scval::struct_fields(data)?;
+                    Ok($event {
+                        $($field: scval::field(fields, stringify!($field))?,)*
+                    })
+                }
+
+                fn to_data(&self) -> ScVal {
+                    scval::struct_val(vec![
+                        $((stringify!($field), self.$field.to_sc_val()),)*
+                    ])
+                }
+            }
+
+            impl From<$event> for EscrowEvent {
+                fn from(event: $event) -> Self {
+                    EscrowEvent::$variant(event)
+                }
+            }
+        )*
+
+        /// Any event published by the task escrow contract
+        #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+        pub enum EscrowEvent {
+            $($variant($event),)*
+        }
+
+        impl EscrowEvent {
+            /// Every event name published by the contract
+            pub const NAMES: &'static [&'static str] = &[$($name,)*];
+
+            /// Event name published as the first topic
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(EscrowEvent::$variant(_) => $event::NAME,)*
+                }
+            }
+
+            /// Decode the data map of the event with the given name
+            pub fn from_name_and_data(name: &str, data: &ScVal) -> Result<Self, DecodeError> {
+                match name {
+                    $($name => Ok(EscrowEvent::$variant($event::from_data(data)?)),)*
+                    _ => Err(DecodeError::UnknownEvent(name.into())),
+                }
+            }
+
+            /// Encode the data map of the event
+            pub fn to_data(&self) -> ScVal {
+                match self {
+                    $(EscrowEvent::$variant(event) => event.to_data(),)*
+                }
+            }
+        }
+    };
+}
+
+contract_events! {
+    /// Event emitted when a new escrow is created
+    EscrowCreated("escrow_created_event") => EscrowCreatedEvent {
+        task_id: String,
+        issue_url: String,
+        labels_hash: Option<[u8; 32]>,
+        creator: Address,
+        bounty_amount: i128,
+        tags: Vec<Symbol>,
+        difficulty: Difficulty,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the creator points a task at a different issue or
+    /// changes its labels hash
+    TaskMetadataUpdated("task_metadata_updated_event") => TaskMetadataUpdatedEvent {
+        task_id: String,
+        creator: Address,
+        old_issue_url: String,
+        new_issue_url: String,
+        old_labels_hash: Option<[u8; 32]>,
+        new_labels_hash: Option<[u8; 32]>,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the creator nominates a new owner for a task
+    TaskOwnershipProposed("task_ownership_proposed_event") => TaskOwnershipProposedEvent {
+        task_id: String,
+        creator: Address,
+        pending_creator: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the nominated owner accepts a task
+    TaskOwnershipTransferred("task_ownership_transferred_event") => TaskOwnershipTransferredEvent {
+        task_id: String,
+        old_creator: Address,
+        new_creator: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the creator withdraws the nomination of a new owner
+    TaskOwnershipCancelled("task_ownership_cancelled_event") => TaskOwnershipCancelledEvent {
+        task_id: String,
+        creator: Address,
+        pending_creator: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the creator changes the tags or difficulty of a task
+    TaskTagsUpdated("task_tags_updated_event") => TaskTagsUpdatedEvent {
+        task_id: String,
+        creator: Address,
+        tags: Vec<Symbol>,
+        difficulty: Difficulty,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a contributor is assigned to a task
+    ContributorAssigned("contributor_assigned_event") => ContributorAssignedEvent {
+        task_id: String,
+        contributor: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a task is marked as completed
+    TaskCompleted("task_completed_event") => TaskCompletedEvent {
+        task_id: String,
+        contributor: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when funds are released to contributor
+    FundsReleased("funds_released_event") => FundsReleasedEvent {
+        task_id: String,
+        contributor: Address,
+        amount: i128,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a dispute is initiated
+    DisputeInitiated("dispute_initiated_event") => DisputeInitiatedEvent {
+        task_id: String,
+        disputing_party: Address,
+        reason: String,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a dispute is withdrawn by the disputing party
+    DisputeWithdrawn("dispute_withdrawn_event") => DisputeWithdrawnEvent {
+        task_id: String,
+        disputing_party: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a dispute is resolved
+    DisputeResolved("dispute_resolved_event") => DisputeResolvedEvent {
+        task_id: String,
+        resolution: DisputeResolution,
+        resolved_by: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when an arbitration fee is credited to an arbiter
+    ArbiterFeeCredited("arbiter_fee_credited_event") => ArbiterFeeCreditedEvent {
+        task_id: String,
+        arbiter: Address,
+        amount: i128,
+        timestamp: u64,
+    }
+
+    /// Event emitted when an arbiter claims accumulated fees
+    ArbiterFeesClaimed("arbiter_fees_claimed_event") => ArbiterFeesClaimedEvent {
+        arbiter: Address,
+        amount: i128,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a refund is processed
+    RefundProcessed("refund_processed_event") => RefundProcessedEvent {
+        task_id: String,
+        creator: Address,
+        amount: i128,
+        timestamp: u64,
+    }
+
+    /// Event emitted when bounty amount is increased
+    BountyIncreased("bounty_increased_event") => BountyIncreasedEvent {
+        task_id: String,
+        creator: Address,
+        added_amount: i128,
+        new_total_amount: i128,
+        timestamp: u64,
+    }
+
+    /// Event emitted when bounty amount is decreased
+    BountyDecreased("bounty_decreased_event") => BountyDecreasedEvent {
+        task_id: String,
+        creator: Address,
+        subtracted_amount: i128,
+        new_total_amount: i128,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the contract is initialized
+    Initialized("initialized_event") => InitializedEvent {
+        admin: Address,
+        usdc_token: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when admin privileges are transferred
+    AdminChanged("admin_changed_event") => AdminChangedEvent {
+        old_admin: Address,
+        new_admin: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the emergency pause state changes
+    PauseStateChanged("pause_state_changed_event") => PauseStateChangedEvent {
+        paused: bool,
+        actor: Address,
+        reason: String,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the USDC token contract address is updated
+    TokenUpdated("token_updated_event") => TokenUpdatedEvent {
+        old_token: Address,
+        new_token: Address,
+        admin: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the dispute timeout or its default policy changes
+    DisputeTimeoutUpdated("dispute_timeout_updated_event") => DisputeTimeoutUpdatedEvent {
+        timeout: u64,
+        policy: DisputeTimeoutPolicy,
+        admin: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the arbitration fee changes
+    ArbitrationFeeUpdated("arbitration_fee_updated_event") => ArbitrationFeeUpdatedEvent {
+        fee_basis_points: u32,
+        admin: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the validation limits change
+    ConfigUpdated("config_updated_event") => ConfigUpdatedEvent {
+        config: Config,
+        admin: Address,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the contract is upgraded
+    ContractUpgraded("contract_upgraded_event") => ContractUpgradedEvent {
+        new_wasm_hash: [u8; 32],
+        admin: Address,
+        timestamp: u64,
+    }
+}
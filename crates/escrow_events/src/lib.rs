@@ -0,0 +1,102 @@
+//! Typed decoders for events published by the DevAsign task escrow contract.
+//!
+//! Events are decoded from the raw XDR topics and data returned by Stellar
+//! RPC `getEvents`, either as base64 XDR or as the JSON form of `ScVal`, into
+//! mirror structs of every event in the contract's `types.rs`.
+
+mod error;
+mod events;
+mod rpc;
+mod scval;
+mod types;
+
+pub use error::DecodeError;
+pub use events::*;
+pub use rpc::{decode_get_events_response, RpcEvent};
+pub use scval::ScValConvert;
+pub use stellar_xdr::curr as xdr;
+pub use types::{
+    Address, Config, Difficulty, DisputePayout, DisputeResolution, DisputeTimeoutPolicy,
+    EscrowOptions, Symbol,
+};
+
+use serde::{Deserialize, Serialize};
+use xdr::{Limits, ReadXdr, ScVal, WriteXdr};
+
+/// Event schema version understood by this crate
+pub const EVENT_SCHEMA_VERSION: u32 = 4;
+
+/// Contract event together with the versions stamped into its topics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedEvent {
+    pub schema_version: u32,
+    pub contract_version: u64,
+    pub event: EscrowEvent,
+}
+
+impl DecodedEvent {
+    /// Wrap an event with the current schema version
+    pub fn new(contract_version: u64, event: impl Into<EscrowEvent>) -> Self {
+        DecodedEvent {
+            schema_version: EVENT_SCHEMA_VERSION,
+            contract_version,
+            event: event.into(),
+        }
+    }
+
+    /// Decode an event from its topic list and data value
+    pub fn from_xdr(topics: &[ScVal], data: &ScVal) -> Result<Self, DecodeError> {
+        let [name, schema_version, contract_version] = topics else {
+            return Err(DecodeError::InvalidTopics);
+        };
+        let name = scval::symbol_name(name)?;
+        let schema_version = u32::from_sc_val(schema_version)?;
+        if schema_version != EVENT_SCHEMA_VERSION {
+            return Err(DecodeError::UnsupportedSchemaVersion(schema_version));
+        }
+
+        Ok(DecodedEvent {
+            schema_version,
+            contract_version: u64::from_sc_val(contract_version)?,
+            event: EscrowEvent::from_name_and_data(&name, data)?,
+        })
+    }
+
+    /// Decode an event from base64 XDR topics and data, as returned by RPC
+    pub fn from_base64<S: AsRef<str>>(topics: &[S], data: &str) -> Result<Self, DecodeError> {
+        let topics = topics
+            .iter()
+            .map(|topic| decode_base64(topic.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_xdr(&topics, &decode_base64(data)?)
+    }
+
+    /// Encode the topic list
+    pub fn topics(&self) -> Vec<ScVal> {
+        vec![
+            scval::symbol(self.event.name()),
+            self.schema_version.to_sc_val(),
+            self.contract_version.to_sc_val(),
+        ]
+    }
+
+    /// Encode the data value
+    pub fn data(&self) -> ScVal {
+        self.event.to_data()
+    }
+
+    /// Encode the topics and data as base64 XDR
+    pub fn to_base64(&self) -> (Vec<String>, String) {
+        let topics = self.topics().iter().map(encode_base64).collect();
+        (topics, encode_base64(&self.data()))
+    }
+}
+
+fn decode_base64(xdr: &str) -> Result<ScVal, DecodeError> {
+    ScVal::from_xdr_base64(xdr, Limits::none()).map_err(|e| DecodeError::InvalidXdr(e.to_string()))
+}
+
+fn encode_base64(val: &ScVal) -> String {
+    val.to_xdr_base64(Limits::none())
+        .expect("ScVal always encodes")
+}
@@ -0,0 +1,83 @@
+use crate::error::DecodeError;
+use crate::types::Address;
+use crate::DecodedEvent;
+use serde_json::Value;
+use stellar_xdr::curr::ScVal;
+
+/// Contract event as returned by Stellar RPC `getEvents`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcEvent {
+    pub id: Option<String>,
+    pub ledger: Option<u32>,
+    pub contract_id: Option<Address>,
+    pub tx_hash: Option<String>,
+    pub decoded: DecodedEvent,
+}
+
+impl RpcEvent {
+    /// Decode a single `getEvents` entry.
+    ///
+    /// Accepts both the default base64 format (`topic`/`value`) and the JSON
+    /// format requested with `xdrFormat: "json"` (`topicJson`/`valueJson`).
+    pub fn from_json(event: &Value) -> Result<Self, DecodeError> {
+        let decoded = if let (Some(topics), Some(value)) = (event.get("topic"), event.get("value"))
+        {
+            let topics = topics
+                .as_array()
+                .ok_or(DecodeError::InvalidJson("topic must be an array".into()))?
+                .iter()
+                .map(|topic| {
+                    topic
+                        .as_str()
+                        .ok_or(DecodeError::InvalidJson("topic must be base64".into()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let value = value
+                .as_str()
+                .ok_or(DecodeError::InvalidJson("value must be base64".into()))?;
+            DecodedEvent::from_base64(&topics, value)?
+        } else if let (Some(topics), Some(value)) = (event.get("topicJson"), event.get("valueJson"))
+        {
+            let topics: Vec<ScVal> = serde_json::from_value(topics.clone())
+                .map_err(|e| DecodeError::InvalidJson(e.to_string()))?;
+            let value: ScVal = serde_json::from_value(value.clone())
+                .map_err(|e| DecodeError::InvalidJson(e.to_string()))?;
+            DecodedEvent::from_xdr(&topics, &value)?
+        } else {
+            return Err(DecodeError::InvalidJson("event has no topics".into()));
+        };
+
+        Ok(RpcEvent {
+            id: event.get("id").and_then(Value::as_str).map(Into::into),
+            ledger: event
+                .get("ledger")
+                .and_then(Value::as_u64)
+                .and_then(|ledger| ledger.try_into().ok()),
+            contract_id: event
+                .get("contractId")
+                .and_then(Value::as_str)
+                .map(Address::from_strkey)
+                .transpose()?,
+            tx_hash: event.get("txHash").and_then(Value::as_str).map(Into::into),
+            decoded,
+        })
+    }
+}
+
+/// Decode every event in a `getEvents` response.
+///
+/// Accepts the full JSON-RPC response, its `result` object, or a bare array
+/// of events.
+pub fn decode_get_events_response(json: &str) -> Result<Vec<RpcEvent>, DecodeError> {
+    let response: Value =
+        serde_json::from_str(json).map_err(|e| DecodeError::InvalidJson(e.to_string()))?;
+    let result = response.get("result").unwrap_or(&response);
+    let events = result.get("events").unwrap_or(result);
+
+    events
+        .as_array()
+        .ok_or(DecodeError::InvalidJson("events must be an array".into()))?
+        .iter()
+        .map(RpcEvent::from_json)
+        .collect()
+}
@@ -0,0 +1,199 @@
+use crate::error::DecodeError;
+use stellar_xdr::curr::{
+    Int128Parts, ScBytes, ScMap, ScMapEntry, ScString, ScSymbol, ScVal, ScVec, VecM,
+};
+
+/// Conversion between Rust values and their contract XDR representation
+pub trait ScValConvert: Sized {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError>;
+    fn to_sc_val(&self) -> ScVal;
+}
+
+impl ScValConvert for bool {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::Bool(value) => Ok(*value),
+            _ => Err(DecodeError::TypeMismatch("bool")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::Bool(*self)
+    }
+}
+
+impl ScValConvert for u32 {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::U32(value) => Ok(*value),
+            _ => Err(DecodeError::TypeMismatch("u32")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::U32(*self)
+    }
+}
+
+impl ScValConvert for u64 {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::U64(value) => Ok(*value),
+            _ => Err(DecodeError::TypeMismatch("u64")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::U64(*self)
+    }
+}
+
+impl ScValConvert for i128 {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::I128(Int128Parts { hi, lo }) => Ok(((*hi as i128) << 64) | (*lo as i128)),
+            _ => Err(DecodeError::TypeMismatch("i128")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::I128(Int128Parts {
+            hi: (*self >> 64) as i64,
+            lo: *self as u64,
+        })
+    }
+}
+
+impl ScValConvert for String {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::String(ScString(value)) => String::from_utf8(value.to_vec())
+                .map_err(|_| DecodeError::TypeMismatch("utf-8 string")),
+            _ => Err(DecodeError::TypeMismatch("string")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::String(ScString(
+            self.as_bytes()
+                .to_vec()
+                .try_into()
+                .expect("string exceeds XDR length limit"),
+        ))
+    }
+}
+
+impl ScValConvert for [u8; 32] {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::Bytes(ScBytes(bytes)) => bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| DecodeError::TypeMismatch("32 bytes")),
+            _ => Err(DecodeError::TypeMismatch("bytes")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::Bytes(ScBytes(self.to_vec().try_into().unwrap()))
+    }
+}
+
+impl<T: ScValConvert> ScValConvert for Vec<T> {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::Vec(Some(ScVec(items))) => items.iter().map(T::from_sc_val).collect(),
+            _ => Err(DecodeError::TypeMismatch("vec")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        let items: Vec<ScVal> = self.iter().map(T::to_sc_val).collect();
+        ScVal::Vec(Some(ScVec(items.try_into().unwrap())))
+    }
+}
+
+impl<T: ScValConvert> ScValConvert for Option<T> {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::Void => Ok(None),
+            _ => T::from_sc_val(val).map(Some),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        match self {
+            Some(value) => value.to_sc_val(),
+            None => ScVal::Void,
+        }
+    }
+}
+
+/// Build a symbol value
+pub(crate) fn symbol(name: &str) -> ScVal {
+    ScVal::Symbol(ScSymbol(
+        name.try_into().expect("symbol exceeds XDR length limit"),
+    ))
+}
+
+/// Read a symbol value
+pub(crate) fn symbol_name(val: &ScVal) -> Result<String, DecodeError> {
+    match val {
+        ScVal::Symbol(ScSymbol(name)) => {
+            String::from_utf8(name.to_vec()).map_err(|_| DecodeError::TypeMismatch("symbol"))
+        }
+        _ => Err(DecodeError::TypeMismatch("symbol")),
+    }
+}
+
+/// Fields of a `#[contracttype]` struct, encoded as a symbol-keyed map
+pub(crate) fn struct_fields(val: &ScVal) -> Result<&VecM<ScMapEntry>, DecodeError> {
+    match val {
+        ScVal::Map(Some(ScMap(entries))) => Ok(entries),
+        _ => Err(DecodeError::TypeMismatch("map")),
+    }
+}
+
+/// Decode a named field of a `#[contracttype]` struct
+pub(crate) fn field<T: ScValConvert>(
+    entries: &VecM<ScMapEntry>,
+    name: &'static str,
+) -> Result<T, DecodeError> {
+    let key = symbol(name);
+    entries
+        .iter()
+        .find(|entry| entry.key == key)
+        .ok_or(DecodeError::MissingField(name))
+        .and_then(|entry| T::from_sc_val(&entry.val))
+}
+
+/// Encode a `#[contracttype]` struct from its named fields
+pub(crate) fn struct_val(mut fields: Vec<(&'static str, ScVal)>) -> ScVal {
+    // Contract maps are sorted by key
+    fields.sort_by_key(|(name, _)| *name);
+    let entries: Vec<ScMapEntry> = fields
+        .into_iter()
+        .map(|(name, val)| ScMapEntry {
+            key: symbol(name),
+            val,
+        })
+        .collect();
+    ScVal::Map(Some(ScMap(entries.try_into().unwrap())))
+}
+
+/// Variant name and values of a `#[contracttype]` enum
+pub(crate) fn enum_parts(val: &ScVal) -> Result<(String, &[ScVal]), DecodeError> {
+    match val {
+        ScVal::Vec(Some(ScVec(items))) if !items.is_empty() => {
+            Ok((symbol_name(&items[0])?, &items[1..]))
+        }
+        _ => Err(DecodeError::TypeMismatch("enum")),
+    }
+}
+
+/// Encode a `#[contracttype]` enum variant with its values
+pub(crate) fn enum_val(name: &str, values: Vec<ScVal>) -> ScVal {
+    let mut items = vec![symbol(name)];
+    items.extend(values);
+    ScVal::Vec(Some(ScVec(items.try_into().unwrap())))
+}
@@ -0,0 +1,345 @@
+use crate::error::DecodeError;
+use crate::scval::{self, ScValConvert};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use stellar_xdr::curr::{ScAddress, ScVal};
+
+/// Account or contract address in strkey form (`G...` / `C...`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Address(String);
+
+impl Address {
+    /// Parse and validate a strkey address
+    pub fn from_strkey(strkey: &str) -> Result<Self, DecodeError> {
+        strkey
+            .parse::<ScAddress>()
+            .map(|address| Address(address.to_string()))
+            .map_err(|_| DecodeError::TypeMismatch("strkey address"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = DecodeError;
+
+    fn try_from(strkey: String) -> Result<Self, Self::Error> {
+        Address::from_strkey(&strkey)
+    }
+}
+
+impl From<Address> for String {
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+/// Symbol of up to 32 ASCII letters, digits and underscores
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Symbol(String);
+
+impl Symbol {
+    /// Validate a symbol name
+    pub fn new(name: &str) -> Result<Self, DecodeError> {
+        if name.len() > 32
+            || !name
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+        {
+            return Err(DecodeError::TypeMismatch("symbol"));
+        }
+        Ok(Symbol(name.into()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for Symbol {
+    type Error = DecodeError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Symbol::new(&name)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(symbol: Symbol) -> Self {
+        symbol.0
+    }
+}
+
+impl ScValConvert for Symbol {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        Symbol::new(&scval::symbol_name(val)?)
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        scval::symbol(&self.0)
+    }
+}
+
+impl ScValConvert for Address {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::Address(address) => Ok(Address(address.to_string())),
+            _ => Err(DecodeError::TypeMismatch("address")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        ScVal::Address(
+            self.0
+                .parse()
+                .expect("address is validated on construction"),
+        )
+    }
+}
+
+/// Mirror of the contract's `DisputeResolution`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisputeResolution {
+    PayContributor,
+    RefundCreator,
+    PartialPayment(i128),
+    PercentageSplit(u32),
+    SplitWithArbiterFee(u32, i128),
+    MultiPayout(Vec<DisputePayout>),
+}
+
+impl ScValConvert for DisputeResolution {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        let (name, values) = scval::enum_parts(val)?;
+        match (name.as_str(), values) {
+            ("PayContributor", []) => Ok(DisputeResolution::PayContributor),
+            ("RefundCreator", []) => Ok(DisputeResolution::RefundCreator),
+            ("PartialPayment", [amount]) => Ok(DisputeResolution::PartialPayment(
+                i128::from_sc_val(amount)?,
+            )),
+            ("PercentageSplit", [bps]) => {
+                Ok(DisputeResolution::PercentageSplit(u32::from_sc_val(bps)?))
+            }
+            ("SplitWithArbiterFee", [bps, fee]) => Ok(DisputeResolution::SplitWithArbiterFee(
+                u32::from_sc_val(bps)?,
+                i128::from_sc_val(fee)?,
+            )),
+            ("MultiPayout", [payouts]) => {
+                Ok(DisputeResolution::MultiPayout(Vec::from_sc_val(payouts)?))
+            }
+            _ => Err(DecodeError::TypeMismatch("DisputeResolution")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        match self {
+            DisputeResolution::PayContributor => scval::enum_val("PayContributor", vec![]),
+            DisputeResolution::RefundCreator => scval::enum_val("RefundCreator", vec![]),
+            DisputeResolution::PartialPayment(amount) => {
+                scval::enum_val("PartialPayment", vec![amount.to_sc_val()])
+            }
+            DisputeResolution::PercentageSplit(bps) => {
+                scval::enum_val("PercentageSplit", vec![bps.to_sc_val()])
+            }
+            DisputeResolution::SplitWithArbiterFee(bps, fee) => scval::enum_val(
+                "SplitWithArbiterFee",
+                vec![bps.to_sc_val(), fee.to_sc_val()],
+            ),
+            DisputeResolution::MultiPayout(payouts) => {
+                scval::enum_val("MultiPayout", vec![payouts.to_sc_val()])
+            }
+        }
+    }
+}
+
+/// Mirror of the contract's `DisputePayout`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisputePayout {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+impl ScValConvert for DisputePayout {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        let fields = scval::struct_fields(val)?;
+        Ok(DisputePayout {
+            recipient: scval::field(fields, "recipient")?,
+            amount: scval::field(fields, "amount")?,
+        })
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        scval::struct_val(vec![
+            ("recipient", self.recipient.to_sc_val()),
+            ("amount", self.amount.to_sc_val()),
+        ])
+    }
+}
+
+/// Mirror of the contract's `DisputeTimeoutPolicy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisputeTimeoutPolicy {
+    PayContributor,
+    RefundCreator,
+    SplitEvenly,
+}
+
+impl ScValConvert for DisputeTimeoutPolicy {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        let (name, values) = scval::enum_parts(val)?;
+        match (name.as_str(), values) {
+            ("PayContributor", []) => Ok(DisputeTimeoutPolicy::PayContributor),
+            ("RefundCreator", []) => Ok(DisputeTimeoutPolicy::RefundCreator),
+            ("SplitEvenly", []) => Ok(DisputeTimeoutPolicy::SplitEvenly),
+            _ => Err(DecodeError::TypeMismatch("DisputeTimeoutPolicy")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        let name = match self {
+            DisputeTimeoutPolicy::PayContributor => "PayContributor",
+            DisputeTimeoutPolicy::RefundCreator => "RefundCreator",
+            DisputeTimeoutPolicy::SplitEvenly => "SplitEvenly",
+        };
+        scval::enum_val(name, vec![])
+    }
+}
+
+/// Mirror of the contract's `Difficulty`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    #[default]
+    Unrated,
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl ScValConvert for Difficulty {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        let (name, values) = scval::enum_parts(val)?;
+        match (name.as_str(), values) {
+            ("Unrated", []) => Ok(Difficulty::Unrated),
+            ("Beginner", []) => Ok(Difficulty::Beginner),
+            ("Intermediate", []) => Ok(Difficulty::Intermediate),
+            ("Advanced", []) => Ok(Difficulty::Advanced),
+            _ => Err(DecodeError::TypeMismatch("Difficulty")),
+        }
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        let name = match self {
+            Difficulty::Unrated => "Unrated",
+            Difficulty::Beginner => "Beginner",
+            Difficulty::Intermediate => "Intermediate",
+            Difficulty::Advanced => "Advanced",
+        };
+        scval::enum_val(name, vec![])
+    }
+}
+
+/// Mirror of the contract's `Config`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Config {
+    pub min_amount: i128,
+    pub max_amount: i128,
+    pub task_id_min_length: u32,
+    pub task_id_max_length: u32,
+    pub dispute_reason_min_length: u32,
+    pub dispute_reason_max_length: u32,
+    pub issue_url_max_length: u32,
+}
+
+impl Default for Config {
+    /// Limits the contract sets on initialization
+    fn default() -> Self {
+        Config {
+            min_amount: 100_000,
+            max_amount: 1_000_000_000 * 10_000_000,
+            task_id_min_length: 25,
+            task_id_max_length: 25,
+            dispute_reason_min_length: 10,
+            dispute_reason_max_length: 500,
+            issue_url_max_length: 192,
+        }
+    }
+}
+
+impl ScValConvert for Config {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        let fields = scval::struct_fields(val)?;
+        Ok(Config {
+            min_amount: scval::field(fields, "min_amount")?,
+            max_amount: scval::field(fields, "max_amount")?,
+            task_id_min_length: scval::field(fields, "task_id_min_length")?,
+            task_id_max_length: scval::field(fields, "task_id_max_length")?,
+            dispute_reason_min_length: scval::field(fields, "dispute_reason_min_length")?,
+            dispute_reason_max_length: scval::field(fields, "dispute_reason_max_length")?,
+            issue_url_max_length: scval::field(fields, "issue_url_max_length")?,
+        })
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        scval::struct_val(vec![
+            ("min_amount", self.min_amount.to_sc_val()),
+            ("max_amount", self.max_amount.to_sc_val()),
+            ("task_id_min_length", self.task_id_min_length.to_sc_val()),
+            ("task_id_max_length", self.task_id_max_length.to_sc_val()),
+            (
+                "dispute_reason_min_length",
+                self.dispute_reason_min_length.to_sc_val(),
+            ),
+            (
+                "dispute_reason_max_length",
+                self.dispute_reason_max_length.to_sc_val(),
+            ),
+            (
+                "issue_url_max_length",
+                self.issue_url_max_length.to_sc_val(),
+            ),
+        ])
+    }
+}
+
+/// Mirror of the contract's `EscrowOptions`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EscrowOptions {
+    pub labels_hash: Option<[u8; 32]>,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
+}
+
+impl ScValConvert for EscrowOptions {
+    fn from_sc_val(val: &ScVal) -> Result<Self, DecodeError> {
+        let fields = scval::struct_fields(val)?;
+        Ok(EscrowOptions {
+            labels_hash: scval::field(fields, "labels_hash")?,
+            tags: scval::field(fields, "tags")?,
+            difficulty: scval::field(fields, "difficulty")?,
+        })
+    }
+
+    fn to_sc_val(&self) -> ScVal {
+        scval::struct_val(vec![
+            ("labels_hash", self.labels_hash.to_sc_val()),
+            ("tags", self.tags.to_sc_val()),
+            ("difficulty", self.difficulty.to_sc_val()),
+        ])
+    }
+}
@@ -0,0 +1,521 @@
+use devasign_escrow_events::{
+    decode_get_events_response, xdr::ScVal, Address, Config, ContractUpgradedEvent, DecodeError,
+    DecodedEvent, Difficulty, DisputeInitiatedEvent, DisputePayout, DisputeResolution,
+    DisputeResolvedEvent, DisputeTimeoutPolicy, EscrowCreatedEvent, EscrowEvent,
+    FundsReleasedEvent, RpcEvent, Symbol, TaskCompletedEvent, TaskMetadataUpdatedEvent,
+    TaskOwnershipCancelledEvent, TaskOwnershipTransferredEvent, TaskTagsUpdatedEvent,
+    EVENT_SCHEMA_VERSION,
+};
+use devasign_task_escrow::{self as contract, TaskEscrowContract, TaskEscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Env, String, TryFromVal,
+};
+
+const BOUNTY: i128 = 1000_0000000;
+const ISSUE_URL: &str = "https://github.com/owner/repo/issues/1";
+
+/// Raw topics and data of a contract event, as stored on the ledger
+struct RawEvent {
+    topics: Vec<ScVal>,
+    data: ScVal,
+}
+
+struct Harness {
+    env: Env,
+    admin: soroban_sdk::Address,
+    contract_id: soroban_sdk::Address,
+    client: TaskEscrowContractClient<'static>,
+    usdc_token: StellarAssetClient<'static>,
+    events: Vec<RawEvent>,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let admin = soroban_sdk::Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let usdc_token = StellarAssetClient::new(&env, &usdc_address);
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let client = TaskEscrowContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &usdc_address);
+
+        let mut harness = Harness {
+            env,
+            admin,
+            contract_id,
+            client,
+            usdc_token,
+            events: Vec::new(),
+        };
+        harness.record();
+        harness
+    }
+
+    /// Capture the contract events of the last invocation
+    fn record(&mut self) {
+        for (address, topics, data) in self.env.events().all().iter() {
+            if address != self.contract_id {
+                continue;
+            }
+            self.events.push(RawEvent {
+                topics: topics
+                    .iter()
+                    .map(|topic| ScVal::try_from_val(&self.env, &topic).unwrap())
+                    .collect(),
+                data: ScVal::try_from_val(&self.env, &data).unwrap(),
+            });
+        }
+    }
+
+    fn task_id(&self, id: &str) -> String {
+        String::from_str(&self.env, id)
+    }
+
+    fn create_task(&mut self, creator: &soroban_sdk::Address, id: &str) -> String {
+        let task_id = self.task_id(id);
+        self.usdc_token.mint(creator, &BOUNTY);
+        self.client.create_escrow(
+            creator,
+            &task_id,
+            &String::from_str(&self.env, ISSUE_URL),
+            &BOUNTY,
+        );
+        self.record();
+        task_id
+    }
+}
+
+/// Convert an SDK address into the decoder's strkey address
+fn address(env: &Env, address: &soroban_sdk::Address) -> Address {
+    match ScVal::try_from_val(env, &address.to_val()).unwrap() {
+        ScVal::Address(address) => Address::from_strkey(&address.to_string()).unwrap(),
+        _ => unreachable!(),
+    }
+}
+
+/// Drive the contract through every reachable event
+fn full_scenario() -> Harness {
+    let mut h = Harness::new();
+    let creator = soroban_sdk::Address::generate(&h.env);
+    let contributor = soroban_sdk::Address::generate(&h.env);
+
+    h.client
+        .set_dispute_timeout(&604_800, &contract::DisputeTimeoutPolicy::SplitEvenly);
+    h.record();
+    h.client.set_arbitration_fee(&100);
+    h.record();
+    h.client
+        .set_paused(&true, &String::from_str(&h.env, "maintenance"));
+    h.record();
+    h.client
+        .set_paused(&false, &String::from_str(&h.env, "maintenance"));
+    h.record();
+
+    let paid = h.create_task(&creator, "clpaidtask000000000000001");
+    h.usdc_token.mint(&creator, &BOUNTY);
+    h.client.increase_bounty(&creator, &paid, &BOUNTY);
+    h.record();
+    h.client.decrease_bounty(&creator, &paid, &BOUNTY);
+    h.record();
+    h.client.update_task_tags(
+        &creator,
+        &paid,
+        &soroban_sdk::vec![&h.env, soroban_sdk::symbol_short!("rust")],
+        &contract::Difficulty::Beginner,
+    );
+    h.record();
+    h.client.update_task_metadata(
+        &creator,
+        &paid,
+        &String::from_str(&h.env, ISSUE_URL),
+        &Some(soroban_sdk::BytesN::from_array(&h.env, &[7; 32])),
+    );
+    h.record();
+    h.client.assign_contributor(&paid, &contributor);
+    h.record();
+    h.client.approve_completion(&paid);
+    h.record();
+
+    let disputed = h.create_task(&creator, "cldisputetask000000000001");
+    h.client.assign_contributor(&disputed, &contributor);
+    h.record();
+    let reason = String::from_str(&h.env, "Work quality does not meet requirements");
+    h.client.dispute_task(&creator, &disputed, &reason);
+    h.record();
+    h.client.withdraw_dispute(&creator, &disputed);
+    h.record();
+    h.client.dispute_task(&contributor, &disputed, &reason);
+    h.record();
+    let payouts = soroban_sdk::vec![
+        &h.env,
+        contract::DisputePayout {
+            recipient: contributor.clone(),
+            amount: 300_0000000,
+        }
+    ];
+    h.client.resolve_dispute(
+        &disputed,
+        &contract::DisputeResolution::MultiPayout(payouts),
+    );
+    h.record();
+    h.client.claim_arbiter_fees(&h.admin);
+    h.record();
+
+    let refunded = h.create_task(&creator, "clrefundtask0000000000001");
+    let new_owner = soroban_sdk::Address::generate(&h.env);
+    h.client.transfer_task_ownership(&refunded, &new_owner);
+    h.record();
+    h.client.cancel_task_ownership_transfer(&refunded);
+    h.record();
+    h.client.transfer_task_ownership(&refunded, &new_owner);
+    h.record();
+    h.client.accept_task_ownership(&refunded);
+    h.record();
+    h.client.refund(&refunded);
+    h.record();
+
+    let new_token = h
+        .env
+        .register_stellar_asset_contract_v2(h.admin.clone())
+        .address();
+    h.client.update_usdc_token(&new_token);
+    h.record();
+    h.client.set_admin(&soroban_sdk::Address::generate(&h.env));
+    h.record();
+    let mut config = h.client.get_config();
+    config.task_id_min_length = 20;
+    config.min_amount = 1_0000000;
+    h.client.update_config(&config);
+    h.record();
+
+    h
+}
+
+#[test]
+fn test_decode_every_contract_event() {
+    let h = full_scenario();
+    let decoded: Vec<DecodedEvent> = h
+        .events
+        .iter()
+        .map(|raw| DecodedEvent::from_xdr(&raw.topics, &raw.data).unwrap())
+        .collect();
+
+    let names: Vec<&str> = decoded.iter().map(|event| event.event.name()).collect();
+    assert_eq!(
+        names,
+        [
+            "initialized_event",
+            "dispute_timeout_updated_event",
+            "arbitration_fee_updated_event",
+            "pause_state_changed_event",
+            "pause_state_changed_event",
+            "escrow_created_event",
+            "bounty_increased_event",
+            "bounty_decreased_event",
+            "task_tags_updated_event",
+            "task_metadata_updated_event",
+            "contributor_assigned_event",
+            "funds_released_event",
+            "escrow_created_event",
+            "contributor_assigned_event",
+            "dispute_initiated_event",
+            "dispute_withdrawn_event",
+            "dispute_initiated_event",
+            "arbiter_fee_credited_event",
+            "dispute_resolved_event",
+            "arbiter_fees_claimed_event",
+            "escrow_created_event",
+            "task_ownership_proposed_event",
+            "task_ownership_cancelled_event",
+            "task_ownership_proposed_event",
+            "task_ownership_transferred_event",
+            "refund_processed_event",
+            "token_updated_event",
+            "admin_changed_event",
+            "config_updated_event",
+        ]
+    );
+
+    assert_eq!(EVENT_SCHEMA_VERSION, contract::EVENT_SCHEMA_VERSION);
+    for event in &decoded {
+        assert_eq!(event.schema_version, EVENT_SCHEMA_VERSION);
+        assert_eq!(event.contract_version, TaskEscrowContract::version());
+    }
+}
+
+#[test]
+fn test_decoded_fields_match_contract_values() {
+    let h = full_scenario();
+    let decoded: Vec<EscrowEvent> = h
+        .events
+        .iter()
+        .map(|raw| {
+            DecodedEvent::from_xdr(&raw.topics, &raw.data)
+                .unwrap()
+                .event
+        })
+        .collect();
+
+    let escrow = h.client.get_escrow(&h.task_id("clpaidtask000000000000001"));
+    assert_eq!(
+        decoded[5],
+        EscrowEvent::EscrowCreated(EscrowCreatedEvent {
+            task_id: "clpaidtask000000000000001".into(),
+            issue_url: ISSUE_URL.into(),
+            labels_hash: None,
+            creator: address(&h.env, &escrow.creator),
+            bounty_amount: BOUNTY,
+            tags: vec![],
+            difficulty: Difficulty::Unrated,
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        decoded[8],
+        EscrowEvent::TaskTagsUpdated(TaskTagsUpdatedEvent {
+            task_id: "clpaidtask000000000000001".into(),
+            creator: address(&h.env, &escrow.creator),
+            tags: vec![Symbol::new("rust").unwrap()],
+            difficulty: Difficulty::Beginner,
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        decoded[9],
+        EscrowEvent::TaskMetadataUpdated(TaskMetadataUpdatedEvent {
+            task_id: "clpaidtask000000000000001".into(),
+            creator: address(&h.env, &escrow.creator),
+            old_issue_url: ISSUE_URL.into(),
+            new_issue_url: ISSUE_URL.into(),
+            old_labels_hash: None,
+            new_labels_hash: Some([7; 32]),
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        decoded[11],
+        EscrowEvent::FundsReleased(FundsReleasedEvent {
+            task_id: "clpaidtask000000000000001".into(),
+            contributor: address(&h.env, escrow.contributor.as_ref().unwrap()),
+            amount: BOUNTY,
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        decoded[14],
+        EscrowEvent::DisputeInitiated(DisputeInitiatedEvent {
+            task_id: "cldisputetask000000000001".into(),
+            disputing_party: address(&h.env, &escrow.creator),
+            reason: "Work quality does not meet requirements".into(),
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        decoded[18],
+        EscrowEvent::DisputeResolved(DisputeResolvedEvent {
+            task_id: "cldisputetask000000000001".into(),
+            resolution: DisputeResolution::MultiPayout(vec![DisputePayout {
+                recipient: address(&h.env, escrow.contributor.as_ref().unwrap()),
+                amount: 300_0000000,
+            }]),
+            resolved_by: address(&h.env, &h.admin),
+            timestamp: 1_000,
+        })
+    );
+
+    let refunded = h.client.get_escrow(&h.task_id("clrefundtask0000000000001"));
+    assert_eq!(
+        decoded[22],
+        EscrowEvent::TaskOwnershipCancelled(TaskOwnershipCancelledEvent {
+            task_id: "clrefundtask0000000000001".into(),
+            creator: address(&h.env, &escrow.creator),
+            pending_creator: address(&h.env, &refunded.creator),
+            timestamp: 1_000,
+        })
+    );
+    assert_eq!(
+        decoded[24],
+        EscrowEvent::TaskOwnershipTransferred(TaskOwnershipTransferredEvent {
+            task_id: "clrefundtask0000000000001".into(),
+            old_creator: address(&h.env, &escrow.creator),
+            new_creator: address(&h.env, &refunded.creator),
+            timestamp: 1_000,
+        })
+    );
+
+    match &decoded[1] {
+        EscrowEvent::DisputeTimeoutUpdated(event) => {
+            assert_eq!(event.timeout, 604_800);
+            assert_eq!(event.policy, DisputeTimeoutPolicy::SplitEvenly);
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    match decoded.last().unwrap() {
+        EscrowEvent::ConfigUpdated(event) => {
+            assert_eq!(
+                event.config,
+                Config {
+                    min_amount: 1_0000000,
+                    task_id_min_length: 20,
+                    ..Config::default()
+                }
+            );
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[test]
+fn test_xdr_round_trip() {
+    let h = full_scenario();
+
+    for raw in &h.events {
+        let decoded = DecodedEvent::from_xdr(&raw.topics, &raw.data).unwrap();
+        assert_eq!(decoded.topics(), raw.topics);
+        assert_eq!(decoded.data(), raw.data);
+
+        let (topics, data) = decoded.to_base64();
+        assert_eq!(DecodedEvent::from_base64(&topics, &data).unwrap(), decoded);
+    }
+}
+
+#[test]
+fn test_serde_json_round_trip() {
+    let h = full_scenario();
+
+    for raw in &h.events {
+        let decoded = DecodedEvent::from_xdr(&raw.topics, &raw.data).unwrap();
+        let json = serde_json::to_string(&decoded).unwrap();
+        let parsed: DecodedEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, decoded);
+    }
+}
+
+#[test]
+fn test_events_outside_scenario_round_trip() {
+    let env = Env::default();
+    let admin = address(&env, &soroban_sdk::Address::generate(&env));
+
+    let events: [EscrowEvent; 2] = [
+        TaskCompletedEvent {
+            task_id: "clcompletetask00000000001".into(),
+            contributor: admin.clone(),
+            timestamp: 42,
+        }
+        .into(),
+        ContractUpgradedEvent {
+            new_wasm_hash: [7; 32],
+            admin,
+            timestamp: 42,
+        }
+        .into(),
+    ];
+
+    for event in events {
+        let decoded = DecodedEvent::new(TaskEscrowContract::version(), event);
+        let parsed = DecodedEvent::from_xdr(&decoded.topics(), &decoded.data()).unwrap();
+        assert_eq!(parsed, decoded);
+    }
+}
+
+#[test]
+fn test_decode_rpc_get_events_response() {
+    let h = full_scenario();
+    let contract_id = address(&h.env, &h.contract_id).to_string();
+
+    // Default base64 format
+    let base64_events: Vec<serde_json::Value> = h
+        .events
+        .iter()
+        .enumerate()
+        .map(|(i, raw)| {
+            let decoded = DecodedEvent::from_xdr(&raw.topics, &raw.data).unwrap();
+            let (topic, value) = decoded.to_base64();
+            serde_json::json!({
+                "type": "contract",
+                "ledger": 100 + i,
+                "contractId": contract_id,
+                "id": format!("{i:019}-0000000001"),
+                "txHash": "ab".repeat(32),
+                "inSuccessfulContractCall": true,
+                "topic": topic,
+                "value": value,
+            })
+        })
+        .collect();
+    let response = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": { "events": base64_events, "latestLedger": 200 },
+    });
+    let decoded = decode_get_events_response(&response.to_string()).unwrap();
+    assert_eq!(decoded.len(), h.events.len());
+    assert_eq!(decoded[3].ledger, Some(103));
+    assert_eq!(
+        decoded[3].contract_id.as_ref().unwrap().as_str(),
+        contract_id
+    );
+
+    // JSON XDR format
+    for (raw, expected) in h.events.iter().zip(decoded.iter()) {
+        let event = serde_json::json!({
+            "topicJson": raw.topics,
+            "valueJson": raw.data,
+        });
+        let parsed = RpcEvent::from_json(&event).unwrap();
+        assert_eq!(parsed.decoded, expected.decoded);
+        assert_eq!(parsed.ledger, None);
+    }
+}
+
+#[test]
+fn test_decode_errors() {
+    let h = full_scenario();
+    let raw = &h.events[0];
+
+    // Missing version topics
+    assert_eq!(
+        DecodedEvent::from_xdr(&raw.topics[..1], &raw.data),
+        Err(DecodeError::InvalidTopics)
+    );
+
+    // Unsupported schema version
+    let mut topics = raw.topics.clone();
+    topics[1] = ScVal::U32(EVENT_SCHEMA_VERSION + 1);
+    assert_eq!(
+        DecodedEvent::from_xdr(&topics, &raw.data),
+        Err(DecodeError::UnsupportedSchemaVersion(
+            EVENT_SCHEMA_VERSION + 1
+        ))
+    );
+
+    // Unknown event name
+    let mut topics = raw.topics.clone();
+    topics[0] = ScVal::Symbol("unknown_event".try_into().unwrap());
+    assert_eq!(
+        DecodedEvent::from_xdr(&topics, &raw.data),
+        Err(DecodeError::UnknownEvent("unknown_event".into()))
+    );
+
+    // Data of another event
+    let mut topics = raw.topics.clone();
+    topics[0] = ScVal::Symbol("funds_released_event".try_into().unwrap());
+    assert_eq!(
+        DecodedEvent::from_xdr(&topics, &raw.data),
+        Err(DecodeError::MissingField("task_id"))
+    );
+
+    // Malformed base64
+    assert!(matches!(
+        DecodedEvent::from_base64(&["not-xdr"], "not-xdr"),
+        Err(DecodeError::InvalidXdr(_))
+    ));
+}
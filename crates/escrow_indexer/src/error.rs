@@ -0,0 +1,36 @@
+use core::fmt;
+use devasign_escrow_events::DecodeError;
+
+/// Errors returned when an event stream is inconsistent with the projection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexError {
+    /// Event could not be decoded
+    Decode(DecodeError),
+    /// Event refers to a task that was never created
+    UnknownTask(String),
+    /// Escrow was created twice for the same task
+    DuplicateTask(String),
+    /// Dispute event arrived for a task without an open dispute
+    NoActiveDispute(String),
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::Decode(err) => write!(f, "decode error: {err}"),
+            IndexError::UnknownTask(task_id) => write!(f, "unknown task: {task_id}"),
+            IndexError::DuplicateTask(task_id) => write!(f, "duplicate task: {task_id}"),
+            IndexError::NoActiveDispute(task_id) => {
+                write!(f, "no active dispute for task: {task_id}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IndexError {}
+
+impl From<DecodeError> for IndexError {
+    fn from(err: DecodeError) -> Self {
+        IndexError::Decode(err)
+    }
+}
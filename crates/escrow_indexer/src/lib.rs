@@ -0,0 +1,16 @@
+//! Event indexer for the DevAsign task escrow contract.
+//!
+//! Consumes decoded contract events in ledger order and maintains an
+//! in-memory projection of every escrow, its dispute history, the arbiter
+//! fee balances and the contract configuration. The projection holds the same
+//! data as the contract's own getters, which shows that events alone are
+//! sufficient to reconstruct on-chain state.
+
+mod error;
+mod projection;
+
+pub use error::IndexError;
+pub use projection::{
+    ContractConfig, DisputeEntry, DisputeOutcome, EscrowState, Forge, IssueRef, Projection,
+    TaskStatus,
+};
@@ -0,0 +1,45 @@
+//! Rebuild escrow state from Stellar RPC `getEvents` responses.
+//!
+//! Usage: `devasign_escrow_indexer [RESPONSE.json]...`
+//!
+//! Each file holds one `getEvents` response; pages are applied in the order
+//! given. Reads a single response from stdin when no file is given and prints
+//! the resulting projection as JSON.
+
+use devasign_escrow_events::decode_get_events_response;
+use devasign_escrow_indexer::Projection;
+use std::io::Read;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    let responses = if paths.is_empty() {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        vec![input]
+    } else {
+        paths
+            .iter()
+            .map(std::fs::read_to_string)
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut projection = Projection::new();
+    for response in &responses {
+        let events = decode_get_events_response(response)?;
+        projection.apply_all(events.iter().map(|event| &event.decoded))?;
+    }
+
+    println!("{}", serde_json::to_string_pretty(&projection)?);
+    Ok(())
+}
@@ -0,0 +1,414 @@
+use crate::error::IndexError;
+use devasign_escrow_events::{
+    Address, Config, DecodedEvent, Difficulty, DisputeResolution, DisputeTimeoutPolicy,
+    EscrowEvent, Symbol,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Dispute timeout set by the contract on initialization
+pub const DEFAULT_DISPUTE_TIMEOUT: u64 = 30 * 24 * 60 * 60;
+
+/// Mirror of the contract's `TaskStatus`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TaskStatus {
+    Open,
+    InProgress,
+    Completed,
+    Disputed,
+    Cancelled,
+}
+
+/// Mirror of the contract's `Forge`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Forge {
+    Unknown,
+    GitHub,
+}
+
+/// Mirror of the contract's `IssueRef`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueRef {
+    pub forge: Forge,
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub labels_hash: Option<[u8; 32]>,
+}
+
+impl IssueRef {
+    /// Parse a canonical `https://github.com/{owner}/{repo}/issues/{number}`
+    /// URL as emitted by the contract; other URLs get an unknown forge
+    fn parse(issue_url: &str, labels_hash: Option<[u8; 32]>) -> Self {
+        let segments: Vec<&str> = issue_url
+            .strip_prefix("https://github.com/")
+            .map(|path| path.split('/').collect())
+            .unwrap_or_default();
+        match segments.as_slice() {
+            [owner, repo, "issues", number] if number.parse::<u64>().is_ok() => IssueRef {
+                forge: Forge::GitHub,
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                number: number.parse().unwrap(),
+                labels_hash,
+            },
+            _ => IssueRef {
+                forge: Forge::Unknown,
+                owner: String::new(),
+                repo: String::new(),
+                number: 0,
+                labels_hash,
+            },
+        }
+    }
+}
+
+/// Projected state of a single escrow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EscrowState {
+    pub task_id: String,
+    pub issue_url: String,
+    pub issue: IssueRef,
+    pub creator: Address,
+    pub pending_creator: Option<Address>,
+    pub contributor: Option<Address>,
+    pub bounty_amount: i128,
+    pub status: TaskStatus,
+    pub created_at: u64,
+    pub completed_at: Option<u64>,
+    pub disputed_at: Option<u64>,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
+}
+
+/// Mirror of the contract's `DisputeOutcome`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum DisputeOutcome {
+    Pending,
+    Withdrawn,
+    Resolved(DisputeResolution),
+}
+
+/// Projected entry of a task's dispute history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DisputeEntry {
+    pub index: u32,
+    pub disputing_party: Address,
+    pub reason: String,
+    pub initiated_at: u64,
+    pub outcome: DisputeOutcome,
+    pub resolved_by: Option<Address>,
+    pub closed_at: Option<u64>,
+}
+
+/// Projected contract configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractConfig {
+    pub admin: Option<Address>,
+    pub usdc_token: Option<Address>,
+    pub paused: bool,
+    pub dispute_timeout: u64,
+    pub dispute_timeout_policy: DisputeTimeoutPolicy,
+    pub arbitration_fee: u32,
+    pub limits: Config,
+    pub contract_version: u64,
+    pub wasm_hash: Option<[u8; 32]>,
+}
+
+impl Default for ContractConfig {
+    fn default() -> Self {
+        ContractConfig {
+            admin: None,
+            usdc_token: None,
+            paused: false,
+            dispute_timeout: DEFAULT_DISPUTE_TIMEOUT,
+            dispute_timeout_policy: DisputeTimeoutPolicy::RefundCreator,
+            arbitration_fee: 0,
+            limits: Config::default(),
+            contract_version: 0,
+            wasm_hash: None,
+        }
+    }
+}
+
+/// In-memory projection of the escrow contract built from its events
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Projection {
+    config: ContractConfig,
+    escrows: BTreeMap<String, EscrowState>,
+    /// Task IDs indexed under each `owner/repo`, in the order the contract indexes them
+    repo_tasks: BTreeMap<String, Vec<String>>,
+    disputes: BTreeMap<String, Vec<DisputeEntry>>,
+    arbiter_balances: BTreeMap<Address, i128>,
+    contract_balance: i128,
+    /// Fees credited for a dispute that is about to be resolved
+    #[serde(skip)]
+    pending_fees: BTreeMap<String, i128>,
+}
+
+impl Projection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a sequence of events in ledger order
+    pub fn apply_all<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = &'a DecodedEvent>,
+    ) -> Result<(), IndexError> {
+        events.into_iter().try_for_each(|event| self.apply(event))
+    }
+
+    /// Apply a single event
+    pub fn apply(&mut self, event: &DecodedEvent) -> Result<(), IndexError> {
+        self.config.contract_version = event.contract_version;
+
+        match &event.event {
+            EscrowEvent::Initialized(e) => {
+                self.config.admin = Some(e.admin.clone());
+                self.config.usdc_token = Some(e.usdc_token.clone());
+            }
+            EscrowEvent::AdminChanged(e) => {
+                self.config.admin = Some(e.new_admin.clone());
+            }
+            EscrowEvent::PauseStateChanged(e) => {
+                self.config.paused = e.paused;
+            }
+            EscrowEvent::TokenUpdated(e) => {
+                self.config.usdc_token = Some(e.new_token.clone());
+            }
+            EscrowEvent::DisputeTimeoutUpdated(e) => {
+                self.config.dispute_timeout = e.timeout;
+                self.config.dispute_timeout_policy = e.policy;
+            }
+            EscrowEvent::ArbitrationFeeUpdated(e) => {
+                self.config.arbitration_fee = e.fee_basis_points;
+            }
+            EscrowEvent::ConfigUpdated(e) => {
+                self.config.limits = e.config.clone();
+            }
+            EscrowEvent::ContractUpgraded(e) => {
+                self.config.wasm_hash = Some(e.new_wasm_hash);
+            }
+            EscrowEvent::EscrowCreated(e) => {
+                if self.escrows.contains_key(&e.task_id) {
+                    return Err(IndexError::DuplicateTask(e.task_id.clone()));
+                }
+                self.escrows.insert(
+                    e.task_id.clone(),
+                    EscrowState {
+                        task_id: e.task_id.clone(),
+                        issue_url: e.issue_url.clone(),
+                        issue: IssueRef::parse(&e.issue_url, e.labels_hash),
+                        creator: e.creator.clone(),
+                        pending_creator: None,
+                        contributor: None,
+                        bounty_amount: e.bounty_amount,
+                        status: TaskStatus::Open,
+                        created_at: e.timestamp,
+                        completed_at: None,
+                        disputed_at: None,
+                        tags: e.tags.clone(),
+                        difficulty: e.difficulty,
+                    },
+                );
+                self.index_repo(&e.task_id);
+                self.contract_balance += e.bounty_amount;
+            }
+            EscrowEvent::TaskMetadataUpdated(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.issue_url = e.new_issue_url.clone();
+                escrow.issue = IssueRef::parse(&e.new_issue_url, e.new_labels_hash);
+                self.index_repo(&e.task_id);
+            }
+            EscrowEvent::TaskOwnershipProposed(e) => {
+                self.escrow_mut(&e.task_id)?.pending_creator = Some(e.pending_creator.clone());
+            }
+            EscrowEvent::TaskOwnershipTransferred(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.creator = e.new_creator.clone();
+                escrow.pending_creator = None;
+            }
+            EscrowEvent::TaskOwnershipCancelled(e) => {
+                self.escrow_mut(&e.task_id)?.pending_creator = None;
+            }
+            EscrowEvent::TaskTagsUpdated(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.tags = e.tags.clone();
+                escrow.difficulty = e.difficulty;
+            }
+            EscrowEvent::BountyIncreased(e) => {
+                self.escrow_mut(&e.task_id)?.bounty_amount = e.new_total_amount;
+                self.contract_balance += e.added_amount;
+            }
+            EscrowEvent::BountyDecreased(e) => {
+                self.escrow_mut(&e.task_id)?.bounty_amount = e.new_total_amount;
+                self.contract_balance -= e.subtracted_amount;
+            }
+            EscrowEvent::ContributorAssigned(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.contributor = Some(e.contributor.clone());
+                escrow.status = TaskStatus::InProgress;
+            }
+            EscrowEvent::TaskCompleted(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.status = TaskStatus::Completed;
+                escrow.completed_at = Some(e.timestamp);
+                escrow.pending_creator = None;
+            }
+            EscrowEvent::FundsReleased(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.status = TaskStatus::Completed;
+                escrow.completed_at = Some(e.timestamp);
+                escrow.pending_creator = None;
+                self.contract_balance -= e.amount;
+            }
+            EscrowEvent::RefundProcessed(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.status = TaskStatus::Cancelled;
+                escrow.pending_creator = None;
+                self.contract_balance -= e.amount;
+            }
+            EscrowEvent::DisputeInitiated(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.status = TaskStatus::Disputed;
+                escrow.disputed_at = Some(e.timestamp);
+
+                let history = self.disputes.entry(e.task_id.clone()).or_default();
+                history.push(DisputeEntry {
+                    index: history.len() as u32,
+                    disputing_party: e.disputing_party.clone(),
+                    reason: e.reason.clone(),
+                    initiated_at: e.timestamp,
+                    outcome: DisputeOutcome::Pending,
+                    resolved_by: None,
+                    closed_at: None,
+                });
+            }
+            EscrowEvent::DisputeWithdrawn(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.status = TaskStatus::InProgress;
+                escrow.disputed_at = None;
+
+                self.close_dispute(
+                    &e.task_id,
+                    DisputeOutcome::Withdrawn,
+                    &e.disputing_party,
+                    e.timestamp,
+                )?;
+            }
+            EscrowEvent::ArbiterFeeCredited(e) => {
+                // Credited fees stay in the contract until claimed
+                *self.arbiter_balances.entry(e.arbiter.clone()).or_default() += e.amount;
+                *self.pending_fees.entry(e.task_id.clone()).or_default() += e.amount;
+            }
+            EscrowEvent::DisputeResolved(e) => {
+                let escrow = self.escrow_mut(&e.task_id)?;
+                escrow.status = TaskStatus::Completed;
+                escrow.completed_at = Some(e.timestamp);
+                escrow.pending_creator = None;
+                let bounty_amount = escrow.bounty_amount;
+
+                self.close_dispute(
+                    &e.task_id,
+                    DisputeOutcome::Resolved(e.resolution.clone()),
+                    &e.resolved_by,
+                    e.timestamp,
+                )?;
+
+                // Everything except credited fees is paid out
+                let retained = self.pending_fees.remove(&e.task_id).unwrap_or(0);
+                self.contract_balance -= bounty_amount - retained;
+            }
+            EscrowEvent::ArbiterFeesClaimed(e) => {
+                self.arbiter_balances.remove(&e.arbiter);
+                self.contract_balance -= e.amount;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Projected contract configuration
+    pub fn config(&self) -> &ContractConfig {
+        &self.config
+    }
+
+    /// Projected escrow for a task
+    pub fn escrow(&self, task_id: &str) -> Option<&EscrowState> {
+        self.escrows.get(task_id)
+    }
+
+    /// All projected escrows ordered by task ID
+    pub fn escrows(&self) -> impl Iterator<Item = &EscrowState> {
+        self.escrows.values()
+    }
+
+    /// Projected escrows of a repository, oldest first, skipping escrows that
+    /// have since moved to another repository
+    pub fn escrows_by_repo(&self, owner: &str, repo: &str) -> Vec<&EscrowState> {
+        self.repo_tasks
+            .get(&format!("{owner}/{repo}"))
+            .into_iter()
+            .flatten()
+            .filter_map(|task_id| self.escrows.get(task_id))
+            .filter(|escrow| escrow.issue.owner == owner && escrow.issue.repo == repo)
+            .collect()
+    }
+
+    /// Dispute history of a task, oldest first
+    pub fn dispute_history(&self, task_id: &str) -> &[DisputeEntry] {
+        self.disputes.get(task_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Claimable arbitration fees of an arbiter
+    pub fn arbiter_balance(&self, arbiter: &Address) -> i128 {
+        self.arbiter_balances.get(arbiter).copied().unwrap_or(0)
+    }
+
+    /// USDC held by the contract: open bounties plus unclaimed fees
+    pub fn contract_balance(&self) -> i128 {
+        self.contract_balance
+    }
+
+    /// Append a task to its current repository's index once, as the contract does
+    fn index_repo(&mut self, task_id: &str) {
+        let Some(escrow) = self.escrows.get(task_id) else {
+            return;
+        };
+        if escrow.issue.forge != Forge::GitHub {
+            return;
+        }
+        let key = format!("{}/{}", escrow.issue.owner, escrow.issue.repo);
+        let tasks = self.repo_tasks.entry(key).or_default();
+        if !tasks.iter().any(|indexed| indexed == task_id) {
+            tasks.push(task_id.to_string());
+        }
+    }
+
+    fn escrow_mut(&mut self, task_id: &str) -> Result<&mut EscrowState, IndexError> {
+        self.escrows
+            .get_mut(task_id)
+            .ok_or_else(|| IndexError::UnknownTask(task_id.into()))
+    }
+
+    fn close_dispute(
+        &mut self,
+        task_id: &str,
+        outcome: DisputeOutcome,
+        closed_by: &Address,
+        timestamp: u64,
+    ) -> Result<(), IndexError> {
+        let entry = self
+            .disputes
+            .get_mut(task_id)
+            .and_then(|history| history.last_mut())
+            .filter(|entry| entry.outcome == DisputeOutcome::Pending)
+            .ok_or_else(|| IndexError::NoActiveDispute(task_id.into()))?;
+
+        entry.outcome = outcome;
+        entry.resolved_by = Some(closed_by.clone());
+        entry.closed_at = Some(timestamp);
+        Ok(())
+    }
+}
@@ -0,0 +1,599 @@
+use devasign_escrow_events::{xdr::ScVal, Address, DecodedEvent, ScValConvert};
+use devasign_escrow_indexer::{
+    DisputeEntry, DisputeOutcome, EscrowState, Forge, IssueRef, Projection, TaskStatus,
+};
+use devasign_task_escrow::{self as contract, TaskEscrowContract, TaskEscrowContractClient};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use soroban_sdk::{
+    testutils::{Address as _, EnvTestConfig, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    BytesN, Env, IntoVal, String, TryFromVal, Val,
+};
+use std::collections::BTreeSet;
+
+const SEEDS: u64 = 20;
+const STEPS: usize = 80;
+const TASKS: usize = 6;
+const ISSUE_URL: &str = "https://github.com/owner/repo/issues/1";
+const TAGS: [&str; 4] = ["rust", "soroban", "wasm", "docs"];
+
+/// Contract under test with the projection built from its events
+struct Scenario {
+    env: Env,
+    contract_id: soroban_sdk::Address,
+    client: TaskEscrowContractClient<'static>,
+    usdc: TokenClient<'static>,
+    admins: Vec<soroban_sdk::Address>,
+    creators: Vec<soroban_sdk::Address>,
+    contributors: Vec<soroban_sdk::Address>,
+    task_ids: Vec<String>,
+    projection: Projection,
+    seen_events: BTreeSet<&'static str>,
+}
+
+impl Scenario {
+    fn new() -> Self {
+        // Random scenarios are not worth keeping as ledger snapshots
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        // The contract stores 0 for unset timestamps, so start after the epoch
+        env.ledger().set_timestamp(1_700_000_000);
+
+        let admin = soroban_sdk::Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let usdc_admin = StellarAssetClient::new(&env, &usdc_address);
+        let usdc = TokenClient::new(&env, &usdc_address);
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let client = TaskEscrowContractClient::new(&env, &contract_id);
+
+        let creators: Vec<_> = (0..3)
+            .map(|_| soroban_sdk::Address::generate(&env))
+            .collect();
+        for creator in &creators {
+            usdc_admin.mint(creator, &1000000_0000000);
+        }
+        let contributors = (0..3)
+            .map(|_| soroban_sdk::Address::generate(&env))
+            .collect();
+        let task_ids = (0..TASKS)
+            .map(|i| String::from_str(&env, &format!("clindexertask{i:012}")))
+            .collect();
+
+        let mut scenario = Scenario {
+            env,
+            contract_id,
+            client,
+            usdc,
+            admins: vec![admin.clone()],
+            creators,
+            contributors,
+            task_ids,
+            projection: Projection::new(),
+            seen_events: BTreeSet::new(),
+        };
+        scenario.client.initialize(&admin, &usdc_address);
+        scenario.index();
+        scenario
+    }
+
+    /// Feed the events of the last invocation into the projection
+    fn index(&mut self) {
+        for (address, topics, data) in self.env.events().all().iter() {
+            if address != self.contract_id {
+                continue;
+            }
+            let topics: Vec<ScVal> = topics
+                .iter()
+                .map(|topic| ScVal::try_from_val(&self.env, &topic).unwrap())
+                .collect();
+            let data = ScVal::try_from_val(&self.env, &data).unwrap();
+            let event = DecodedEvent::from_xdr(&topics, &data).unwrap();
+            self.projection.apply(&event).unwrap();
+            self.seen_events.insert(event.event.name());
+        }
+    }
+
+    /// Run one random contract call; calls rejected by the contract emit nothing
+    fn step(&mut self, rng: &mut StdRng) {
+        let task = rng.gen_range(0..TASKS);
+        let task_id = self.task_ids[task].clone();
+        let creator = self.creators[rng.gen_range(0..self.creators.len())].clone();
+        // Calls that only the task creator may make use the current owner
+        let owner = match self.client.try_get_escrow(&task_id) {
+            Ok(Ok(escrow)) => escrow.creator,
+            _ => creator.clone(),
+        };
+        let contributor = self.contributors[rng.gen_range(0..self.contributors.len())].clone();
+        let amount = rng.gen_range(1..=500) * 10_0000000;
+
+        match rng.gen_range(0..15) {
+            0 | 1 => {
+                // One issue per task, so re-creating a refunded task is allowed
+                let url = format!("https://github.com/owner/repo/issues/{}", task + 1);
+                let url = String::from_str(&self.env, &url);
+                let options = contract::EscrowOptions {
+                    labels_hash: self.random_labels_hash(rng),
+                    tags: self.random_tags(rng),
+                    difficulty: self.random_difficulty(rng),
+                };
+                let _ = self
+                    .client
+                    .try_create_escrow_with_options(&creator, &task_id, &url, &amount, &options);
+            }
+            2 if rng.gen_bool(0.5) => {
+                let _ = self.client.try_increase_bounty(&owner, &task_id, &amount);
+            }
+            2 => {
+                let tags = self.random_tags(rng);
+                let difficulty = self.random_difficulty(rng);
+                let _ = self
+                    .client
+                    .try_update_task_tags(&owner, &task_id, &tags, &difficulty);
+            }
+            3 if rng.gen_bool(0.5) => {
+                let _ = self.client.try_decrease_bounty(&owner, &task_id, &amount);
+            }
+            3 => {
+                // Accept or withdraw the pending nomination, or nominate one of the creators
+                if let Ok(Ok(_)) = self.client.try_get_pending_task_owner(&task_id) {
+                    if rng.gen_bool(0.75) {
+                        let _ = self.client.try_accept_task_ownership(&task_id);
+                    } else {
+                        let _ = self.client.try_cancel_task_ownership_transfer(&task_id);
+                    }
+                } else {
+                    let _ = self.client.try_transfer_task_ownership(&task_id, &creator);
+                }
+            }
+            4 | 5 => {
+                let _ = self.client.try_assign_contributor(&task_id, &contributor);
+            }
+            6 => {
+                let _ = self.client.try_approve_completion(&task_id);
+            }
+            7 | 8 => {
+                let party = match self.client.try_get_escrow(&task_id) {
+                    Ok(Ok(escrow)) if rng.gen_bool(0.5) => {
+                        escrow.contributor.unwrap_or(escrow.creator)
+                    }
+                    Ok(Ok(escrow)) => escrow.creator,
+                    _ => creator,
+                };
+                let reason = String::from_str(&self.env, "Work quality does not meet requirements");
+                let _ = self.client.try_dispute_task(&party, &task_id, &reason);
+            }
+            9 => {
+                if let Ok(Ok(info)) = self.client.try_get_dispute_info(&task_id) {
+                    let _ = self
+                        .client
+                        .try_withdraw_dispute(&info.disputing_party, &task_id);
+                }
+            }
+            10 => {
+                let resolution = self.random_resolution(rng);
+                let _ = self.client.try_resolve_dispute(&task_id, &resolution);
+            }
+            11 => {
+                self.env
+                    .ledger()
+                    .with_mut(|ledger| ledger.timestamp += rng.gen_range(1..200_000));
+                let _ = self.client.try_resolve_stale_dispute(&task_id);
+            }
+            12 => {
+                let _ = self.client.try_refund(&task_id);
+            }
+            13 => match rng.gen_range(0..4) {
+                0 => {
+                    let _ = self
+                        .client
+                        .try_set_arbitration_fee(&rng.gen_range(0..=1_000));
+                }
+                1 => {
+                    let policy = match rng.gen_range(0..3) {
+                        0 => contract::DisputeTimeoutPolicy::PayContributor,
+                        1 => contract::DisputeTimeoutPolicy::RefundCreator,
+                        _ => contract::DisputeTimeoutPolicy::SplitEvenly,
+                    };
+                    let _ = self
+                        .client
+                        .try_set_dispute_timeout(&rng.gen_range(1..100_000), &policy);
+                }
+                2 => {
+                    let admin = soroban_sdk::Address::generate(&self.env);
+                    let _ = self.client.try_set_admin(&admin);
+                    self.admins.push(admin);
+                }
+                _ => {
+                    let reason = String::from_str(&self.env, "maintenance");
+                    let _ = self.client.try_set_paused(&rng.gen_bool(0.3), &reason);
+                }
+            },
+            14 if rng.gen_bool(0.5) => {
+                // Each task moves between two issues of its own
+                let number = task + 1 + TASKS * rng.gen_range(0..2);
+                let url = format!("https://github.com/owner/repo/issues/{number}");
+                let url = String::from_str(&self.env, &url);
+                let labels_hash = self.random_labels_hash(rng);
+                let _ = self
+                    .client
+                    .try_update_task_metadata(&owner, &task_id, &url, &labels_hash);
+            }
+            _ => {
+                let arbiter = self.admins[rng.gen_range(0..self.admins.len())].clone();
+                let _ = self.client.try_claim_arbiter_fees(&arbiter);
+            }
+        }
+
+        self.index();
+    }
+
+    fn random_tags(&self, rng: &mut StdRng) -> soroban_sdk::Vec<soroban_sdk::Symbol> {
+        let mut tags = soroban_sdk::Vec::new(&self.env);
+        for tag in TAGS {
+            if rng.gen_bool(0.4) {
+                tags.push_back(soroban_sdk::Symbol::new(&self.env, tag));
+            }
+        }
+        tags
+    }
+
+    fn random_labels_hash(&self, rng: &mut StdRng) -> Option<BytesN<32>> {
+        if rng.gen_bool(0.5) {
+            Some(BytesN::from_array(&self.env, &[rng.gen(); 32]))
+        } else {
+            None
+        }
+    }
+
+    fn random_difficulty(&self, rng: &mut StdRng) -> contract::Difficulty {
+        match rng.gen_range(0..4) {
+            0 => contract::Difficulty::Unrated,
+            1 => contract::Difficulty::Beginner,
+            2 => contract::Difficulty::Intermediate,
+            _ => contract::Difficulty::Advanced,
+        }
+    }
+
+    fn random_resolution(&self, rng: &mut StdRng) -> contract::DisputeResolution {
+        match rng.gen_range(0..6) {
+            0 => contract::DisputeResolution::PayContributor,
+            1 => contract::DisputeResolution::RefundCreator,
+            2 => contract::DisputeResolution::PartialPayment(rng.gen_range(1..=100) * 10_0000000),
+            3 => contract::DisputeResolution::PercentageSplit(rng.gen_range(0..=10_000)),
+            4 => contract::DisputeResolution::SplitWithArbiterFee(
+                rng.gen_range(0..=10_000),
+                rng.gen_range(0..=10) * 10_0000000,
+            ),
+            _ => {
+                let mut payouts = soroban_sdk::Vec::new(&self.env);
+                for contributor in &self.contributors[..rng.gen_range(1..=3)] {
+                    payouts.push_back(contract::DisputePayout {
+                        recipient: contributor.clone(),
+                        amount: rng.gen_range(1..=20) * 10_0000000,
+                    });
+                }
+                contract::DisputeResolution::MultiPayout(payouts)
+            }
+        }
+    }
+
+    /// Assert the projection matches every contract getter
+    fn assert_consistent(&self) {
+        for task_id in &self.task_ids {
+            let key = task_id.to_string();
+            match self.client.try_get_escrow(task_id) {
+                Ok(Ok(escrow)) => {
+                    assert_eq!(
+                        self.projection.escrow(&key),
+                        Some(&self.expected_escrow(&escrow))
+                    );
+                    let history: Vec<DisputeEntry> = self
+                        .client
+                        .get_dispute_history(task_id)
+                        .iter()
+                        .map(|record| self.expected_dispute(&record))
+                        .collect();
+                    assert_eq!(self.projection.dispute_history(&key), history.as_slice());
+                }
+                _ => assert_eq!(self.projection.escrow(&key), None),
+            }
+        }
+
+        self.assert_repo_consistent("owner", "repo");
+
+        for arbiter in &self.admins {
+            assert_eq!(
+                self.projection.arbiter_balance(&self.address(arbiter)),
+                self.client.get_arbiter_balance(arbiter)
+            );
+        }
+
+        assert_eq!(
+            self.projection.contract_balance(),
+            self.usdc.balance(&self.contract_id)
+        );
+
+        let config = self.projection.config();
+        assert_eq!(config.admin, Some(self.address(&self.client.get_admin())));
+        assert_eq!(config.arbitration_fee, self.client.get_arbitration_fee());
+        let limits: Val = self.client.get_config().into_val(&self.env);
+        let limits = ScVal::try_from_val(&self.env, &limits).unwrap();
+        assert_eq!(config.limits, ScValConvert::from_sc_val(&limits).unwrap());
+        let (timeout, _) = self.client.get_dispute_timeout();
+        assert_eq!(config.dispute_timeout, timeout);
+    }
+
+    /// Assert the projected repository index lists what the contract lists
+    fn assert_repo_consistent(&self, owner: &str, repo: &str) {
+        let listed: Vec<EscrowState> = self
+            .client
+            .list_escrows_by_repo(
+                &String::from_str(&self.env, owner),
+                &String::from_str(&self.env, repo),
+                &0,
+            )
+            .iter()
+            .map(|escrow| self.expected_escrow(&escrow))
+            .collect();
+        let projected: Vec<EscrowState> = self
+            .projection
+            .escrows_by_repo(owner, repo)
+            .into_iter()
+            .cloned()
+            .collect();
+        assert_eq!(projected, listed);
+    }
+
+    fn address(&self, address: &soroban_sdk::Address) -> Address {
+        match ScVal::try_from_val(&self.env, &address.to_val()).unwrap() {
+            ScVal::Address(address) => Address::from_strkey(&address.to_string()).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn expected_escrow(&self, escrow: &contract::TaskEscrow) -> EscrowState {
+        EscrowState {
+            task_id: escrow.task_id.to_string(),
+            issue_url: escrow.issue_url.to_string(),
+            issue: IssueRef {
+                forge: match escrow.issue.forge {
+                    contract::Forge::Unknown => Forge::Unknown,
+                    contract::Forge::GitHub => Forge::GitHub,
+                },
+                owner: escrow.issue.owner.to_string(),
+                repo: escrow.issue.repo.to_string(),
+                number: escrow.issue.number,
+                labels_hash: escrow
+                    .issue
+                    .labels_hash
+                    .as_ref()
+                    .map(|hash| hash.to_array()),
+            },
+            creator: self.address(&escrow.creator),
+            pending_creator: match self.client.try_get_pending_task_owner(&escrow.task_id) {
+                Ok(Ok(pending)) => Some(self.address(&pending)),
+                _ => None,
+            },
+            contributor: escrow
+                .contributor
+                .as_ref()
+                .map(|contributor| self.address(contributor)),
+            bounty_amount: escrow.bounty_amount,
+            status: match escrow.status {
+                contract::TaskStatus::Open => TaskStatus::Open,
+                contract::TaskStatus::InProgress => TaskStatus::InProgress,
+                contract::TaskStatus::Completed => TaskStatus::Completed,
+                contract::TaskStatus::Disputed => TaskStatus::Disputed,
+                contract::TaskStatus::Cancelled => TaskStatus::Cancelled,
+            },
+            created_at: escrow.created_at,
+            completed_at: escrow.completed_at,
+            disputed_at: escrow.disputed_at,
+            tags: escrow.tags.iter().map(|tag| self.convert(tag)).collect(),
+            difficulty: self.convert(escrow.difficulty),
+        }
+    }
+
+    fn convert<T: IntoVal<Env, Val>, U: ScValConvert>(&self, value: T) -> U {
+        let val = ScVal::try_from_val(&self.env, &value.into_val(&self.env)).unwrap();
+        U::from_sc_val(&val).unwrap()
+    }
+
+    fn expected_dispute(&self, record: &contract::DisputeRecord) -> DisputeEntry {
+        let outcome = match &record.outcome {
+            contract::DisputeOutcome::Pending => DisputeOutcome::Pending,
+            contract::DisputeOutcome::Withdrawn => DisputeOutcome::Withdrawn,
+            contract::DisputeOutcome::Resolved(resolution) => {
+                let val: Val = resolution.into_val(&self.env);
+                let val = ScVal::try_from_val(&self.env, &val).unwrap();
+                DisputeOutcome::Resolved(ScValConvert::from_sc_val(&val).unwrap())
+            }
+        };
+
+        DisputeEntry {
+            index: record.index,
+            disputing_party: self.address(&record.disputing_party),
+            reason: record.reason.to_string(),
+            initiated_at: record.initiated_at,
+            outcome,
+            resolved_by: record.resolved_by.as_ref().map(|a| self.address(a)),
+            closed_at: record.closed_at,
+        }
+    }
+}
+
+#[test]
+fn test_projection_matches_contract_in_random_scenarios() {
+    let mut seen_events = BTreeSet::new();
+
+    for seed in 0..SEEDS {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut scenario = Scenario::new();
+        scenario
+            .client
+            .set_arbitration_fee(&rng.gen_range(0..=1_000));
+        scenario.index();
+        let mut config = scenario.client.get_config();
+        config.min_amount = rng.gen_range(1..=20) * 10_0000000;
+        scenario.client.update_config(&config);
+        scenario.index();
+
+        for _ in 0..STEPS {
+            scenario.step(&mut rng);
+            scenario.assert_consistent();
+        }
+
+        assert!(scenario.projection.escrows().count() > 0);
+        seen_events.extend(scenario.seen_events);
+    }
+
+    // The scenarios must reach every state transition, not just failed calls
+    for name in [
+        "escrow_created_event",
+        "bounty_increased_event",
+        "bounty_decreased_event",
+        "contributor_assigned_event",
+        "funds_released_event",
+        "refund_processed_event",
+        "dispute_initiated_event",
+        "dispute_withdrawn_event",
+        "dispute_resolved_event",
+        "arbiter_fee_credited_event",
+        "arbiter_fees_claimed_event",
+        "admin_changed_event",
+        "config_updated_event",
+        "task_tags_updated_event",
+        "task_metadata_updated_event",
+        "task_ownership_proposed_event",
+        "task_ownership_transferred_event",
+        "task_ownership_cancelled_event",
+    ] {
+        assert!(seen_events.contains(name), "scenarios never emitted {name}");
+    }
+}
+
+#[test]
+fn test_projection_keeps_issue_metadata() {
+    let mut scenario = Scenario::new();
+    let task_id = scenario.task_ids[0].clone();
+    let creator = scenario.creators[0].clone();
+    let url = String::from_str(
+        &scenario.env,
+        "https://github.com/DevAsign/Contracts/issues/42",
+    );
+    let options = contract::EscrowOptions {
+        labels_hash: Some(BytesN::from_array(&scenario.env, &[9; 32])),
+        tags: soroban_sdk::Vec::new(&scenario.env),
+        difficulty: contract::Difficulty::Unrated,
+    };
+    scenario
+        .client
+        .create_escrow_with_options(&creator, &task_id, &url, &100_0000000, &options);
+    scenario.index();
+    scenario.assert_consistent();
+
+    let escrow = scenario.projection.escrow(&task_id.to_string()).unwrap();
+    assert_eq!(
+        escrow.issue,
+        IssueRef {
+            forge: Forge::GitHub,
+            owner: "devasign".into(),
+            repo: "contracts".into(),
+            number: 42,
+            labels_hash: Some([9; 32]),
+        }
+    );
+}
+
+#[test]
+fn test_projection_follows_task_metadata_updates() {
+    let mut scenario = Scenario::new();
+    let task_id = scenario.task_ids[0].clone();
+    let creator = scenario.creators[0].clone();
+    let options = contract::EscrowOptions {
+        labels_hash: Some(BytesN::from_array(&scenario.env, &[1; 32])),
+        tags: soroban_sdk::Vec::new(&scenario.env),
+        difficulty: contract::Difficulty::Unrated,
+    };
+    let url = String::from_str(&scenario.env, ISSUE_URL);
+    scenario
+        .client
+        .create_escrow_with_options(&creator, &task_id, &url, &100_0000000, &options);
+    scenario.index();
+
+    // Move the task to an issue in another repository with new labels
+    let moved_url = String::from_str(&scenario.env, "https://github.com/Other/Project/issues/7");
+    let labels_hash = Some(BytesN::from_array(&scenario.env, &[2; 32]));
+    scenario
+        .client
+        .update_task_metadata(&creator, &task_id, &moved_url, &labels_hash);
+    scenario.index();
+    scenario.assert_consistent();
+    scenario.assert_repo_consistent("other", "project");
+
+    let escrow = scenario.projection.escrow(&task_id.to_string()).unwrap();
+    assert_eq!(
+        escrow.issue_url,
+        "https://github.com/other/project/issues/7"
+    );
+    assert_eq!(
+        escrow.issue,
+        IssueRef {
+            forge: Forge::GitHub,
+            owner: "other".into(),
+            repo: "project".into(),
+            number: 7,
+            labels_hash: Some([2; 32]),
+        }
+    );
+    assert!(scenario
+        .projection
+        .escrows_by_repo("owner", "repo")
+        .is_empty());
+    assert_eq!(
+        scenario.projection.escrows_by_repo("other", "project"),
+        [escrow]
+    );
+
+    // Clearing the labels hash is projected too
+    scenario
+        .client
+        .update_task_metadata(&creator, &task_id, &moved_url, &None);
+    scenario.index();
+    scenario.assert_consistent();
+    let escrow = scenario.projection.escrow(&task_id.to_string()).unwrap();
+    assert_eq!(escrow.issue.labels_hash, None);
+}
+
+#[test]
+fn test_projection_rejects_inconsistent_stream() {
+    let scenario = Scenario::new();
+    let task_id = scenario.task_ids[0].clone();
+    let creator = scenario.creators[0].clone();
+    let url = String::from_str(&scenario.env, ISSUE_URL);
+    scenario
+        .client
+        .create_escrow(&creator, &task_id, &url, &100_0000000);
+
+    // Replaying the creation event is detected as a duplicate
+    let (_, topics, data) = scenario.env.events().all().last().unwrap();
+    let topics: Vec<ScVal> = topics
+        .iter()
+        .map(|topic| ScVal::try_from_val(&scenario.env, &topic).unwrap())
+        .collect();
+    let data = ScVal::try_from_val(&scenario.env, &data).unwrap();
+    let event = DecodedEvent::from_xdr(&topics, &data).unwrap();
+
+    let mut projection = Projection::new();
+    projection.apply(&event).unwrap();
+    assert_eq!(
+        projection.apply(&event),
+        Err(devasign_escrow_indexer::IndexError::DuplicateTask(
+            task_id.to_string()
+        ))
+    );
+}
@@ -0,0 +1,157 @@
+//! Runs arbitrary sequences of escrow calls and checks after every call that
+//! funds stay locked and no USDC is created or destroyed.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use devasign_task_escrow_fuzz::{accepted, FuzzPolicy, FuzzResolution, Harness};
+use libfuzzer_sys::fuzz_target;
+use soroban_sdk::testutils::Ledger;
+
+/// Calls with actors and tasks chosen by index
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Create {
+        creator: u8,
+        task: u8,
+        amount: i128,
+    },
+    Increase {
+        caller: u8,
+        task: u8,
+        amount: i128,
+    },
+    Decrease {
+        caller: u8,
+        task: u8,
+        amount: i128,
+    },
+    Assign {
+        task: u8,
+        contributor: u8,
+    },
+    Approve {
+        task: u8,
+    },
+    Dispute {
+        party: u8,
+        task: u8,
+        reason: std::string::String,
+    },
+    Withdraw {
+        party: u8,
+        task: u8,
+    },
+    Resolve {
+        task: u8,
+        resolution: FuzzResolution,
+    },
+    ResolveStale {
+        task: u8,
+    },
+    Refund {
+        task: u8,
+    },
+    ClaimFees,
+    SetFee(u32),
+    SetTimeout(u64, FuzzPolicy),
+    Pause(bool),
+    AdvanceTime(u32),
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let harness = Harness::new();
+    let client = &harness.client;
+    let task_ids: Vec<_> = (0..8).map(|index| harness.task(index)).collect();
+    let total = harness.total_supply();
+
+    for op in ops.iter().take(64) {
+        match op {
+            Op::Create {
+                creator,
+                task,
+                amount,
+            } => accepted(
+                "create_escrow",
+                client.try_create_escrow(
+                    harness.actor(*creator),
+                    &harness.task(*task),
+                    &harness.issue_url(*task),
+                    amount,
+                ),
+            ),
+            Op::Increase {
+                caller,
+                task,
+                amount,
+            } => accepted(
+                "increase_bounty",
+                client.try_increase_bounty(harness.actor(*caller), &harness.task(*task), amount),
+            ),
+            Op::Decrease {
+                caller,
+                task,
+                amount,
+            } => accepted(
+                "decrease_bounty",
+                client.try_decrease_bounty(harness.actor(*caller), &harness.task(*task), amount),
+            ),
+            Op::Assign { task, contributor } => accepted(
+                "assign_contributor",
+                client.try_assign_contributor(&harness.task(*task), harness.actor(*contributor)),
+            ),
+            Op::Approve { task } => accepted(
+                "approve_completion",
+                client.try_approve_completion(&harness.task(*task)),
+            ),
+            Op::Dispute {
+                party,
+                task,
+                reason,
+            } => accepted(
+                "dispute_task",
+                client.try_dispute_task(
+                    harness.actor(*party),
+                    &harness.task(*task),
+                    &harness.string(reason),
+                ),
+            ),
+            Op::Withdraw { party, task } => accepted(
+                "withdraw_dispute",
+                client.try_withdraw_dispute(harness.actor(*party), &harness.task(*task)),
+            ),
+            Op::Resolve { task, resolution } => accepted(
+                "resolve_dispute",
+                client.try_resolve_dispute(&harness.task(*task), &harness.resolution(resolution)),
+            ),
+            Op::ResolveStale { task } => accepted(
+                "resolve_stale_dispute",
+                client.try_resolve_stale_dispute(&harness.task(*task)),
+            ),
+            Op::Refund { task } => accepted("refund", client.try_refund(&harness.task(*task))),
+            Op::ClaimFees => accepted(
+                "claim_arbiter_fees",
+                client.try_claim_arbiter_fees(&harness.admin),
+            ),
+            Op::SetFee(fee) => accepted("set_arbitration_fee", client.try_set_arbitration_fee(fee)),
+            Op::SetTimeout(timeout, policy) => accepted(
+                "set_dispute_timeout",
+                client.try_set_dispute_timeout(timeout, &(*policy).into()),
+            ),
+            Op::Pause(paused) => accepted(
+                "set_paused",
+                client.try_set_paused(paused, &harness.string("fuzz")),
+            ),
+            Op::AdvanceTime(seconds) => {
+                harness
+                    .env
+                    .ledger()
+                    .set_timestamp(harness.env.ledger().timestamp() + *seconds as u64);
+                true
+            }
+        };
+
+        harness.assert_funds_locked(&task_ids);
+        assert_eq!(harness.total_supply(), total);
+    }
+});
@@ -0,0 +1,86 @@
+//! Feeds arbitrary task IDs, issue URLs, dispute reasons and amounts through
+//! a full escrow lifecycle.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use devasign_task_escrow::DisputeResolution;
+use devasign_task_escrow_fuzz::{accepted, Harness};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    task_id: std::string::String,
+    issue_url: std::string::String,
+    bounty_amount: i128,
+    increase: i128,
+    decrease: i128,
+    reason: std::string::String,
+    partial_payment: i128,
+    percentage: u32,
+}
+
+fuzz_target!(|input: Input| {
+    let harness = Harness::new();
+    let client = &harness.client;
+    let task_id = harness.string(&input.task_id);
+    let creator = harness.actor(0);
+    let contributor = harness.actor(1);
+    let total = harness.total_supply();
+
+    accepted("get_escrow", client.try_get_escrow(&task_id));
+    let created = accepted(
+        "create_escrow",
+        client.try_create_escrow(
+            creator,
+            &task_id,
+            &harness.string(&input.issue_url),
+            &input.bounty_amount,
+        ),
+    );
+    let task_ids = [task_id.clone()];
+    harness.assert_funds_locked(&task_ids);
+    if !created {
+        assert_eq!(harness.token.balance(&client.address), 0);
+        return;
+    }
+
+    accepted(
+        "increase_bounty",
+        client.try_increase_bounty(creator, &task_id, &input.increase),
+    );
+    accepted(
+        "decrease_bounty",
+        client.try_decrease_bounty(creator, &task_id, &input.decrease),
+    );
+    harness.assert_funds_locked(&task_ids);
+
+    accepted(
+        "assign_contributor",
+        client.try_assign_contributor(&task_id, contributor),
+    );
+    let disputed = accepted(
+        "dispute_task",
+        client.try_dispute_task(contributor, &task_id, &harness.string(&input.reason)),
+    );
+    harness.assert_funds_locked(&task_ids);
+
+    if disputed {
+        accepted(
+            "resolve_dispute",
+            client.try_resolve_dispute(
+                &task_id,
+                &DisputeResolution::PartialPayment(input.partial_payment),
+            ),
+        );
+        accepted(
+            "resolve_dispute",
+            client.try_resolve_dispute(
+                &task_id,
+                &DisputeResolution::PercentageSplit(input.percentage),
+            ),
+        );
+    }
+    harness.assert_funds_locked(&task_ids);
+    assert_eq!(harness.total_supply(), total);
+});
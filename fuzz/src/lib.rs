@@ -0,0 +1,186 @@
+//! Shared harness for the escrow fuzz targets.
+//!
+//! Every target drives a freshly initialized contract through the Soroban
+//! test utilities. A call may succeed or return a contract `Error`; any other
+//! failure (a host panic, a trapped arithmetic overflow, a failed token
+//! transfer) is reported as a crash, and so is a broken accounting invariant.
+
+use arbitrary::Arbitrary;
+use devasign_task_escrow::{
+    DisputePayout, DisputeResolution, DisputeTimeoutPolicy, Error, TaskEscrowContract,
+    TaskEscrowContractClient, TaskStatus,
+};
+use soroban_sdk::{
+    testutils::{Address as _, EnvTestConfig, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, InvokeError, String, Vec,
+};
+
+/// Number of funded accounts available to a fuzz case
+pub const ACTORS: usize = 4;
+
+/// USDC minted to every actor, large enough for several maximum bounties
+pub const STARTING_BALANCE: i128 = 10_000_000_000 * 10_000_000;
+
+pub struct Harness {
+    pub env: Env,
+    pub admin: Address,
+    pub actors: std::vec::Vec<Address>,
+    pub client: TaskEscrowContractClient<'static>,
+    pub token: TokenClient<'static>,
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_700_000_000);
+
+        let admin = Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let usdc_token = StellarAssetClient::new(&env, &usdc_address);
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let client = TaskEscrowContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &usdc_address);
+
+        let actors = (0..ACTORS).map(|_| Address::generate(&env)).collect();
+        for actor in &actors {
+            usdc_token.mint(actor, &STARTING_BALANCE);
+        }
+
+        Harness {
+            token: TokenClient::new(&env, &usdc_address),
+            env,
+            admin,
+            actors,
+            client,
+        }
+    }
+
+    /// Actor chosen by a fuzzed index
+    pub fn actor(&self, index: u8) -> &Address {
+        &self.actors[index as usize % ACTORS]
+    }
+
+    /// Well-formed task ID chosen by a fuzzed index
+    pub fn task(&self, index: u8) -> String {
+        String::from_str(&self.env, &format!("cfuzz{:020}", index % 8))
+    }
+
+    /// Issue URL belonging to the task at the same index
+    pub fn issue_url(&self, index: u8) -> String {
+        let url = format!("https://github.com/owner/repo/issues/{}", index % 8 + 1);
+        String::from_str(&self.env, &url)
+    }
+
+    pub fn string(&self, value: &str) -> String {
+        String::from_str(&self.env, value)
+    }
+
+    pub fn resolution(&self, resolution: &FuzzResolution) -> DisputeResolution {
+        match resolution {
+            FuzzResolution::PayContributor => DisputeResolution::PayContributor,
+            FuzzResolution::RefundCreator => DisputeResolution::RefundCreator,
+            FuzzResolution::PartialPayment(amount) => DisputeResolution::PartialPayment(*amount),
+            FuzzResolution::PercentageSplit(bps) => DisputeResolution::PercentageSplit(*bps),
+            FuzzResolution::SplitWithArbiterFee(bps, fee) => {
+                DisputeResolution::SplitWithArbiterFee(*bps, *fee)
+            }
+            FuzzResolution::MultiPayout(payouts) => {
+                let mut list = Vec::new(&self.env);
+                for (recipient, amount) in payouts {
+                    list.push_back(DisputePayout {
+                        recipient: self.actor(*recipient).clone(),
+                        amount: *amount,
+                    });
+                }
+                DisputeResolution::MultiPayout(list)
+            }
+        }
+    }
+
+    /// Total USDC held by the actors, the admin and the contract
+    pub fn total_supply(&self) -> i128 {
+        self.actors
+            .iter()
+            .chain([&self.admin, &self.client.address])
+            .map(|address| self.token.balance(address))
+            .sum()
+    }
+
+    /// Assert that the contract holds exactly the bounties of the given tasks
+    /// that are still locked, plus the arbitration fees not yet claimed
+    pub fn assert_funds_locked(&self, task_ids: &[String]) {
+        let locked: i128 = task_ids
+            .iter()
+            .filter_map(|task_id| self.client.try_get_escrow(task_id).ok())
+            .map(|escrow| escrow.unwrap())
+            .filter(|escrow| {
+                !matches!(escrow.status, TaskStatus::Completed | TaskStatus::Cancelled)
+            })
+            .map(|escrow| {
+                assert!(escrow.bounty_amount > 0, "non-positive bounty: {escrow:?}");
+                escrow.bounty_amount
+            })
+            .sum();
+        let fees = self.client.get_arbiter_balance(&self.admin);
+        assert_eq!(
+            self.token.balance(&self.client.address),
+            locked + fees,
+            "contract balance differs from locked bounties plus unclaimed fees"
+        );
+    }
+}
+
+/// Fuzzed counterpart of `DisputeResolution` with recipients chosen by index
+#[derive(Arbitrary, Debug, Clone)]
+pub enum FuzzResolution {
+    PayContributor,
+    RefundCreator,
+    PartialPayment(i128),
+    PercentageSplit(u32),
+    SplitWithArbiterFee(u32, i128),
+    MultiPayout(std::vec::Vec<(u8, i128)>),
+}
+
+/// Fuzzed counterpart of `DisputeTimeoutPolicy`
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum FuzzPolicy {
+    RefundCreator,
+    PayContributor,
+    SplitEvenly,
+}
+
+impl From<FuzzPolicy> for DisputeTimeoutPolicy {
+    fn from(policy: FuzzPolicy) -> Self {
+        match policy {
+            FuzzPolicy::RefundCreator => DisputeTimeoutPolicy::RefundCreator,
+            FuzzPolicy::PayContributor => DisputeTimeoutPolicy::PayContributor,
+            FuzzPolicy::SplitEvenly => DisputeTimeoutPolicy::SplitEvenly,
+        }
+    }
+}
+
+/// Check that a `try_` call either succeeded or returned a contract `Error`,
+/// returning whether it succeeded
+pub fn accepted<T, C: std::fmt::Debug>(
+    call: &str,
+    result: Result<Result<T, C>, Result<Error, InvokeError>>,
+) -> bool {
+    match result {
+        Ok(Ok(_)) => true,
+        Ok(Err(err)) => panic!("{call} returned an unconvertible value: {err:?}"),
+        Err(Ok(_)) => false,
+        Err(Err(err)) => panic!("{call} failed outside the contract's errors: {err:?}"),
+    }
+}
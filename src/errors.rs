@@ -10,6 +10,7 @@ pub enum Error {
     TaskAlreadyExists = 2,
     InvalidTaskStatus = 3,
     ContractNotInitialized = 4,
+    AlreadyInitialized = 5,
 
     // Permission errors (10-19)
     Unauthorized = 10,
@@ -17,6 +18,7 @@ pub enum Error {
     NotTaskContributor = 12,
     NotAdmin = 13,
     OnlyCreatorOrContributor = 14,
+    NotDisputingParty = 15,
 
     // Business logic errors (20-29)
     ContributorAlreadyAssigned = 20,
@@ -26,6 +28,9 @@ pub enum Error {
     TaskNotDisputed = 24,
     TaskAlreadyResolved = 25,
     CannotRefundWithContributor = 26,
+    DisputeNotExpired = 27,
+    NoFeesToClaim = 28,
+    IssueAlreadyEscrowed = 29,
 
     // Token errors (30-39)
     TokenTransferFailed = 30,
@@ -45,4 +50,11 @@ pub enum Error {
     DisputeReasonTooShort = 49,
     InvalidIssueUrl = 50,
     ContractPaused = 51,
+    InvalidDisputeTimeout = 52,
+    InvalidBasisPoints = 53,
+    InvalidPayoutSplit = 54,
+    InvalidConfig = 55,
+    InvalidRepository = 56,
+    InvalidTags = 57,
+    NoPendingOwnershipTransfer = 58,
 }
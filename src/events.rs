@@ -1,46 +1,192 @@
+//! Event publication for the task escrow contract.
+//!
+//! Every event is published through [`publish`], which stamps the schema and
+//! contract versions into the topic list so off-chain decoders can detect
+//! layout changes. Topics are laid out as:
+//!
+//! `[event_name: Symbol, schema_version: u32, contract_version: u64]`
+//!
+//! Event data is a map keyed by field name.
+//!
+//! Compatibility policy:
+//! - Within a schema version the topic layout and the name and type of every
+//!   data field are frozen.
+//! - Adding, removing, renaming or retyping a field of an existing event
+//!   requires bumping [`EVENT_SCHEMA_VERSION`] and regenerating the raw XDR
+//!   fixture in `tests/fixtures`.
+//! - Adding a new event type does not require a bump.
+//! - The contract version topic changes with every release and carries no
+//!   compatibility guarantee on its own.
+
 use crate::types::*;
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{Address, BytesN, Env, Event, String, Symbol, Val, Vec};
+
+/// Version of the event payload schema, emitted as the second topic
+pub const EVENT_SCHEMA_VERSION: u32 = 4;
+
+/// Event wrapper that adds the version topics after the event name
+struct Versioned<'a, E: Event> {
+    event: &'a E,
+}
+
+impl<E: Event> Event for Versioned<'_, E> {
+    fn topics(&self, env: &Env) -> Vec<Val> {
+        use soroban_sdk::IntoVal;
+        let mut topics = self.event.topics(env);
+        topics.insert(1, EVENT_SCHEMA_VERSION.into_val(env));
+        topics.insert(2, crate::TaskEscrowContract::version().into_val(env));
+        topics
+    }
+
+    fn data(&self, env: &Env) -> Val {
+        self.event.data(env)
+    }
+}
+
+/// Publish an event with schema and contract version topics
+fn publish<E: Event>(env: &Env, event: &E) {
+    env.events().publish_event(&Versioned { event });
+}
 
 /// Emit an EscrowCreated event
-pub fn emit_escrow_created(env: &Env, task_id: String, creator: Address, bounty_amount: i128) {
-    EscrowCreatedEvent {
+pub fn emit_escrow_created(
+    env: &Env,
+    task_id: String,
+    issue_url: String,
+    creator: Address,
+    bounty_amount: i128,
+    options: EscrowOptions,
+) {
+    let event = EscrowCreatedEvent {
         task_id,
+        issue_url,
+        labels_hash: options.labels_hash,
         creator,
         bounty_amount,
+        tags: options.tags,
+        difficulty: options.difficulty,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
+}
+
+/// Emit a TaskOwnershipProposed event
+pub fn emit_task_ownership_proposed(
+    env: &Env,
+    task_id: String,
+    creator: Address,
+    pending_creator: Address,
+) {
+    let event = TaskOwnershipProposedEvent {
+        task_id,
+        creator,
+        pending_creator,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a TaskOwnershipTransferred event
+pub fn emit_task_ownership_transferred(
+    env: &Env,
+    task_id: String,
+    old_creator: Address,
+    new_creator: Address,
+) {
+    let event = TaskOwnershipTransferredEvent {
+        task_id,
+        old_creator,
+        new_creator,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a TaskOwnershipCancelled event
+pub fn emit_task_ownership_cancelled(
+    env: &Env,
+    task_id: String,
+    creator: Address,
+    pending_creator: Address,
+) {
+    let event = TaskOwnershipCancelledEvent {
+        task_id,
+        creator,
+        pending_creator,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a TaskMetadataUpdated event
+pub fn emit_task_metadata_updated(
+    env: &Env,
+    task_id: String,
+    creator: Address,
+    old_issue_url: String,
+    new_issue_url: String,
+    old_labels_hash: Option<BytesN<32>>,
+    new_labels_hash: Option<BytesN<32>>,
+) {
+    let event = TaskMetadataUpdatedEvent {
+        task_id,
+        creator,
+        old_issue_url,
+        new_issue_url,
+        old_labels_hash,
+        new_labels_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a TaskTagsUpdated event
+pub fn emit_task_tags_updated(
+    env: &Env,
+    task_id: String,
+    creator: Address,
+    tags: Vec<Symbol>,
+    difficulty: Difficulty,
+) {
+    let event = TaskTagsUpdatedEvent {
+        task_id,
+        creator,
+        tags,
+        difficulty,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
 }
 
 /// Emit a ContributorAssigned event
 pub fn emit_contributor_assigned(env: &Env, task_id: String, contributor: Address) {
-    ContributorAssignedEvent {
+    let event = ContributorAssignedEvent {
         task_id,
         contributor,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
 }
 
 /// Emit a TaskCompleted event
 pub fn emit_task_completed(env: &Env, task_id: String, contributor: Address) {
-    TaskCompletedEvent {
+    let event = TaskCompletedEvent {
         task_id,
         contributor,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
 }
 
 /// Emit a FundsReleased event
 pub fn emit_funds_released(env: &Env, task_id: String, contributor: Address, amount: i128) {
-    FundsReleasedEvent {
+    let event = FundsReleasedEvent {
         task_id,
         contributor,
         amount,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
 }
 
 /// Emit a DisputeInitiated event
@@ -50,13 +196,23 @@ pub fn emit_dispute_initiated(
     disputing_party: Address,
     reason: String,
 ) {
-    DisputeInitiatedEvent {
+    let event = DisputeInitiatedEvent {
         task_id,
         disputing_party,
         reason,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
+}
+
+/// Emit a DisputeWithdrawn event
+pub fn emit_dispute_withdrawn(env: &Env, task_id: String, disputing_party: Address) {
+    let event = DisputeWithdrawnEvent {
+        task_id,
+        disputing_party,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
 }
 
 /// Emit a DisputeResolved event
@@ -66,24 +222,45 @@ pub fn emit_dispute_resolved(
     resolution: DisputeResolution,
     resolved_by: Address,
 ) {
-    DisputeResolvedEvent {
+    let event = DisputeResolvedEvent {
         task_id,
         resolution,
         resolved_by,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
+}
+
+/// Emit an ArbiterFeeCredited event
+pub fn emit_arbiter_fee_credited(env: &Env, task_id: String, arbiter: Address, amount: i128) {
+    let event = ArbiterFeeCreditedEvent {
+        task_id,
+        arbiter,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit an ArbiterFeesClaimed event
+pub fn emit_arbiter_fees_claimed(env: &Env, arbiter: Address, amount: i128) {
+    let event = ArbiterFeesClaimedEvent {
+        arbiter,
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
 }
 
 /// Emit a RefundProcessed event
 pub fn emit_refund_processed(env: &Env, task_id: String, creator: Address, amount: i128) {
-    RefundProcessedEvent {
+    let event = RefundProcessedEvent {
         task_id,
         creator,
         amount,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
 }
 
 /// Emit a BountyIncreased event
@@ -94,14 +271,14 @@ pub fn emit_bounty_increased(
     added_amount: i128,
     new_total_amount: i128,
 ) {
-    BountyIncreasedEvent {
+    let event = BountyIncreasedEvent {
         task_id,
         creator,
         added_amount,
         new_total_amount,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
 }
 
 /// Emit a BountyDecreased event
@@ -112,22 +289,100 @@ pub fn emit_bounty_decreased(
     subtracted_amount: i128,
     new_total_amount: i128,
 ) {
-    BountyDecreasedEvent {
+    let event = BountyDecreasedEvent {
         task_id,
         creator,
         subtracted_amount,
         new_total_amount,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
+}
+
+/// Emit an Initialized event
+pub fn emit_initialized(env: &Env, admin: Address, usdc_token: Address) {
+    let event = InitializedEvent {
+        admin,
+        usdc_token,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit an AdminChanged event
+pub fn emit_admin_changed(env: &Env, old_admin: Address, new_admin: Address) {
+    let event = AdminChangedEvent {
+        old_admin,
+        new_admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a PauseStateChanged event
+pub fn emit_pause_state_changed(env: &Env, paused: bool, actor: Address, reason: String) {
+    let event = PauseStateChangedEvent {
+        paused,
+        actor,
+        reason,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a TokenUpdated event
+pub fn emit_token_updated(env: &Env, old_token: Address, new_token: Address, admin: Address) {
+    let event = TokenUpdatedEvent {
+        old_token,
+        new_token,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a DisputeTimeoutUpdated event
+pub fn emit_dispute_timeout_updated(
+    env: &Env,
+    timeout: u64,
+    policy: DisputeTimeoutPolicy,
+    admin: Address,
+) {
+    let event = DisputeTimeoutUpdatedEvent {
+        timeout,
+        policy,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit an ArbitrationFeeUpdated event
+pub fn emit_arbitration_fee_updated(env: &Env, fee_basis_points: u32, admin: Address) {
+    let event = ArbitrationFeeUpdatedEvent {
+        fee_basis_points,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
+}
+
+/// Emit a ConfigUpdated event
+pub fn emit_config_updated(env: &Env, config: Config, admin: Address) {
+    let event = ConfigUpdatedEvent {
+        config,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    publish(env, &event);
 }
 
 /// Emit a ContractUpgraded event
 pub fn emit_contract_upgraded(env: &Env, new_wasm_hash: soroban_sdk::BytesN<32>, admin: Address) {
-    ContractUpgradedEvent {
+    let event = ContractUpgradedEvent {
         new_wasm_hash,
         admin,
         timestamp: env.ledger().timestamp(),
-    }
-    .publish(env);
+    };
+    publish(env, &event);
 }
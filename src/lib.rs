@@ -8,26 +8,130 @@ pub use errors::*;
 pub use events::*;
 pub use types::*;
 
-use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, token, Address, BytesN, Env, Map, String, Symbol, SymbolStr,
+    TryFromVal, TryIntoVal, Val, Vec,
+};
+
+/// Default maximum dispute duration (30 days)
+const DEFAULT_DISPUTE_TIMEOUT: u64 = 30 * 24 * 60 * 60;
+
+/// Basis points representing 100% in dispute splits
+const BASIS_POINTS_DENOMINATOR: u32 = 10_000;
+
+/// Maximum number of recipients in a multi-contributor dispute payout
+const MAX_DISPUTE_PAYOUTS: u32 = 10;
+
+/// Maximum arbitration fee in basis points (10%)
+const MAX_ARBITRATION_FEE: u32 = 1_000;
+
+/// Default smallest bounty (0.01 USDC = 100000 stroops)
+const DEFAULT_MIN_AMOUNT: i128 = 100_000;
+
+/// Default largest bounty (1 billion USDC with 7 decimal places)
+const DEFAULT_MAX_AMOUNT: i128 = 1_000_000_000 * 10_000_000;
+
+/// Default task ID length (cuid)
+const DEFAULT_TASK_ID_LENGTH: u32 = 25;
+
+/// Number of task ID bytes checked per copy out of the host
+const TASK_ID_CHUNK: usize = 64;
+
+/// Default dispute reason length limits
+const DEFAULT_MIN_DISPUTE_REASON_LENGTH: u32 = 10;
+const DEFAULT_MAX_DISPUTE_REASON_LENGTH: u32 = 500;
+
+/// Default maximum issue URL length, the longest URL the parser accepts
+const DEFAULT_MAX_ISSUE_URL_LENGTH: u32 = MAX_ISSUE_URL_BUFFER as u32;
+
+/// Longest owner and repository names GitHub allows
+const MAX_GITHUB_OWNER_LENGTH: usize = 39;
+const MAX_GITHUB_REPO_LENGTH: usize = 100;
+
+/// Most digits accepted in an issue number
+const MAX_ISSUE_NUMBER_DIGITS: usize = 10;
+
+/// Longest issue URL that can name a GitHub issue, sized for the `http(s)://www.`
+/// prefix, the longest owner and repository and a trailing slash
+const MAX_ISSUE_URL_BUFFER: usize = 192;
+
+/// Number of escrows returned per page of a repository or tag listing
+const INDEX_PAGE_SIZE: u32 = 20;
+
+/// Most skill tags on a task
+const MAX_TASK_TAGS: u32 = 5;
+
+/// Longest skill tag, the most characters a symbol can hold without a
+/// separate storage object
+const MAX_TAG_LENGTH: usize = 9;
+
+/// All-zero account stored as the contributor placeholder by legacy escrows
+const NULL_ADDRESS: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+/// Field only present in escrows stored in the legacy layout
+const LEGACY_ESCROW_FIELD: &str = "has_contributor";
+
+/// Field missing from escrows stored in the version 5 layout
+const ISSUE_ESCROW_FIELD: &str = "issue";
+
+/// Field missing from escrows stored in the version 6 layout
+const TAGS_ESCROW_FIELD: &str = "tags";
+
+/// Layout an escrow was read from by `load_stored_escrow`
+#[derive(Clone, Copy, PartialEq)]
+enum StoredLayout {
+    /// The current layout
+    Current,
+    /// The version 6 layout, indexed at creation but without tags
+    Untagged,
+    /// The legacy and version 5 layouts, stored before the issue and
+    /// repository indexes existed
+    Unindexed,
+}
 
 #[contract]
 pub struct TaskEscrowContract;
 
 #[contractimpl]
 impl TaskEscrowContract {
+    /// Record the account allowed to initialize the contract
+    /// Runs as part of the deployment, which must pass the deployer address,
+    /// so no other account can initialize the contract before `initialize`
+    /// is called in a separate transaction
+    pub fn __constructor(env: Env, deployer: Address) {
+        env.storage().instance().set(&DataKey::Deployer, &deployer);
+    }
+
     /// Initialize the contract with admin and USDC token addresses
-    /// This function can only be called once during contract deployment
+    /// This function can only be called once after contract deployment and
+    /// must be signed by both the deployer and the admin
     pub fn initialize(env: Env, admin: Address, usdc_token: Address) -> Result<(), Error> {
         // Check if contract is already initialized
         if env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::TaskAlreadyExists); // Using existing error for "already initialized"
+            return Err(Error::AlreadyInitialized);
+        }
+
+        // Require authentication from the account named on deployment
+        let deployer: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Deployer)
+            .ok_or(Error::Unauthorized)?;
+        if deployer != admin {
+            deployer.require_auth();
         }
 
+        // Require authentication from the admin being installed
+        admin.require_auth();
+
+        // The deployer has no role once the contract is set up
+        env.storage().instance().remove(&DataKey::Deployer);
+
         // Validate admin address
-        Self::validate_address(&admin)?;
+        Self::validate_participant(&env, &admin)?;
 
         // Validate USDC token address
-        Self::validate_address(&usdc_token)?;
+        Self::validate_address(&env, &usdc_token)?;
 
         // Store admin and USDC token addresses
         env.storage().instance().set(&DataKey::Admin, &admin);
@@ -38,6 +142,28 @@ impl TaskEscrowContract {
         // Initialize paused state to false
         env.storage().instance().set(&DataKey::Paused, &false);
 
+        // Initialize dispute timeout with the default policy
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeTimeout, &DEFAULT_DISPUTE_TIMEOUT);
+        env.storage().instance().set(
+            &DataKey::DisputeTimeoutPolicy,
+            &DisputeTimeoutPolicy::RefundCreator,
+        );
+
+        // Initialize arbitration fee to zero
+        env.storage()
+            .instance()
+            .set(&DataKey::ArbitrationFee, &0u32);
+
+        // Initialize validation limits with the defaults
+        env.storage()
+            .instance()
+            .set(&DataKey::Config, &Self::default_config());
+
+        // Emit initialized event
+        crate::events::emit_initialized(&env, admin, usdc_token);
+
         Ok(())
     }
 
@@ -48,23 +174,31 @@ impl TaskEscrowContract {
         Self::require_admin(&env)?;
 
         // Validate new admin address
-        Self::validate_address(&new_admin)?;
+        Self::validate_participant(&env, &new_admin)?;
 
         // Update admin address
+        let old_admin = Self::get_admin(env.clone())?;
         env.storage().instance().set(&DataKey::Admin, &new_admin);
 
+        // Emit admin changed event
+        crate::events::emit_admin_changed(&env, old_admin, new_admin);
+
         Ok(())
     }
 
-    /// Set the emergency pause state
+    /// Set the emergency pause state with a reason for monitoring
     /// Can only be called by admin
-    pub fn set_paused(env: Env, paused: bool) -> Result<(), Error> {
+    pub fn set_paused(env: Env, paused: bool, reason: String) -> Result<(), Error> {
         // Validate caller is current admin
         Self::require_admin(&env)?;
 
         // Update paused state
         env.storage().instance().set(&DataKey::Paused, &paused);
 
+        // Emit pause state changed event
+        let admin = Self::get_admin(env.clone())?;
+        crate::events::emit_pause_state_changed(&env, paused, admin, reason);
+
         Ok(())
     }
 
@@ -75,16 +209,155 @@ impl TaskEscrowContract {
         Self::require_admin(&env)?;
 
         // Validate new USDC token address
-        Self::validate_address(&new_usdc_token)?;
+        Self::validate_address(&env, &new_usdc_token)?;
 
         // Update USDC token address
+        let old_usdc_token = Self::get_usdc_token_internal(&env)?;
         env.storage()
             .instance()
             .set(&DataKey::UsdcToken, &new_usdc_token);
 
+        // Emit token updated event
+        let admin = Self::get_admin(env.clone())?;
+        crate::events::emit_token_updated(&env, old_usdc_token, new_usdc_token, admin);
+
+        Ok(())
+    }
+
+    /// Configure how long a dispute may stay open and the outcome applied afterwards
+    /// Can only be called by admin
+    pub fn set_dispute_timeout(
+        env: Env,
+        timeout: u64,
+        policy: DisputeTimeoutPolicy,
+    ) -> Result<(), Error> {
+        // Validate caller is current admin
+        Self::require_admin(&env)?;
+
+        // Validate timeout (must be non-zero)
+        if timeout == 0 {
+            return Err(Error::InvalidDisputeTimeout);
+        }
+
+        // Update dispute timeout and policy
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeTimeout, &timeout);
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeTimeoutPolicy, &policy);
+
+        // Emit dispute timeout updated event
+        let admin = Self::get_admin(env.clone())?;
+        crate::events::emit_dispute_timeout_updated(&env, timeout, policy, admin);
+
+        Ok(())
+    }
+
+    /// Get the dispute timeout in seconds and the policy applied once it elapses
+    pub fn get_dispute_timeout(env: Env) -> Result<(u64, DisputeTimeoutPolicy), Error> {
+        let timeout = env
+            .storage()
+            .instance()
+            .get(&DataKey::DisputeTimeout)
+            .unwrap_or(DEFAULT_DISPUTE_TIMEOUT);
+        let policy = env
+            .storage()
+            .instance()
+            .get(&DataKey::DisputeTimeoutPolicy)
+            .unwrap_or(DisputeTimeoutPolicy::RefundCreator);
+
+        Ok((timeout, policy))
+    }
+
+    /// Set the arbitration fee taken from the escrow when admin resolves a dispute
+    /// Fee is expressed in basis points of the bounty; can only be called by admin
+    pub fn set_arbitration_fee(env: Env, fee_basis_points: u32) -> Result<(), Error> {
+        // Validate caller is current admin
+        Self::require_admin(&env)?;
+
+        // Validate fee does not exceed the allowed maximum
+        if fee_basis_points > MAX_ARBITRATION_FEE {
+            return Err(Error::InvalidBasisPoints);
+        }
+
+        // Update arbitration fee
+        env.storage()
+            .instance()
+            .set(&DataKey::ArbitrationFee, &fee_basis_points);
+
+        // Emit arbitration fee updated event
+        let admin = Self::get_admin(env.clone())?;
+        crate::events::emit_arbitration_fee_updated(&env, fee_basis_points, admin);
+
         Ok(())
     }
 
+    /// Get the arbitration fee in basis points of the bounty
+    pub fn get_arbitration_fee(env: Env) -> Result<u32, Error> {
+        Ok(env
+            .storage()
+            .instance()
+            .get(&DataKey::ArbitrationFee)
+            .unwrap_or(0))
+    }
+
+    /// Replace the validation limits applied to task IDs, amounts, URLs and dispute reasons
+    /// Can only be called by admin; task ID limits only apply to escrows created afterwards
+    pub fn update_config(env: Env, config: Config) -> Result<(), Error> {
+        // Validate caller is current admin
+        Self::require_admin(&env)?;
+
+        // Validate every range is non-empty and positive
+        if config.min_amount <= 0
+            || config.min_amount > config.max_amount
+            || config.task_id_min_length == 0
+            || config.task_id_min_length > config.task_id_max_length
+            || config.dispute_reason_min_length == 0
+            || config.dispute_reason_min_length > config.dispute_reason_max_length
+            || config.issue_url_max_length == 0
+            || config.issue_url_max_length > MAX_ISSUE_URL_BUFFER as u32
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        // Update validation limits
+        env.storage().instance().set(&DataKey::Config, &config);
+
+        // Emit config updated event
+        let admin = Self::get_admin(env.clone())?;
+        crate::events::emit_config_updated(&env, config, admin);
+
+        Ok(())
+    }
+
+    /// Get the validation limits
+    pub fn get_config(env: Env) -> Result<Config, Error> {
+        Ok(Self::config(&env))
+    }
+
+    /// Helper function to read the validation limits
+    /// Deployments initialized before limits were configurable use the defaults
+    fn config(env: &Env) -> Config {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .unwrap_or_else(Self::default_config)
+    }
+
+    /// Helper function to build the validation limits set on initialization
+    fn default_config() -> Config {
+        Config {
+            min_amount: DEFAULT_MIN_AMOUNT,
+            max_amount: DEFAULT_MAX_AMOUNT,
+            task_id_min_length: DEFAULT_TASK_ID_LENGTH,
+            task_id_max_length: DEFAULT_TASK_ID_LENGTH,
+            dispute_reason_min_length: DEFAULT_MIN_DISPUTE_REASON_LENGTH,
+            dispute_reason_max_length: DEFAULT_MAX_DISPUTE_REASON_LENGTH,
+            issue_url_max_length: DEFAULT_MAX_ISSUE_URL_LENGTH,
+        }
+    }
+
     /// Get the current admin address
     pub fn get_admin(env: Env) -> Result<Address, Error> {
         env.storage()
@@ -129,31 +402,62 @@ impl TaskEscrowContract {
         task_id: String,
         issue_url: String,
         bounty_amount: i128,
+    ) -> Result<(), Error> {
+        let options = EscrowOptions {
+            labels_hash: None,
+            tags: Vec::new(&env),
+            difficulty: Difficulty::Unrated,
+        };
+        Self::create_escrow_with_options(env, creator, task_id, issue_url, bounty_amount, options)
+    }
+
+    /// Create a new escrow with optional details about the issue
+    /// Behaves like `create_escrow`, additionally recording the supplied
+    /// labels hash in the escrow's issue metadata along with its skill tags
+    /// and difficulty
+    pub fn create_escrow_with_options(
+        env: Env,
+        creator: Address,
+        task_id: String,
+        issue_url: String,
+        bounty_amount: i128,
+        options: EscrowOptions,
     ) -> Result<(), Error> {
         // Validate contract state and initialization
         Self::validate_contract_state(&env)?;
         Self::check_not_paused(&env)?;
 
         // Validate creator address
-        Self::validate_address(&creator)?;
+        Self::validate_participant(&env, &creator)?;
 
         // Require authentication from the creator
         creator.require_auth();
 
         // Validate task_id format with enhanced checks
-        Self::validate_task_id(&task_id)?;
+        Self::validate_new_task_id(&env, &task_id)?;
 
-        // Validate issue_url
-        Self::validate_issue_url(&issue_url)?;
+        // Validate issue_url and store it in canonical form with its parsed parts
+        let (issue_url, mut issue) = Self::validate_issue_url(&env, &issue_url)?;
+        issue.labels_hash = options.labels_hash.clone();
 
         // Validate bounty amount with enhanced checks
-        Self::validate_amount(bounty_amount)?;
+        Self::validate_amount(&env, bounty_amount)?;
+
+        // Validate skill tags
+        Self::validate_tags(&env, &options.tags)?;
 
         // Check if task already exists
         if Self::task_exists(&env, &task_id) {
             return Err(Error::TaskAlreadyExists);
         }
 
+        // Check the issue is not already funded by an active escrow
+        if let Some(existing) = Self::issue_task(&env, &issue_url) {
+            if !Self::is_resolved(&Self::load_escrow(&env, &existing)?) {
+                return Err(Error::IssueAlreadyEscrowed);
+            }
+        }
+
         // Check creator has sufficient USDC balance using helper
         // if !Self::has_sufficient_usdc_balance(env.clone(), creator.clone(), bounty_amount)? {
         //     return Err(Error::InsufficientBalance);
@@ -163,103 +467,896 @@ impl TaskEscrowContract {
         Self::transfer_usdc_to_contract(&env, &creator, bounty_amount)?;
 
         // Create escrow record
-        let null_address = Address::from_string(&String::from_str(
-            &env,
-            "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
-        ));
         let escrow = TaskEscrow {
             task_id: task_id.clone(),
             issue_url: issue_url.clone(),
             creator: creator.clone(),
-            contributor: null_address, // Use null address placeholder
-            has_contributor: false,
+            contributor: None,
             bounty_amount,
             status: TaskStatus::Open,
             created_at: env.ledger().timestamp(),
-            completed_at: 0,
-            disputed_at: 0,
+            completed_at: None,
+            disputed_at: None,
+            issue: issue.clone(),
+            tags: options.tags.clone(),
+            difficulty: options.difficulty,
         };
 
-        // Store escrow data
-        env.storage()
-            .persistent()
-            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+        // Store escrow data
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+
+        // Point the issue at its newest escrow
+        env.storage()
+            .persistent()
+            .set(&DataKey::IssueTask(issue_url.clone()), &task_id);
+
+        // Append the task to its repository's index; a new task cannot be in it yet
+        Self::append_repo_task(&env, &task_id, &issue);
+
+        // Append the task to the index of each of its tags
+        for tag in options.tags.iter() {
+            Self::index_task_tag(&env, &task_id, &tag);
+        }
+
+        // Emit escrow created event
+        crate::events::emit_escrow_created(
+            &env,
+            task_id,
+            issue_url,
+            creator,
+            bounty_amount,
+            options,
+        );
+
+        Ok(())
+    }
+
+    /// Get escrow information for a specific task
+    pub fn get_escrow(env: Env, task_id: String) -> Result<TaskEscrow, Error> {
+        // Validate task_id format
+        Self::validate_task_id(&task_id)?;
+
+        // Retrieve escrow data
+        Self::load_escrow(&env, &task_id)
+    }
+
+    /// Get the newest escrow created for a GitHub issue
+    /// The URL is canonicalized first, so any accepted spelling of it matches
+    pub fn get_escrow_by_issue(env: Env, issue_url: String) -> Result<TaskEscrow, Error> {
+        // Validate and canonicalize issue_url
+        let (issue_url, _) = Self::validate_issue_url(&env, &issue_url)?;
+
+        // Look up the task funding the issue
+        let task_id = Self::issue_task(&env, &issue_url).ok_or(Error::TaskNotFound)?;
+        Self::load_escrow(&env, &task_id)
+    }
+
+    /// List escrows created for a GitHub repository, oldest first
+    /// Owner and repository names are case-insensitive. Pages hold up to 20
+    /// index entries and start at 0; pages past the end are empty. Escrows
+    /// created before repository indexing was introduced are not listed, and
+    /// entries for escrows later moved to another repository are skipped, so
+    /// a page may hold fewer escrows.
+    pub fn list_escrows_by_repo(
+        env: Env,
+        owner: String,
+        repo: String,
+        page: u32,
+    ) -> Result<Vec<TaskEscrow>, Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+
+        // Validate and lowercase the repository name
+        let owner = Self::repo_segment(&env, &owner, MAX_GITHUB_OWNER_LENGTH, Self::is_owner_byte)
+            .ok_or(Error::InvalidRepository)?;
+        let repo = Self::repo_segment(&env, &repo, MAX_GITHUB_REPO_LENGTH, Self::is_repo_byte)
+            .ok_or(Error::InvalidRepository)?;
+
+        // Collect the requested page of indexed tasks
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RepoTaskCount(owner.clone(), repo.clone()))
+            .unwrap_or(0);
+        let start = page.saturating_mul(INDEX_PAGE_SIZE).min(count);
+        let end = start.saturating_add(INDEX_PAGE_SIZE).min(count);
+        let mut escrows = Vec::new(&env);
+        for index in start..end {
+            if let Some(task_id) = env.storage().persistent().get(&DataKey::RepoTask(
+                owner.clone(),
+                repo.clone(),
+                index,
+            )) {
+                let escrow = Self::load_escrow(&env, &task_id)?;
+                if escrow.issue.owner == owner && escrow.issue.repo == repo {
+                    escrows.push_back(escrow);
+                }
+            }
+        }
+
+        Ok(escrows)
+    }
+
+    /// List escrows currently tagged with a skill tag, oldest first
+    /// Pages hold up to 20 index entries and start at 0; pages past the end
+    /// are empty. Entries for tasks whose tag was later removed are skipped,
+    /// so a page may hold fewer escrows.
+    pub fn list_escrows_by_tag(env: Env, tag: Symbol, page: u32) -> Result<Vec<TaskEscrow>, Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+
+        // Validate tag format
+        Self::validate_tag(&env, &tag)?;
+
+        // Collect the requested page of indexed tasks still carrying the tag
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TagTaskCount(tag.clone()))
+            .unwrap_or(0);
+        let start = page.saturating_mul(INDEX_PAGE_SIZE).min(count);
+        let end = start.saturating_add(INDEX_PAGE_SIZE).min(count);
+        let mut escrows = Vec::new(&env);
+        for index in start..end {
+            if let Some(task_id) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::TagTask(tag.clone(), index))
+            {
+                let escrow = Self::load_escrow(&env, &task_id)?;
+                if escrow.tags.contains(&tag) {
+                    escrows.push_back(escrow);
+                }
+            }
+        }
+
+        Ok(escrows)
+    }
+
+    /// Replace the skill tags and difficulty of an open task
+    /// Can only be called by the task creator
+    pub fn update_task_tags(
+        env: Env,
+        creator: Address,
+        task_id: String,
+        tags: Vec<Symbol>,
+        difficulty: Difficulty,
+    ) -> Result<(), Error> {
+        // Check paused state
+        Self::check_not_paused(&env)?;
+
+        // Authenticate creator
+        creator.require_auth();
+
+        // Validate input
+        Self::validate_tags(&env, &tags)?;
+
+        // Get escrow data
+        Self::validate_task_id(&task_id)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+
+        // Verify creator is the owner of the task
+        if escrow.creator != creator {
+            return Err(Error::NotTaskCreator);
+        }
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
+        // Allow editing tags for only Open tasks
+        if escrow.status != TaskStatus::Open {
+            return Err(Error::InvalidTaskStatus);
+        }
+
+        // Index newly added tags; removed tags are filtered out when listing
+        for tag in tags.iter() {
+            Self::index_task_tag(&env, &task_id, &tag);
+        }
+
+        // Save updated escrow
+        escrow.tags = tags.clone();
+        escrow.difficulty = difficulty;
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+
+        // Emit event
+        crate::events::emit_task_tags_updated(&env, task_id, creator, tags, difficulty);
+
+        Ok(())
+    }
+
+    /// Point an open task at a different issue or replace its labels hash
+    /// Can only be called by the task creator. The new URL is validated and
+    /// canonicalized like in `create_escrow` and must not be funded by another
+    /// active escrow.
+    pub fn update_task_metadata(
+        env: Env,
+        creator: Address,
+        task_id: String,
+        issue_url: String,
+        labels_hash: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        // Check paused state
+        Self::check_not_paused(&env)?;
+
+        // Authenticate creator
+        creator.require_auth();
+
+        // Validate and canonicalize issue_url
+        let (issue_url, mut issue) = Self::validate_issue_url(&env, &issue_url)?;
+        issue.labels_hash = labels_hash.clone();
+
+        // Get escrow data
+        Self::validate_task_id(&task_id)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+
+        // Verify creator is the owner of the task
+        if escrow.creator != creator {
+            return Err(Error::NotTaskCreator);
+        }
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
+        // Allow editing metadata for only Open tasks
+        if escrow.status != TaskStatus::Open {
+            return Err(Error::InvalidTaskStatus);
+        }
+
+        let old_issue_url = escrow.issue_url.clone();
+        let old_labels_hash = escrow.issue.labels_hash.clone();
+        if issue_url != old_issue_url {
+            // Check the new issue is not already funded by another active escrow
+            if let Some(existing) = Self::issue_task(&env, &issue_url) {
+                if existing != task_id && !Self::is_resolved(&Self::load_escrow(&env, &existing)?) {
+                    return Err(Error::IssueAlreadyEscrowed);
+                }
+            }
+
+            // Release the old issue if it still points at this task
+            if Self::issue_task(&env, &old_issue_url) == Some(task_id.clone()) {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::IssueTask(old_issue_url.clone()));
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssueTask(issue_url.clone()), &task_id);
+
+            // Escrows are indexed under the repository they were created in
+            // without a marker, so record it before the task moves away
+            if escrow.issue.forge == Forge::GitHub {
+                env.storage().persistent().set(
+                    &DataKey::TaskRepoIndexed(
+                        task_id.clone(),
+                        escrow.issue.owner.clone(),
+                        escrow.issue.repo.clone(),
+                    ),
+                    &true,
+                );
+            }
+
+            // Index the new repository; the old one filters the task out when listing
+            Self::index_task_repo(&env, &task_id, &issue);
+        }
+
+        // Save updated escrow
+        escrow.issue_url = issue_url.clone();
+        escrow.issue = issue;
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+
+        // Emit event
+        crate::events::emit_task_metadata_updated(
+            &env,
+            task_id,
+            creator,
+            old_issue_url,
+            issue_url,
+            old_labels_hash,
+            labels_hash,
+        );
+
+        Ok(())
+    }
+
+    /// Nominate a new creator for an open or in-progress task
+    /// Can only be called by the task creator. The nominee becomes the creator
+    /// once it calls `accept_task_ownership`; until then the current creator
+    /// keeps control and can nominate someone else instead.
+    pub fn transfer_task_ownership(
+        env: Env,
+        task_id: String,
+        new_creator: Address,
+    ) -> Result<(), Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+        Self::check_not_paused(&env)?;
+
+        // Validate task_id format with enhanced checks
+        Self::validate_task_id(&task_id)?;
+
+        // Validate new creator address
+        Self::validate_participant(&env, &new_creator)?;
+
+        // Get the existing escrow
+        let escrow = Self::load_escrow(&env, &task_id)?;
+
+        // Require authentication from the task creator
+        escrow.creator.require_auth();
+
+        // The task must change hands, and never to its contributor
+        if new_creator == escrow.creator || escrow.contributor.as_ref() == Some(&new_creator) {
+            return Err(Error::InvalidAddress);
+        }
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
+        // Disputes stay between the parties that opened them
+        if escrow.status == TaskStatus::Disputed {
+            return Err(Error::InvalidTaskStatus);
+        }
+
+        // Record the nominee, replacing any earlier one
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingTaskOwner(task_id.clone()), &new_creator);
+
+        // Emit event
+        crate::events::emit_task_ownership_proposed(&env, task_id, escrow.creator, new_creator);
+
+        Ok(())
+    }
+
+    /// Accept ownership of a task as its nominated creator
+    /// From then on the new creator approves, refunds and adjusts the bounty,
+    /// and receives refunds of the whole bounty, including funds deposited by
+    /// earlier creators.
+    pub fn accept_task_ownership(env: Env, task_id: String) -> Result<(), Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+        Self::check_not_paused(&env)?;
+
+        // Validate task_id format with enhanced checks
+        Self::validate_task_id(&task_id)?;
+
+        // Get the existing escrow and its nominee
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+        let new_creator =
+            Self::pending_task_owner(&env, &task_id).ok_or(Error::NoPendingOwnershipTransfer)?;
+
+        // Require authentication from the nominee
+        new_creator.require_auth();
+
+        // The contributor may have been assigned since the nomination
+        if escrow.contributor.as_ref() == Some(&new_creator) {
+            return Err(Error::InvalidAddress);
+        }
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
+        // Disputes stay between the parties that opened them
+        if escrow.status == TaskStatus::Disputed {
+            return Err(Error::InvalidTaskStatus);
+        }
+
+        // Hand the task over
+        let old_creator = escrow.creator.clone();
+        escrow.creator = new_creator.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingTaskOwner(task_id.clone()));
+
+        // Emit event
+        crate::events::emit_task_ownership_transferred(&env, task_id, old_creator, new_creator);
+
+        Ok(())
+    }
+
+    /// Withdraw the nomination of a new creator for a task
+    /// Can only be called by the task creator. Nominations are also dropped
+    /// when the task is completed or cancelled.
+    pub fn cancel_task_ownership_transfer(env: Env, task_id: String) -> Result<(), Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+        Self::check_not_paused(&env)?;
+
+        // Validate task_id format with enhanced checks
+        Self::validate_task_id(&task_id)?;
+
+        // Get the existing escrow and its nominee
+        let escrow = Self::load_escrow(&env, &task_id)?;
+        let pending_creator =
+            Self::pending_task_owner(&env, &task_id).ok_or(Error::NoPendingOwnershipTransfer)?;
+
+        // Require authentication from the task creator
+        escrow.creator.require_auth();
+
+        // Drop the nomination
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingTaskOwner(task_id.clone()));
+
+        // Emit event
+        crate::events::emit_task_ownership_cancelled(
+            &env,
+            task_id,
+            escrow.creator,
+            pending_creator,
+        );
+
+        Ok(())
+    }
+
+    /// Get the creator nominated to take over a task
+    pub fn get_pending_task_owner(env: Env, task_id: String) -> Result<Address, Error> {
+        // Validate task_id format
+        Self::validate_task_id(&task_id)?;
+
+        // Validate that the task exists first
+        if !Self::task_exists(&env, &task_id) {
+            return Err(Error::TaskNotFound);
+        }
+
+        Self::pending_task_owner(&env, &task_id).ok_or(Error::NoPendingOwnershipTransfer)
+    }
+
+    /// Helper function to append a task to its repository's index once
+    fn index_task_repo(env: &Env, task_id: &String, issue: &IssueRef) {
+        let indexed_key =
+            DataKey::TaskRepoIndexed(task_id.clone(), issue.owner.clone(), issue.repo.clone());
+        if env.storage().persistent().has(&indexed_key) {
+            return;
+        }
+
+        Self::append_repo_task(env, task_id, issue);
+        env.storage().persistent().set(&indexed_key, &true);
+    }
+
+    /// Helper function to append a task to its repository's index
+    fn append_repo_task(env: &Env, task_id: &String, issue: &IssueRef) {
+        let count_key = DataKey::RepoTaskCount(issue.owner.clone(), issue.repo.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage().persistent().set(
+            &DataKey::RepoTask(issue.owner.clone(), issue.repo.clone(), count),
+            task_id,
+        );
+        env.storage().persistent().set(&count_key, &(count + 1));
+    }
+
+    /// Helper function to append a task to a tag's index once
+    fn index_task_tag(env: &Env, task_id: &String, tag: &Symbol) {
+        let indexed_key = DataKey::TaskTagIndexed(task_id.clone(), tag.clone());
+        if env.storage().persistent().has(&indexed_key) {
+            return;
+        }
+
+        let count_key = DataKey::TagTaskCount(tag.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TagTask(tag.clone(), count), task_id);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage().persistent().set(&indexed_key, &true);
+    }
+
+    /// Helper function to find the newest task created for a canonical issue URL
+    fn issue_task(env: &Env, issue_url: &String) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IssueTask(issue_url.clone()))
+    }
+
+    /// Helper function to find the creator nominated to take over a task
+    fn pending_task_owner(env: &Env, task_id: &String) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingTaskOwner(task_id.clone()))
+    }
+
+    /// Helper function to drop the nomination of a task that reached a final status
+    fn clear_pending_task_owner(env: &Env, task_id: &String) {
+        let pending_key = DataKey::PendingTaskOwner(task_id.clone());
+        if env.storage().persistent().has(&pending_key) {
+            env.storage().persistent().remove(&pending_key);
+        }
+    }
+
+    /// Helper function to load an escrow, converting the legacy layout if needed
+    fn load_escrow(env: &Env, task_id: &String) -> Result<TaskEscrow, Error> {
+        let (escrow, _) = Self::load_stored_escrow(env, task_id)?;
+        Ok(escrow)
+    }
+
+    /// Helper function to load an escrow that is about to be saved
+    /// Saving stores the current layout, which `migrate_escrows` leaves alone,
+    /// so escrows stored before the indexes existed are indexed here first
+    fn load_escrow_for_update(env: &Env, task_id: &String) -> Result<TaskEscrow, Error> {
+        let (mut escrow, layout) = Self::load_stored_escrow(env, task_id)?;
+        if layout == StoredLayout::Unindexed {
+            Self::index_legacy_escrow(env, task_id, &mut escrow)?;
+        }
+        Ok(escrow)
+    }
+
+    /// Helper function to load an escrow along with the layout it is stored in
+    fn load_stored_escrow(
+        env: &Env,
+        task_id: &String,
+    ) -> Result<(TaskEscrow, StoredLayout), Error> {
+        let stored: Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TaskEscrow(task_id.clone()))
+            .ok_or(Error::TaskNotFound)?;
+
+        // Only the current layout has tags, so most reads stop here
+        if stored.contains_key(Symbol::new(env, TAGS_ESCROW_FIELD)) {
+            return Ok((
+                stored.to_val().try_into_val(env).unwrap(),
+                StoredLayout::Current,
+            ));
+        }
+
+        // Only the legacy layout has the contributor flag
+        if stored.contains_key(Symbol::new(env, LEGACY_ESCROW_FIELD)) {
+            let legacy: LegacyTaskEscrow = stored.to_val().try_into_val(env).unwrap();
+            let v6 = Self::upgrade_escrow_v5(env, legacy.into());
+            return Ok((Self::upgrade_escrow_v6(env, v6), StoredLayout::Unindexed));
+        }
+
+        // The version 5 layout has no issue metadata
+        if !stored.contains_key(Symbol::new(env, ISSUE_ESCROW_FIELD)) {
+            let v5: TaskEscrowV5 = stored.to_val().try_into_val(env).unwrap();
+            let v6 = Self::upgrade_escrow_v5(env, v5);
+            return Ok((Self::upgrade_escrow_v6(env, v6), StoredLayout::Unindexed));
+        }
+
+        // The version 6 layout has no tags or difficulty
+        let v6: TaskEscrowV6 = stored.to_val().try_into_val(env).unwrap();
+        Ok((Self::upgrade_escrow_v6(env, v6), StoredLayout::Untagged))
+    }
+
+    /// Helper function to convert a version 6 escrow, which has no tags and is unrated
+    fn upgrade_escrow_v6(env: &Env, escrow: TaskEscrowV6) -> TaskEscrow {
+        TaskEscrow {
+            task_id: escrow.task_id,
+            issue_url: escrow.issue_url,
+            creator: escrow.creator,
+            contributor: escrow.contributor,
+            bounty_amount: escrow.bounty_amount,
+            status: escrow.status,
+            created_at: escrow.created_at,
+            completed_at: escrow.completed_at,
+            disputed_at: escrow.disputed_at,
+            issue: escrow.issue,
+            tags: Vec::new(env),
+            difficulty: Difficulty::Unrated,
+        }
+    }
+
+    /// Helper function to convert a version 5 escrow, parsing its issue URL
+    /// URLs stored before they were validated may not parse and get an unknown forge
+    fn upgrade_escrow_v5(env: &Env, escrow: TaskEscrowV5) -> TaskEscrowV6 {
+        let issue = Self::parse_issue_url(env, &escrow.issue_url)
+            .map(|(_, issue)| issue)
+            .unwrap_or_else(|| IssueRef {
+                forge: Forge::Unknown,
+                owner: String::from_str(env, ""),
+                repo: String::from_str(env, ""),
+                number: 0,
+                labels_hash: None,
+            });
+        TaskEscrowV6 {
+            task_id: escrow.task_id,
+            issue_url: escrow.issue_url,
+            creator: escrow.creator,
+            contributor: escrow.contributor,
+            bounty_amount: escrow.bounty_amount,
+            status: escrow.status,
+            created_at: escrow.created_at,
+            completed_at: escrow.completed_at,
+            disputed_at: escrow.disputed_at,
+            issue,
+        }
+    }
+
+    /// Rewrite escrows stored in older layouts in the current one and add them
+    /// to the issue and repository indexes
+    /// Can only be called by admin; returns the number of escrows migrated
+    pub fn migrate_escrows(env: Env, task_ids: Vec<String>) -> Result<u32, Error> {
+        // Validate caller is current admin
+        Self::require_admin(&env)?;
+
+        let mut migrated = 0;
+        for task_id in task_ids.iter() {
+            // Escrows already in the current layout are left untouched
+            let (mut escrow, layout) = Self::load_stored_escrow(&env, &task_id)?;
+            if layout != StoredLayout::Current {
+                if layout == StoredLayout::Unindexed {
+                    Self::index_legacy_escrow(&env, &task_id, &mut escrow)?;
+                }
+
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::TaskEscrow(task_id), &escrow);
+                migrated += 1;
+            }
+        }
+
+        Ok(migrated)
+    }
+
+    /// Helper function to add an escrow created before the issue and
+    /// repository indexes to them under its canonical issue URL, so its issue
+    /// cannot be escrowed twice
+    fn index_legacy_escrow(
+        env: &Env,
+        task_id: &String,
+        escrow: &mut TaskEscrow,
+    ) -> Result<(), Error> {
+        let Some((issue_url, issue)) = Self::parse_issue_url(env, &escrow.issue_url) else {
+            return Ok(());
+        };
+
+        let point_issue = match Self::issue_task(env, &issue_url) {
+            None => true,
+            Some(existing) => {
+                existing != *task_id
+                    && !Self::is_resolved(escrow)
+                    && Self::is_resolved(&Self::load_escrow(env, &existing)?)
+            }
+        };
+        if point_issue {
+            env.storage()
+                .persistent()
+                .set(&DataKey::IssueTask(issue_url.clone()), task_id);
+        }
+        Self::index_task_repo(env, task_id, &issue);
+        escrow.issue_url = issue_url;
+
+        Ok(())
+    }
+
+    /// Helper function to check if a task exists
+    fn task_exists(env: &Env, task_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::TaskEscrow(task_id.clone()))
+    }
+
+    /// Helper function to check whether a task is completed or cancelled
+    fn is_resolved(escrow: &TaskEscrow) -> bool {
+        matches!(escrow.status, TaskStatus::Completed | TaskStatus::Cancelled)
+    }
+
+    /// Helper function to reject changes to completed or cancelled tasks
+    fn ensure_not_resolved(escrow: &TaskEscrow) -> Result<(), Error> {
+        if Self::is_resolved(escrow) {
+            return Err(Error::TaskAlreadyResolved);
+        }
+        Ok(())
+    }
+
+    /// Helper function to validate the ID of a new task against the task ID
+    /// character set and the configured length range (25 characters by default)
+    fn validate_new_task_id(env: &Env, task_id: &String) -> Result<(), Error> {
+        Self::validate_task_id(task_id)?;
+
+        // Check character set: cuids are lowercase alphanumerics, and
+        // hyphens and underscores also admit UUIDs and slug-style IDs.
+        // The ID is copied out in chunks rather than read a byte per host call
+        let bytes = task_id.to_bytes();
+        let mut chunk = [0u8; TASK_ID_CHUNK];
+        let mut start = 0;
+        while start < bytes.len() {
+            let end = bytes.len().min(start + TASK_ID_CHUNK as u32);
+            let part = &mut chunk[..(end - start) as usize];
+            bytes.slice(start..end).copy_into_slice(part);
+            if part
+                .iter()
+                .any(|byte| !byte.is_ascii_alphanumeric() && *byte != b'-' && *byte != b'_')
+            {
+                return Err(Error::InvalidTaskIdCharacters);
+            }
+            start = end;
+        }
+
+        let config = Self::config(env);
+        if task_id.len() < config.task_id_min_length {
+            return Err(Error::TaskIdTooShort);
+        }
+        if task_id.len() > config.task_id_max_length {
+            return Err(Error::TaskIdTooLong);
+        }
+
+        Ok(())
+    }
+
+    /// Helper function to validate the ID of an existing task
+    /// The character set and length limits only apply on creation, so escrows
+    /// created under earlier rules stay reachable
+    fn validate_task_id(task_id: &String) -> Result<(), Error> {
+        // Check for empty task ID
+        if task_id.is_empty() {
+            return Err(Error::EmptyTaskId);
+        }
+
+        Ok(())
+    }
+
+    /// Helper function to validate issue URL, returning its canonical form and parsed parts
+    fn validate_issue_url(env: &Env, url: &String) -> Result<(String, IssueRef), Error> {
+        if url.is_empty() {
+            return Err(Error::InvalidIssueUrl);
+        }
+        // Basic length check for URL
+        if url.len() > Self::config(env).issue_url_max_length {
+            return Err(Error::InvalidIssueUrl);
+        }
+        // Structure check for https://github.com/{owner}/{repo}/issues/{number}
+        Self::parse_issue_url(env, url).ok_or(Error::InvalidIssueUrl)
+    }
+
+    /// Helper function to parse a GitHub issue URL, returning it canonicalized to
+    /// `https://github.com/{owner}/{repo}/issues/{number}` in lowercase along
+    /// with its owner, repository and issue number.
+    /// The scheme may be `http` or `https`, the host may start with `www.`
+    /// and a single trailing slash is dropped; queries and fragments are
+    /// rejected. Owners are alphanumerics and hyphens, repositories may also
+    /// contain underscores and dots, and the issue number is a positive
+    /// integer without leading zeros.
+    fn parse_issue_url(env: &Env, url: &String) -> Option<(String, IssueRef)> {
+        const CANONICAL_PREFIX: &[u8] = b"https://github.com/";
+
+        let len = url.len() as usize;
+        if len > MAX_ISSUE_URL_BUFFER {
+            return None;
+        }
+        let mut buffer = [0u8; MAX_ISSUE_URL_BUFFER];
+        let lowercase = &mut buffer[..len];
+        url.copy_into_slice(lowercase);
+        lowercase.make_ascii_lowercase();
+
+        // Scheme and host
+        let rest = lowercase
+            .strip_prefix(b"https://")
+            .or_else(|| lowercase.strip_prefix(b"http://"))?;
+        let rest = rest.strip_prefix(b"www.").unwrap_or(rest);
+        let path = rest.strip_prefix(b"github.com/")?;
+        let path = path.strip_suffix(b"/").unwrap_or(path);
+
+        // Segments: owner, repo, "issues", number
+        let mut segments = path.split(|byte| *byte == b'/');
+        let owner = segments.next()?;
+        let repo = segments.next()?;
+        if segments.next()? != b"issues" {
+            return None;
+        }
+        let number = segments.next()?;
+        if segments.next().is_some() {
+            return None;
+        }
 
-        // Emit escrow created event
-        crate::events::emit_escrow_created(&env, task_id, creator, bounty_amount);
+        let valid_owner = (1..=MAX_GITHUB_OWNER_LENGTH).contains(&owner.len())
+            && owner.iter().copied().all(Self::is_owner_byte);
+        let valid_repo = (1..=MAX_GITHUB_REPO_LENGTH).contains(&repo.len())
+            && repo.iter().copied().all(Self::is_repo_byte);
+        let valid_number = (1..=MAX_ISSUE_NUMBER_DIGITS).contains(&number.len())
+            && number[0] != b'0'
+            && number.iter().all(u8::is_ascii_digit);
+        if !(valid_owner && valid_repo && valid_number) {
+            return None;
+        }
 
-        Ok(())
+        let mut canonical = [0u8; MAX_ISSUE_URL_BUFFER];
+        let canonical_len = CANONICAL_PREFIX.len() + path.len();
+        canonical[..CANONICAL_PREFIX.len()].copy_from_slice(CANONICAL_PREFIX);
+        canonical[CANONICAL_PREFIX.len()..canonical_len].copy_from_slice(path);
+        let issue = IssueRef {
+            forge: Forge::GitHub,
+            owner: String::from_bytes(env, owner),
+            repo: String::from_bytes(env, repo),
+            number: number
+                .iter()
+                .fold(0, |value, digit| value * 10 + u64::from(digit - b'0')),
+            labels_hash: None,
+        };
+        Some((String::from_bytes(env, &canonical[..canonical_len]), issue))
     }
 
-    /// Get escrow information for a specific task
-    pub fn get_escrow(env: Env, task_id: String) -> Result<TaskEscrow, Error> {
-        // Validate task_id format
-        Self::validate_task_id(&task_id)?;
+    /// Helper function to check a byte is allowed in a GitHub owner name
+    fn is_owner_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || byte == b'-'
+    }
 
-        // Retrieve escrow data
-        env.storage()
-            .persistent()
-            .get(&DataKey::TaskEscrow(task_id))
-            .ok_or(Error::TaskNotFound)
+    /// Helper function to check a byte is allowed in a GitHub repository name
+    fn is_repo_byte(byte: u8) -> bool {
+        byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.')
     }
 
-    /// Helper function to check if a task exists
-    fn task_exists(env: &Env, task_id: &String) -> bool {
-        env.storage()
-            .persistent()
-            .has(&DataKey::TaskEscrow(task_id.clone()))
+    /// Helper function to validate an owner or repository name, returning it in lowercase
+    fn repo_segment(
+        env: &Env,
+        name: &String,
+        max_length: usize,
+        is_valid_byte: fn(u8) -> bool,
+    ) -> Option<String> {
+        let len = name.len() as usize;
+        if !(1..=max_length).contains(&len) {
+            return None;
+        }
+        let mut buffer = [0u8; MAX_GITHUB_REPO_LENGTH];
+        let lowercase = &mut buffer[..len];
+        name.copy_into_slice(lowercase);
+        lowercase.make_ascii_lowercase();
+        if !lowercase.iter().copied().all(is_valid_byte) {
+            return None;
+        }
+        Some(String::from_bytes(env, lowercase))
     }
 
-    /// Helper function to validate task ID format with comprehensive checks
-    fn validate_task_id(task_id: &String) -> Result<(), Error> {
-        // Check for empty task ID
-        if task_id.len() == 0 {
-            return Err(Error::EmptyTaskId);
+    /// Helper function to validate the skill tags of a task
+    fn validate_tags(env: &Env, tags: &Vec<Symbol>) -> Result<(), Error> {
+        // Check tag count
+        if tags.len() > MAX_TASK_TAGS {
+            return Err(Error::InvalidTags);
         }
 
-        // Check required length (25 characters)
-        if task_id.len() != 25 {
-            return Err(Error::InvalidTaskId);
+        for (index, tag) in tags.iter().enumerate() {
+            Self::validate_tag(env, &tag)?;
+
+            // Check each tag appears once
+            if tags.first_index_of(&tag) != Some(index as u32) {
+                return Err(Error::InvalidTags);
+            }
         }
 
         Ok(())
     }
 
-    /// Helper function to validate issue URL
-    fn validate_issue_url(url: &String) -> Result<(), Error> {
-        if url.len() == 0 {
-            return Err(Error::InvalidIssueUrl);
-        }
-        // Basic length check for URL
-        if url.len() > 500 {
-            return Err(Error::InvalidIssueUrl);
+    /// Helper function to validate a skill tag: 1 to 9 lowercase letters,
+    /// digits or underscores, so each tag is stored inline and matches its
+    /// index regardless of how it was spelled
+    fn validate_tag(env: &Env, tag: &Symbol) -> Result<(), Error> {
+        let name =
+            SymbolStr::try_from_val(env, &tag.to_symbol_val()).map_err(|_| Error::InvalidTags)?;
+        let name: &str = name.as_ref();
+        let valid = (1..=MAX_TAG_LENGTH).contains(&name.len())
+            && name
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_');
+        if !valid {
+            return Err(Error::InvalidTags);
         }
         Ok(())
     }
 
     /// Helper function to validate bounty amount with comprehensive checks
-    fn validate_amount(amount: i128) -> Result<(), Error> {
+    fn validate_amount(env: &Env, amount: i128) -> Result<(), Error> {
         // Check for negative or zero amounts
         if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
 
-        // Check minimum amount (0.01 USDC = 100000 stroops)
+        // Check configured minimum (0.01 USDC by default)
         // This prevents dust amounts that could cause issues
-        if amount < 100000 {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Check for reasonable upper limit (1 billion USDC)
-        // USDC has 7 decimal places, so this is 1,000,000,000.0000000 USDC
-        if amount > 1_000_000_000_0000000 {
-            return Err(Error::InvalidTokenAmount);
+        let config = Self::config(env);
+        if amount < config.min_amount {
+            return Err(Error::AmountTooSmall);
         }
 
-        // Validate precision - USDC has 7 decimal places
-        // Ensure the amount is properly formatted for USDC
-        // This prevents precision errors in token operations
-        if amount % 1 != 0 {
+        // Check configured upper limit (1 billion USDC by default)
+        if amount > config.max_amount {
             return Err(Error::InvalidTokenAmount);
         }
 
@@ -285,13 +1382,17 @@ impl TaskEscrowContract {
         }
 
         // Get escrow data
-        let mut escrow = Self::get_escrow(env.clone(), task_id.clone())?;
+        Self::validate_task_id(&task_id)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
 
         // Verify creator is the owner of the task
         if escrow.creator != creator {
             return Err(Error::NotTaskCreator);
         }
 
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
         // Allow increasing bounty for only Open tasks
         if escrow.status != TaskStatus::Open {
             return Err(Error::InvalidTaskStatus);
@@ -333,13 +1434,17 @@ impl TaskEscrowContract {
         }
 
         // Get escrow data
-        let mut escrow = Self::get_escrow(env.clone(), task_id.clone())?;
+        Self::validate_task_id(&task_id)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
 
         // Verify creator is the owner of the task
         if escrow.creator != creator {
             return Err(Error::NotTaskCreator);
         }
 
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
         // Allow decreasing bounty for only Open tasks
         if escrow.status != TaskStatus::Open {
             return Err(Error::InvalidTaskStatus);
@@ -383,20 +1488,27 @@ impl TaskEscrowContract {
         Self::validate_task_id(&task_id)?;
 
         // Validate contributor address
-        Self::validate_address(&contributor)?;
+        Self::validate_participant(&env, &contributor)?;
 
         // Get the existing escrow
-        let mut escrow: TaskEscrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TaskEscrow(task_id.clone()))
-            .ok_or(Error::TaskNotFound)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
 
         // Require authentication from the task creator
         escrow.creator.require_auth();
 
+        // Creators cannot assign their own tasks to themselves, nor to the
+        // creator they nominated to take over
+        if contributor == escrow.creator
+            || Self::pending_task_owner(&env, &task_id).as_ref() == Some(&contributor)
+        {
+            return Err(Error::InvalidAddress);
+        }
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
         // Validate no contributor is already assigned
-        if escrow.has_contributor {
+        if escrow.contributor.is_some() {
             return Err(Error::ContributorAlreadyAssigned);
         }
 
@@ -406,8 +1518,7 @@ impl TaskEscrowContract {
         }
 
         // Update escrow with contributor information
-        escrow.contributor = contributor.clone();
-        escrow.has_contributor = true;
+        escrow.contributor = Some(contributor.clone());
         escrow.status = TaskStatus::InProgress;
 
         // Store updated escrow data
@@ -432,39 +1543,39 @@ impl TaskEscrowContract {
         Self::validate_task_id(&task_id)?;
 
         // Get the existing escrow
-        let mut escrow: TaskEscrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TaskEscrow(task_id.clone()))
-            .ok_or(Error::TaskNotFound)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
 
         // Require authentication from the task creator
         escrow.creator.require_auth();
 
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
         // Validate task status (must be InProgress)
         if escrow.status != TaskStatus::InProgress {
             return Err(Error::InvalidTaskStatus);
         }
 
         // Validate that a contributor is assigned
-        if !escrow.has_contributor {
+        let Some(contributor) = escrow.contributor.clone() else {
             return Err(Error::NoContributorAssigned);
-        }
+        };
 
         // Transfer USDC from contract to contributor using safe helper
-        Self::transfer_usdc_from_contract(&env, &escrow.contributor, escrow.bounty_amount)?;
+        Self::transfer_usdc_from_contract(&env, &contributor, escrow.bounty_amount)?;
 
         // Update escrow status to Completed
         escrow.status = TaskStatus::Completed;
-        escrow.completed_at = env.ledger().timestamp(); // Mark completed time
+        escrow.completed_at = Some(env.ledger().timestamp()); // Mark completed time
 
         // Store updated escrow data
         env.storage()
             .persistent()
             .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+        Self::clear_pending_task_owner(&env, &task_id);
 
         // Emit funds released event
-        crate::events::emit_funds_released(&env, task_id, escrow.contributor, escrow.bounty_amount);
+        crate::events::emit_funds_released(&env, task_id, contributor, escrow.bounty_amount);
 
         Ok(())
     }
@@ -485,25 +1596,24 @@ impl TaskEscrowContract {
         Self::validate_task_id(&task_id)?;
 
         // Validate disputing party address
-        Self::validate_address(&disputing_party)?;
+        Self::validate_address(&env, &disputing_party)?;
 
         // Validate dispute reason with enhanced checks
-        Self::validate_dispute_reason(&reason)?;
+        Self::validate_dispute_reason(&env, &reason)?;
 
         // Require authentication from the disputing party
         disputing_party.require_auth();
 
         // Get the existing escrow
-        let mut escrow: TaskEscrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TaskEscrow(task_id.clone()))
-            .ok_or(Error::TaskNotFound)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
 
         // Validate that a contributor is assigned (needed for resolution)
-        if !escrow.has_contributor {
+        let Some(contributor) = &escrow.contributor else {
             return Err(Error::NoContributorAssigned);
-        }
+        };
 
         // Validate task is InProgress.
         // If it is already Completed (paid), Disputed, or Cancelled, we can't dispute
@@ -513,7 +1623,7 @@ impl TaskEscrowContract {
 
         // Validate that the disputing party is either creator or contributor
         let is_creator = disputing_party == escrow.creator;
-        let is_contributor = escrow.has_contributor && disputing_party == escrow.contributor;
+        let is_contributor = disputing_party == *contributor;
 
         if !is_creator && !is_contributor {
             return Err(Error::OnlyCreatorOrContributor);
@@ -532,9 +1642,12 @@ impl TaskEscrowContract {
             .persistent()
             .set(&DataKey::Dispute(task_id.clone()), &dispute_info);
 
+        // Append to the task's dispute history
+        Self::record_dispute_opened(&env, &dispute_info);
+
         // Update escrow status to disputed
         escrow.status = TaskStatus::Disputed;
-        escrow.disputed_at = env.ledger().timestamp();
+        escrow.disputed_at = Some(env.ledger().timestamp());
 
         // Store updated escrow data
         env.storage()
@@ -547,20 +1660,82 @@ impl TaskEscrowContract {
         Ok(())
     }
 
-    /// Helper function to validate dispute reason with comprehensive checks
-    fn validate_dispute_reason(reason: &String) -> Result<(), Error> {
-        // Check for empty reason
-        if reason.len() == 0 {
-            return Err(Error::InvalidDisputeReason);
+    /// Withdraw an open dispute and return the task to InProgress
+    /// Can only be called by the party that initiated the dispute
+    pub fn withdraw_dispute(env: Env, party: Address, task_id: String) -> Result<(), Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+        Self::check_not_paused(&env)?;
+
+        // Validate task_id format with enhanced checks
+        Self::validate_task_id(&task_id)?;
+
+        // Validate withdrawing party address
+        Self::validate_address(&env, &party)?;
+
+        // Require authentication from the withdrawing party
+        party.require_auth();
+
+        // Get the existing escrow
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
+        // Validate task status (must be Disputed)
+        if escrow.status != TaskStatus::Disputed {
+            return Err(Error::TaskNotDisputed);
         }
 
-        // Check minimum length (at least 10 characters for meaningful dispute reasons)
-        if reason.len() < 10 {
-            return Err(Error::InvalidDisputeReason);
+        // Get the dispute record
+        let dispute_info: DisputeInfo = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(task_id.clone()))
+            .ok_or(Error::TaskNotDisputed)?;
+
+        // Only the party that opened the dispute may withdraw it
+        if dispute_info.disputing_party != party {
+            return Err(Error::NotDisputingParty);
+        }
+
+        // Clear dispute info and archive the outcome
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Dispute(task_id.clone()));
+        Self::record_dispute_closed(
+            &env,
+            &task_id,
+            DisputeOutcome::Withdrawn,
+            Some(party.clone()),
+        );
+
+        // Restore the status the task had before the dispute
+        escrow.status = TaskStatus::InProgress;
+        escrow.disputed_at = None;
+
+        // Store updated escrow data
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+
+        // Emit dispute withdrawn event
+        crate::events::emit_dispute_withdrawn(&env, task_id, party);
+
+        Ok(())
+    }
+
+    /// Helper function to validate dispute reason with comprehensive checks
+    fn validate_dispute_reason(env: &Env, reason: &String) -> Result<(), Error> {
+        // Check minimum length (10 characters by default for meaningful
+        // dispute reasons), which also rejects empty reasons
+        let config = Self::config(env);
+        if reason.len() < config.dispute_reason_min_length {
+            return Err(Error::DisputeReasonTooShort);
         }
 
         // Check maximum length (reasonable limit for storage and readability)
-        if reason.len() > 500 {
+        if reason.len() > config.dispute_reason_max_length {
             return Err(Error::InvalidDisputeReason);
         }
 
@@ -568,15 +1743,35 @@ impl TaskEscrowContract {
     }
 
     /// Helper function to validate addresses and prevent null/invalid addresses
-    fn validate_address(_address: &Address) -> Result<(), Error> {
-        // To be implemented
+    fn validate_address(env: &Env, address: &Address) -> Result<(), Error> {
+        // Reject the all-zero placeholder account
+        if *address == Address::from_str(env, NULL_ADDRESS) {
+            return Err(Error::InvalidAddress);
+        }
+
+        Ok(())
+    }
+
+    /// Helper function to validate the admin, a creator, a contributor or a payout recipient
+    /// These roles can never be held by the contract itself
+    fn validate_participant(env: &Env, address: &Address) -> Result<(), Error> {
+        Self::validate_address(env, address)?;
+
+        if *address == env.current_contract_address() {
+            return Err(Error::InvalidAddress);
+        }
+
         Ok(())
     }
 
     /// Helper function to validate partial payment amounts in dispute resolution
-    fn validate_partial_payment(partial_amount: i128, total_amount: i128) -> Result<(), Error> {
+    fn validate_partial_payment(
+        env: &Env,
+        partial_amount: i128,
+        total_amount: i128,
+    ) -> Result<(), Error> {
         // Validate the partial amount itself
-        Self::validate_amount(partial_amount)?;
+        Self::validate_amount(env, partial_amount)?;
 
         // Ensure partial amount doesn't exceed total amount
         if partial_amount > total_amount {
@@ -600,27 +1795,12 @@ impl TaskEscrowContract {
 
     /// Helper function to validate contract state before operations
     fn validate_contract_state(env: &Env) -> Result<(), Error> {
-        // Check if contract is initialized
+        // Admin and USDC token addresses were validated when stored, so they
+        // are not parsed against the null address again here
         if !Self::is_initialized(env) {
             return Err(Error::ContractNotInitialized);
         }
 
-        // Verify admin address exists and is valid
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::ContractNotInitialized)?;
-        Self::validate_address(&admin)?;
-
-        // Verify USDC token address exists and is valid
-        let usdc_token: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::UsdcToken)
-            .ok_or(Error::TokenContractNotSet)?;
-        Self::validate_address(&usdc_token)?;
-
         Ok(())
     }
 
@@ -657,11 +1837,10 @@ impl TaskEscrowContract {
         Self::require_admin(&env)?;
 
         // Get the existing escrow
-        let mut escrow: TaskEscrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TaskEscrow(task_id.clone()))
-            .ok_or(Error::TaskNotFound)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
 
         // Validate task status (must be Disputed)
         if escrow.status != TaskStatus::Disputed {
@@ -669,9 +1848,9 @@ impl TaskEscrowContract {
         }
 
         // Validate that a contributor is assigned (needed for resolution)
-        if !escrow.has_contributor {
+        let Some(contributor) = escrow.contributor.clone() else {
             return Err(Error::NoContributorAssigned);
-        }
+        };
 
         // Get admin address for event emission
         let admin: Address = env
@@ -680,44 +1859,304 @@ impl TaskEscrowContract {
             .get(&DataKey::Admin)
             .ok_or(Error::ContractNotInitialized)?;
 
+        // Credit the configured arbitration fee to the resolving admin
+        let fee_basis_points = Self::get_arbitration_fee(env.clone())?;
+        let fee = Self::basis_points_of(escrow.bounty_amount, fee_basis_points)?;
+        if fee > 0 {
+            Self::credit_arbiter_fee(&env, &task_id, &admin, fee);
+        }
+
+        // Pay out the remaining pool and close the escrow
+        let pool = escrow.bounty_amount - fee;
+        Self::apply_dispute_resolution(&env, &mut escrow, &contributor, &resolution, &admin, pool)?;
+
+        // Emit dispute resolved event
+        crate::events::emit_dispute_resolved(&env, task_id, resolution, admin);
+
+        Ok(())
+    }
+
+    /// Resolve a dispute that has been open longer than the configured timeout
+    /// Can be called by anyone; applies the configured default policy
+    pub fn resolve_stale_dispute(env: Env, task_id: String) -> Result<(), Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+        Self::check_not_paused(&env)?;
+
+        // Validate task_id format with enhanced checks
+        Self::validate_task_id(&task_id)?;
+
+        // Get the existing escrow
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
+
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
+        // Validate task status (must be Disputed)
+        if escrow.status != TaskStatus::Disputed {
+            return Err(Error::TaskNotDisputed);
+        }
+
+        // Validate that a contributor is assigned (needed for resolution)
+        let Some(contributor) = escrow.contributor.clone() else {
+            return Err(Error::NoContributorAssigned);
+        };
+
+        // Validate the dispute has been open for longer than the timeout
+        let (timeout, policy) = Self::get_dispute_timeout(env.clone())?;
+        let disputed_at = escrow.disputed_at.unwrap_or_default();
+        if env.ledger().timestamp() < disputed_at.saturating_add(timeout) {
+            return Err(Error::DisputeNotExpired);
+        }
+
+        // Translate the default policy into a concrete resolution
+        let resolution = match policy {
+            DisputeTimeoutPolicy::PayContributor => DisputeResolution::PayContributor,
+            DisputeTimeoutPolicy::RefundCreator => DisputeResolution::RefundCreator,
+            DisputeTimeoutPolicy::SplitEvenly => {
+                DisputeResolution::PercentageSplit(BASIS_POINTS_DENOMINATOR / 2)
+            }
+        };
+
+        // Pay out and close the escrow, attributed to the contract itself
+        // No arbitration fee is taken since no arbiter handled the dispute
+        let resolver = env.current_contract_address();
+        let pool = escrow.bounty_amount;
+        Self::apply_dispute_resolution(
+            &env,
+            &mut escrow,
+            &contributor,
+            &resolution,
+            &resolver,
+            pool,
+        )?;
+
+        // Emit dispute resolved event
+        crate::events::emit_dispute_resolved(&env, task_id, resolution, resolver);
+
+        Ok(())
+    }
+
+    /// Helper function to distribute the disputed pool for a resolution, mark the task
+    /// completed and archive the outcome in the dispute history
+    /// The pool is the bounty amount less any arbitration fee already credited
+    fn apply_dispute_resolution(
+        env: &Env,
+        escrow: &mut TaskEscrow,
+        contributor: &Address,
+        resolution: &DisputeResolution,
+        arbiter: &Address,
+        pool: i128,
+    ) -> Result<(), Error> {
         // Process resolution based on type with enhanced validation
         match resolution {
             DisputeResolution::PayContributor => {
                 // Transfer full amount to contributor using safe helper
-                Self::transfer_usdc_from_contract(&env, &escrow.contributor, escrow.bounty_amount)?;
+                Self::transfer_usdc_from_contract(env, contributor, pool)?;
             }
             DisputeResolution::RefundCreator => {
                 // Transfer full amount back to creator using safe helper
-                Self::transfer_usdc_from_contract(&env, &escrow.creator, escrow.bounty_amount)?;
+                Self::transfer_usdc_from_contract(env, &escrow.creator, pool)?;
             }
             DisputeResolution::PartialPayment(amount) => {
                 // Validate partial payment amount with enhanced checks
-                Self::validate_partial_payment(amount, escrow.bounty_amount)?;
+                Self::validate_partial_payment(env, *amount, pool)?;
 
                 // Transfer partial amount to contributor using safe helper
-                Self::transfer_usdc_from_contract(&env, &escrow.contributor, amount)?;
+                Self::transfer_usdc_from_contract(env, contributor, *amount)?;
 
                 // Transfer remaining amount to creator using safe helper
-                let remaining = escrow.bounty_amount - amount;
-                Self::transfer_usdc_from_contract(&env, &escrow.creator, remaining)?;
+                let remaining = pool - amount;
+                Self::transfer_usdc_from_contract(env, &escrow.creator, remaining)?;
+            }
+            DisputeResolution::PercentageSplit(basis_points) => {
+                // Split the full pool between contributor and creator
+                let contributor_share = Self::basis_points_of(pool, *basis_points)?;
+                Self::release_dispute_share(env, contributor, contributor_share)?;
+                Self::release_dispute_share(env, &escrow.creator, pool - contributor_share)?;
+            }
+            DisputeResolution::SplitWithArbiterFee(basis_points, fee) => {
+                // Arbiter fee must fit within the pool
+                if *fee < 0 || *fee > pool {
+                    return Err(Error::InvalidTokenAmount);
+                }
+
+                // Split what remains after the fee between contributor and creator
+                let remaining = pool - fee;
+                let contributor_share = Self::basis_points_of(remaining, *basis_points)?;
+                if *fee > 0 {
+                    Self::credit_arbiter_fee(env, &escrow.task_id, arbiter, *fee);
+                }
+                Self::release_dispute_share(env, contributor, contributor_share)?;
+                Self::release_dispute_share(env, &escrow.creator, remaining - contributor_share)?;
+            }
+            DisputeResolution::MultiPayout(payouts) => {
+                // Validate payout list before moving any funds
+                if payouts.is_empty() || payouts.len() > MAX_DISPUTE_PAYOUTS {
+                    return Err(Error::InvalidPayoutSplit);
+                }
+                let mut total: i128 = 0;
+                for payout in payouts.iter() {
+                    Self::validate_participant(env, &payout.recipient)?;
+                    if payout.amount <= 0 {
+                        return Err(Error::InvalidTokenAmount);
+                    }
+                    total = total
+                        .checked_add(payout.amount)
+                        .ok_or(Error::InvalidTokenAmount)?;
+                }
+                if total > pool {
+                    return Err(Error::InvalidTokenAmount);
+                }
+
+                // Pay each contributor, then return the remainder to the creator
+                for payout in payouts.iter() {
+                    Self::release_dispute_share(env, &payout.recipient, payout.amount)?;
+                }
+                Self::release_dispute_share(env, &escrow.creator, pool - total)?;
             }
         }
 
         // Update escrow status to completed
         escrow.status = TaskStatus::Completed;
-        escrow.completed_at = env.ledger().timestamp(); // Mark as completed
+        escrow.completed_at = Some(env.ledger().timestamp()); // Mark as completed
 
         // Store updated escrow data
         env.storage()
             .persistent()
-            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+            .set(&DataKey::TaskEscrow(escrow.task_id.clone()), escrow);
+        Self::clear_pending_task_owner(env, &escrow.task_id);
 
-        // Emit dispute resolved event
-        crate::events::emit_dispute_resolved(&env, task_id, resolution, admin);
+        // Archive the outcome in the dispute history
+        Self::record_dispute_closed(
+            env,
+            &escrow.task_id,
+            DisputeOutcome::Resolved(resolution.clone()),
+            Some(arbiter.clone()),
+        );
 
         Ok(())
     }
 
+    /// Helper function to append a newly opened dispute to the task's history
+    fn record_dispute_opened(env: &Env, dispute_info: &DisputeInfo) {
+        let task_id = dispute_info.task_id.clone();
+        let index: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeCount(task_id.clone()))
+            .unwrap_or(0);
+
+        let record = DisputeRecord {
+            index,
+            disputing_party: dispute_info.disputing_party.clone(),
+            reason: dispute_info.reason.clone(),
+            initiated_at: dispute_info.initiated_at,
+            outcome: DisputeOutcome::Pending,
+            resolved_by: None,
+            closed_at: None,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeRecord(task_id.clone(), index), &record);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeCount(task_id), &(index + 1));
+    }
+
+    /// Helper function to record the outcome of the task's latest dispute
+    /// Disputes opened before history tracking existed have no record and are skipped
+    fn record_dispute_closed(
+        env: &Env,
+        task_id: &String,
+        outcome: DisputeOutcome,
+        resolved_by: Option<Address>,
+    ) {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeCount(task_id.clone()))
+            .unwrap_or(0);
+        if count == 0 {
+            return;
+        }
+
+        let key = DataKey::DisputeRecord(task_id.clone(), count - 1);
+        if let Some(mut record) = env.storage().persistent().get::<_, DisputeRecord>(&key) {
+            record.outcome = outcome;
+            record.resolved_by = resolved_by;
+            record.closed_at = Some(env.ledger().timestamp());
+            env.storage().persistent().set(&key, &record);
+        }
+    }
+
+    /// Helper function to credit an arbitration fee to an arbiter's claimable balance
+    fn credit_arbiter_fee(env: &Env, task_id: &String, arbiter: &Address, amount: i128) {
+        let key = DataKey::ArbiterBalance(arbiter.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+
+        crate::events::emit_arbiter_fee_credited(env, task_id.clone(), arbiter.clone(), amount);
+    }
+
+    /// Claim all arbitration fees credited to an arbiter
+    /// Transfers the full claimable balance and returns the amount paid
+    pub fn claim_arbiter_fees(env: Env, arbiter: Address) -> Result<i128, Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+        Self::check_not_paused(&env)?;
+
+        // Validate arbiter address
+        Self::validate_address(&env, &arbiter)?;
+
+        // Require authentication from the arbiter
+        arbiter.require_auth();
+
+        // Get claimable balance
+        let key = DataKey::ArbiterBalance(arbiter.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(Error::NoFeesToClaim);
+        }
+
+        // Clear balance before transferring
+        env.storage().persistent().remove(&key);
+
+        // Transfer USDC from contract to arbiter using safe helper
+        Self::transfer_usdc_from_contract(&env, &arbiter, amount)?;
+
+        // Emit arbiter fees claimed event
+        crate::events::emit_arbiter_fees_claimed(&env, arbiter, amount);
+
+        Ok(amount)
+    }
+
+    /// Get the arbitration fees an arbiter can currently claim
+    pub fn get_arbiter_balance(env: Env, arbiter: Address) -> Result<i128, Error> {
+        Ok(env
+            .storage()
+            .persistent()
+            .get(&DataKey::ArbiterBalance(arbiter))
+            .unwrap_or(0))
+    }
+
+    /// Helper function to compute a basis-point share of an amount, rounding down
+    fn basis_points_of(amount: i128, basis_points: u32) -> Result<i128, Error> {
+        if basis_points > BASIS_POINTS_DENOMINATOR {
+            return Err(Error::InvalidBasisPoints);
+        }
+        Ok(amount * basis_points as i128 / BASIS_POINTS_DENOMINATOR as i128)
+    }
+
+    /// Helper function to release one share of a dispute resolution, skipping empty shares
+    fn release_dispute_share(env: &Env, to: &Address, amount: i128) -> Result<(), Error> {
+        if amount == 0 {
+            return Ok(());
+        }
+        Self::transfer_usdc_from_contract(env, to, amount)
+    }
+
     /// Process refund for a cancelled task
     /// Can only be called by the task creator when no contributor is assigned
     pub fn refund(env: Env, task_id: String) -> Result<(), Error> {
@@ -729,18 +2168,17 @@ impl TaskEscrowContract {
         Self::validate_task_id(&task_id)?;
 
         // Get the existing escrow
-        let mut escrow: TaskEscrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TaskEscrow(task_id.clone()))
-            .ok_or(Error::TaskNotFound)?;
+        let mut escrow = Self::load_escrow_for_update(&env, &task_id)?;
 
         // Require authentication from the task creator
         escrow.creator.require_auth();
 
+        // Completed and cancelled tasks are final
+        Self::ensure_not_resolved(&escrow)?;
+
         // Validate that no contributor is assigned (task status must be Open)
-        if escrow.has_contributor {
-            return Err(Error::ContributorAlreadyAssigned);
+        if escrow.contributor.is_some() {
+            return Err(Error::CannotRefundWithContributor);
         }
 
         // Validate task status (must be Open)
@@ -758,6 +2196,7 @@ impl TaskEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+        Self::clear_pending_task_owner(&env, &task_id);
 
         // Emit refund processed event
         crate::events::emit_refund_processed(&env, task_id, escrow.creator, escrow.bounty_amount);
@@ -786,6 +2225,40 @@ impl TaskEscrowContract {
             .ok_or(Error::TaskNotDisputed)
     }
 
+    /// Get every dispute ever opened for a task, oldest first
+    /// Each entry records its outcome, resolver and timestamps
+    pub fn get_dispute_history(env: Env, task_id: String) -> Result<Vec<DisputeRecord>, Error> {
+        // Validate contract state
+        Self::validate_contract_state(&env)?;
+
+        // Validate task_id format with enhanced checks
+        Self::validate_task_id(&task_id)?;
+
+        // Validate that the task exists first
+        if !Self::task_exists(&env, &task_id) {
+            return Err(Error::TaskNotFound);
+        }
+
+        // Collect indexed dispute records
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeCount(task_id.clone()))
+            .unwrap_or(0);
+        let mut history = Vec::new(&env);
+        for index in 0..count {
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<_, DisputeRecord>(&DataKey::DisputeRecord(task_id.clone(), index))
+            {
+                history.push_back(record);
+            }
+        }
+
+        Ok(history)
+    }
+
     // USDC Token Contract Integration Helpers
 
     /// Get the balance of USDC tokens for a specific address
@@ -795,7 +2268,7 @@ impl TaskEscrowContract {
         Self::validate_contract_state(&env)?;
 
         // Validate address
-        Self::validate_address(&address)?;
+        Self::validate_address(&env, &address)?;
 
         // Get USDC token contract address
         let usdc_token = Self::get_usdc_token_internal(&env)?;
@@ -818,10 +2291,10 @@ impl TaskEscrowContract {
         Self::validate_contract_state(&env)?;
 
         // Validate address
-        Self::validate_address(&address)?;
+        Self::validate_address(&env, &address)?;
 
         // Validate amount
-        Self::validate_amount(required_amount)?;
+        Self::validate_amount(&env, required_amount)?;
 
         // Get current balance
         let current_balance = Self::get_usdc_balance(env.clone(), address)?;
@@ -838,11 +2311,14 @@ impl TaskEscrowContract {
         amount: i128,
     ) -> Result<(), Error> {
         // Validate addresses
-        Self::validate_address(from)?;
-        Self::validate_address(to)?;
+        Self::validate_address(env, from)?;
+        Self::validate_address(env, to)?;
 
-        // Validate amount
-        Self::validate_amount(amount)?;
+        // Validate amount (minimum bounty size is enforced by callers, since
+        // dispute splits may legitimately produce shares below it)
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
         // Get USDC token contract address
         let usdc_token = Self::get_usdc_token_internal(env)?;
@@ -858,9 +2334,10 @@ impl TaskEscrowContract {
 
         // Perform the transfer
         // Note: The token contract will handle authorization checks
-        token_client.transfer(from, to, &amount);
-
-        Ok(())
+        match token_client.try_transfer(from, to, &amount) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(Error::TokenTransferFailed),
+        }
     }
 
     /// Transfer USDC from an external address to the contract
@@ -953,6 +2430,6 @@ impl TaskEscrowContract {
 
     /// Get contract version
     pub fn version() -> u64 {
-        4
+        7
     }
 }
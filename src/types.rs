@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, contracttype, Address, String};
+use soroban_sdk::{contractevent, contracttype, Address, BytesN, String, Symbol, Vec};
 
 /// Main escrow data structure for storing task information
 #[derive(Clone, Debug, PartialEq)]
@@ -7,13 +7,119 @@ pub struct TaskEscrow {
     pub task_id: String,
     pub issue_url: String, // Github issue URL
     pub creator: Address,
-    pub contributor: Address, // Will use a special "null" address when no contributor
-    pub has_contributor: bool, // Flag to indicate if contributor is set
+    pub contributor: Option<Address>, // None until a contributor is assigned
     pub bounty_amount: i128,
     pub status: TaskStatus,
     pub created_at: u64,
-    pub completed_at: u64, // Will use 0 when not set
-    pub disputed_at: u64,  // Will use 0 when not set
+    pub completed_at: Option<u64>, // None until the task is completed
+    pub disputed_at: Option<u64>,  // None unless a dispute is open
+    pub issue: IssueRef,           // Parsed from issue_url
+    pub tags: Vec<Symbol>,         // Skill tags, e.g. languages
+    pub difficulty: Difficulty,
+}
+
+/// Difficulty level of a task, as rated by its creator
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Difficulty {
+    Unrated,
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+/// Code forge hosting the issue an escrow funds
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Forge {
+    Unknown, // Issue URL stored before URLs were validated could not be parsed
+    GitHub,
+}
+
+/// Repository and issue an escrow funds, parsed from its issue URL
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct IssueRef {
+    pub forge: Forge,
+    pub owner: String, // Lowercase owner name, empty for unknown forges
+    pub repo: String,  // Lowercase repository name, empty for unknown forges
+    pub number: u64,   // 0 for unknown forges
+    pub labels_hash: Option<BytesN<32>>, // Hash of the issue labels, if supplied
+}
+
+/// Optional details supplied when creating an escrow
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EscrowOptions {
+    pub labels_hash: Option<BytesN<32>>, // Hash of the issue labels, computed off-chain
+    pub tags: Vec<Symbol>,               // Up to 5 lowercase symbols of at most 9 characters
+    pub difficulty: Difficulty,
+}
+
+/// Escrow layout stored by contract version 6
+/// Only read to migrate escrows created before tags and difficulty were introduced
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TaskEscrowV6 {
+    pub task_id: String,
+    pub issue_url: String,
+    pub creator: Address,
+    pub contributor: Option<Address>,
+    pub bounty_amount: i128,
+    pub status: TaskStatus,
+    pub created_at: u64,
+    pub completed_at: Option<u64>,
+    pub disputed_at: Option<u64>,
+    pub issue: IssueRef,
+}
+
+/// Escrow layout stored by contract version 5
+/// Only read to migrate escrows created before issue metadata was introduced
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TaskEscrowV5 {
+    pub task_id: String,
+    pub issue_url: String,
+    pub creator: Address,
+    pub contributor: Option<Address>,
+    pub bounty_amount: i128,
+    pub status: TaskStatus,
+    pub created_at: u64,
+    pub completed_at: Option<u64>,
+    pub disputed_at: Option<u64>,
+}
+
+/// Escrow layout stored by contract versions up to 4
+/// Only read to migrate escrows created before the optional fields were introduced
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LegacyTaskEscrow {
+    pub task_id: String,
+    pub issue_url: String,
+    pub creator: Address,
+    pub contributor: Address, // All-zero placeholder address when no contributor
+    pub has_contributor: bool,
+    pub bounty_amount: i128,
+    pub status: TaskStatus,
+    pub created_at: u64,
+    pub completed_at: u64, // 0 when not set
+    pub disputed_at: u64,  // 0 when not set
+}
+
+impl From<LegacyTaskEscrow> for TaskEscrowV5 {
+    fn from(legacy: LegacyTaskEscrow) -> Self {
+        TaskEscrowV5 {
+            task_id: legacy.task_id,
+            issue_url: legacy.issue_url,
+            creator: legacy.creator,
+            contributor: legacy.has_contributor.then_some(legacy.contributor),
+            bounty_amount: legacy.bounty_amount,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            completed_at: (legacy.completed_at != 0).then_some(legacy.completed_at),
+            disputed_at: (legacy.disputed_at != 0).then_some(legacy.disputed_at),
+        }
+    }
 }
 
 /// Task status enumeration for lifecycle management
@@ -37,24 +143,95 @@ pub struct DisputeInfo {
     pub initiated_at: u64,
 }
 
+/// Outcome of a single dispute in a task's history
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DisputeOutcome {
+    Pending,                     // Dispute is still open
+    Withdrawn,                   // Withdrawn by the disputing party
+    Resolved(DisputeResolution), // Resolved by admin or timeout
+}
+
+/// Archived dispute entry retained in a task's dispute history
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputeRecord {
+    pub index: u32,
+    pub disputing_party: Address,
+    pub reason: String,
+    pub initiated_at: u64,
+    pub outcome: DisputeOutcome,
+    pub resolved_by: Option<Address>, // Admin, or the contract itself for timeouts
+    pub closed_at: Option<u64>,
+}
+
+/// Admin-managed validation limits
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Config {
+    pub min_amount: i128,               // Smallest bounty or payout in stroops
+    pub max_amount: i128,               // Largest bounty in stroops
+    pub task_id_min_length: u32,        // Shortest accepted task ID
+    pub task_id_max_length: u32,        // Longest accepted task ID
+    pub dispute_reason_min_length: u32, // Shortest accepted dispute reason
+    pub dispute_reason_max_length: u32, // Longest accepted dispute reason
+    pub issue_url_max_length: u32,      // Longest accepted issue URL
+}
+
 /// Storage keys for contract data organization
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    TaskEscrow(String), // task_id -> TaskEscrow
-    Admin,              // Admin address
-    UsdcToken,          // USDC token contract address
-    Paused,             // Boolean flag for emergency pause
-    Dispute(String),    // task_id -> DisputeInfo
+    TaskEscrow(String),                      // task_id -> TaskEscrow
+    Admin,                                   // Admin address
+    UsdcToken,                               // USDC token contract address
+    Paused,                                  // Boolean flag for emergency pause
+    Dispute(String),                         // task_id -> DisputeInfo
+    DisputeTimeout,                          // Maximum dispute duration in seconds
+    DisputeTimeoutPolicy,                    // Default outcome for stale disputes
+    DisputeCount(String),                    // task_id -> number of disputes ever opened
+    DisputeRecord(String, u32),              // (task_id, index) -> DisputeRecord
+    ArbitrationFee,                          // Arbitration fee in basis points
+    ArbiterBalance(Address),                 // arbiter -> claimable fees
+    Config,                                  // Validation limits
+    IssueTask(String),                       // canonical issue URL -> task_id of its newest escrow
+    RepoTaskCount(String, String),           // (owner, repo) -> number of escrows ever created
+    RepoTask(String, String, u32),           // (owner, repo, index) -> task_id
+    TagTaskCount(Symbol),                    // tag -> number of escrows ever tagged with it
+    TagTask(Symbol, u32),                    // (tag, index) -> task_id
+    TaskTagIndexed(String, Symbol),          // (task_id, tag) -> whether in the tag index
+    TaskRepoIndexed(String, String, String), // (task_id, owner, repo) -> whether in the repo index
+    PendingTaskOwner(String),                // task_id -> creator nominated to take over the task
+    Deployer,                                // Account allowed to initialize, set on deployment
 }
 
 /// Dispute resolution options for admin
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum DisputeResolution {
-    PayContributor,       // Full payment to contributor
-    RefundCreator,        // Full refund to creator
-    PartialPayment(i128), // Partial payment to contributor, rest to creator
+    PayContributor,                  // Full payment to contributor
+    RefundCreator,                   // Full refund to creator
+    PartialPayment(i128),            // Partial payment to contributor, rest to creator
+    PercentageSplit(u32),            // Basis points to contributor, rest to creator
+    SplitWithArbiterFee(u32, i128),  // Arbiter fee, then basis points of the rest to contributor
+    MultiPayout(Vec<DisputePayout>), // Fixed payouts to several contributors, rest to creator
+}
+
+/// Single payout entry for multi-contributor dispute resolutions
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputePayout {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// Default outcome applied when a dispute exceeds the configured timeout
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DisputeTimeoutPolicy {
+    PayContributor, // Full payment to contributor
+    RefundCreator,  // Full refund to creator
+    SplitEvenly,    // Half to contributor, rest to creator
 }
 
 // Event structures for off-chain integration
@@ -63,8 +240,62 @@ pub enum DisputeResolution {
 #[contractevent]
 pub struct EscrowCreatedEvent {
     pub task_id: String,
+    pub issue_url: String,
+    pub labels_hash: Option<BytesN<32>>,
     pub creator: Address,
     pub bounty_amount: i128,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the creator nominates a new owner for a task
+#[contractevent]
+pub struct TaskOwnershipProposedEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub pending_creator: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the nominated owner accepts a task
+#[contractevent]
+pub struct TaskOwnershipTransferredEvent {
+    pub task_id: String,
+    pub old_creator: Address,
+    pub new_creator: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the creator withdraws the nomination of a new owner
+#[contractevent]
+pub struct TaskOwnershipCancelledEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub pending_creator: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the creator points a task at a different issue or
+/// changes its labels hash
+#[contractevent]
+pub struct TaskMetadataUpdatedEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub old_issue_url: String,
+    pub new_issue_url: String,
+    pub old_labels_hash: Option<BytesN<32>>,
+    pub new_labels_hash: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the creator changes the tags or difficulty of a task
+#[contractevent]
+pub struct TaskTagsUpdatedEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
     pub timestamp: u64,
 }
 
@@ -102,6 +333,14 @@ pub struct DisputeInitiatedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a dispute is withdrawn by the disputing party
+#[contractevent]
+pub struct DisputeWithdrawnEvent {
+    pub task_id: String,
+    pub disputing_party: Address,
+    pub timestamp: u64,
+}
+
 /// Event emitted when a dispute is resolved
 #[contractevent]
 pub struct DisputeResolvedEvent {
@@ -111,6 +350,23 @@ pub struct DisputeResolvedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when an arbitration fee is credited to an arbiter
+#[contractevent]
+pub struct ArbiterFeeCreditedEvent {
+    pub task_id: String,
+    pub arbiter: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Event emitted when an arbiter claims accumulated fees
+#[contractevent]
+pub struct ArbiterFeesClaimedEvent {
+    pub arbiter: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
 /// Event emitted when a refund is processed
 #[contractevent]
 pub struct RefundProcessedEvent {
@@ -140,6 +396,65 @@ pub struct BountyDecreasedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when the contract is initialized
+#[contractevent]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub usdc_token: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when admin privileges are transferred
+#[contractevent]
+pub struct AdminChangedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the emergency pause state changes
+#[contractevent]
+pub struct PauseStateChangedEvent {
+    pub paused: bool,
+    pub actor: Address,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the USDC token contract address is updated
+#[contractevent]
+pub struct TokenUpdatedEvent {
+    pub old_token: Address,
+    pub new_token: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the dispute timeout or its default policy changes
+#[contractevent]
+pub struct DisputeTimeoutUpdatedEvent {
+    pub timeout: u64,
+    pub policy: DisputeTimeoutPolicy,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the arbitration fee changes
+#[contractevent]
+pub struct ArbitrationFeeUpdatedEvent {
+    pub fee_basis_points: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when the validation limits change
+#[contractevent]
+pub struct ConfigUpdatedEvent {
+    pub config: Config,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
 /// Event emitted when the contract is upgraded
 #[contractevent]
 pub struct ContractUpgradedEvent {
@@ -0,0 +1,565 @@
+//! Declarative scenario harness for contract workflows.
+//!
+//! A [`Scenario`] names its actors and their starting USDC, then lists steps
+//! with the error or events each one is expected to produce. Running it
+//! deploys a fresh contract, executes the steps in order with ledger time
+//! control and returns an [`Outcome`] that can be queried or compared with a
+//! checked-in state snapshot.
+//!
+//! Snapshots live in `tests/fixtures/scenarios`; regenerate them with
+//! `UPDATE_SCENARIO_SNAPSHOTS=1 cargo test`.
+
+#![allow(dead_code)]
+
+use devasign_task_escrow::{
+    DisputePayout, DisputeResolution, DisputeTimeoutPolicy, Error, TaskEscrow, TaskEscrowContract,
+    TaskEscrowContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, EnvTestConfig, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, InvokeError, String, Symbol, TryIntoVal,
+};
+use std::collections::BTreeMap;
+
+/// Ledger timestamp at the start of every scenario
+pub const SCENARIO_START_TIME: u64 = 1_700_000_000;
+
+const SNAPSHOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/scenarios");
+
+/// Name of the contract admin, who is also the arbiter
+pub const ADMIN: &str = "admin";
+
+/// A single contract call or ledger change, with actors and tasks referred to by name
+#[derive(Clone, Debug)]
+pub enum Action {
+    CreateEscrow {
+        creator: &'static str,
+        task: &'static str,
+        amount: i128,
+    },
+    IncreaseBounty {
+        creator: &'static str,
+        task: &'static str,
+        amount: i128,
+    },
+    DecreaseBounty {
+        creator: &'static str,
+        task: &'static str,
+        amount: i128,
+    },
+    AssignContributor {
+        task: &'static str,
+        contributor: &'static str,
+    },
+    ApproveCompletion {
+        task: &'static str,
+    },
+    DisputeTask {
+        party: &'static str,
+        task: &'static str,
+    },
+    WithdrawDispute {
+        party: &'static str,
+        task: &'static str,
+    },
+    ResolveDispute {
+        task: &'static str,
+        resolution: DisputeResolution,
+    },
+    ResolveMultiPayout {
+        task: &'static str,
+        payouts: Vec<(&'static str, i128)>,
+    },
+    ResolveStaleDispute {
+        task: &'static str,
+    },
+    Refund {
+        task: &'static str,
+    },
+    ClaimArbiterFees {
+        arbiter: &'static str,
+    },
+    SetArbitrationFee(u32),
+    SetDisputeTimeout(u64, DisputeTimeoutPolicy),
+    SetPaused(bool),
+    AdvanceTime(u64),
+}
+
+pub fn create(creator: &'static str, task: &'static str, amount: i128) -> Action {
+    Action::CreateEscrow {
+        creator,
+        task,
+        amount,
+    }
+}
+
+pub fn increase(creator: &'static str, task: &'static str, amount: i128) -> Action {
+    Action::IncreaseBounty {
+        creator,
+        task,
+        amount,
+    }
+}
+
+pub fn decrease(creator: &'static str, task: &'static str, amount: i128) -> Action {
+    Action::DecreaseBounty {
+        creator,
+        task,
+        amount,
+    }
+}
+
+pub fn assign(task: &'static str, contributor: &'static str) -> Action {
+    Action::AssignContributor { task, contributor }
+}
+
+pub fn approve(task: &'static str) -> Action {
+    Action::ApproveCompletion { task }
+}
+
+pub fn dispute(party: &'static str, task: &'static str) -> Action {
+    Action::DisputeTask { party, task }
+}
+
+pub fn withdraw(party: &'static str, task: &'static str) -> Action {
+    Action::WithdrawDispute { party, task }
+}
+
+pub fn resolve(task: &'static str, resolution: DisputeResolution) -> Action {
+    Action::ResolveDispute { task, resolution }
+}
+
+pub fn resolve_multi(task: &'static str, payouts: &[(&'static str, i128)]) -> Action {
+    Action::ResolveMultiPayout {
+        task,
+        payouts: payouts.to_vec(),
+    }
+}
+
+pub fn resolve_stale(task: &'static str) -> Action {
+    Action::ResolveStaleDispute { task }
+}
+
+pub fn refund(task: &'static str) -> Action {
+    Action::Refund { task }
+}
+
+pub fn claim_fees(arbiter: &'static str) -> Action {
+    Action::ClaimArbiterFees { arbiter }
+}
+
+pub fn set_fee(fee_basis_points: u32) -> Action {
+    Action::SetArbitrationFee(fee_basis_points)
+}
+
+pub fn set_timeout(timeout: u64, policy: DisputeTimeoutPolicy) -> Action {
+    Action::SetDisputeTimeout(timeout, policy)
+}
+
+pub fn pause() -> Action {
+    Action::SetPaused(true)
+}
+
+pub fn unpause() -> Action {
+    Action::SetPaused(false)
+}
+
+pub fn advance(seconds: u64) -> Action {
+    Action::AdvanceTime(seconds)
+}
+
+#[derive(Clone, Debug)]
+struct Step {
+    action: Action,
+    expected: Result<(), Error>,
+    events: Option<Vec<&'static str>>,
+}
+
+/// Declarative description of a workflow and its expected results
+#[derive(Clone, Debug)]
+pub struct Scenario {
+    name: std::string::String,
+    actors: Vec<(&'static str, i128)>,
+    steps: Vec<Step>,
+}
+
+impl Scenario {
+    pub fn new(name: impl Into<std::string::String>) -> Self {
+        Scenario {
+            name: name.into(),
+            actors: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Declare an actor funded with `balance` USDC; undeclared actors start empty
+    pub fn actor(mut self, name: &'static str, balance: i128) -> Self {
+        self.actors.push((name, balance));
+        self
+    }
+
+    /// Add a step that must succeed
+    pub fn step(mut self, action: Action) -> Self {
+        self.steps.push(Step {
+            action,
+            expected: Ok(()),
+            events: None,
+        });
+        self
+    }
+
+    /// Add a step that must fail with `error`
+    pub fn fails(mut self, action: Action, error: Error) -> Self {
+        self.steps.push(Step {
+            action,
+            expected: Err(error),
+            events: None,
+        });
+        self
+    }
+
+    /// Expect the previous step to emit exactly these contract events, in order
+    pub fn emits(mut self, events: &[&'static str]) -> Self {
+        let step = self.steps.last_mut().expect("emits follows a step");
+        step.events = Some(events.to_vec());
+        self
+    }
+
+    /// Deploy a fresh contract and execute every step, panicking on the first
+    /// step whose result differs from its expectation
+    pub fn run(&self) -> Outcome {
+        let mut outcome = Outcome::deploy(&self.name);
+        for (name, balance) in &self.actors {
+            let actor = outcome.actor(name);
+            if *balance > 0 {
+                outcome.usdc_token.mint(&actor, balance);
+            }
+        }
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let context = format!(
+                "scenario {} step {} {:?}",
+                self.name,
+                index + 1,
+                step.action
+            );
+            let result = outcome.execute(&step.action);
+            assert_eq!(result, step.expected, "{context}");
+
+            // Only contract calls emit events; advancing time leaves the last call's in place
+            let events = match step.action {
+                Action::AdvanceTime(_) => Vec::new(),
+                _ => outcome.take_events(),
+            };
+            if let Some(expected) = &step.events {
+                assert_eq!(&events, expected, "{context}");
+            }
+            for event in events {
+                outcome.events.push((index + 1, event));
+            }
+        }
+        outcome
+    }
+}
+
+/// Contract deployment after a scenario has run
+pub struct Outcome {
+    name: std::string::String,
+    pub env: Env,
+    pub client: TaskEscrowContractClient<'static>,
+    pub token: TokenClient<'static>,
+    usdc_token: StellarAssetClient<'static>,
+    actors: BTreeMap<&'static str, Address>,
+    tasks: BTreeMap<&'static str, String>,
+    events: Vec<(usize, std::string::String)>,
+}
+
+impl Outcome {
+    fn deploy(name: &str) -> Self {
+        // Scenarios keep their own snapshots, so ledger snapshots are not written
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        env.ledger().set_timestamp(SCENARIO_START_TIME);
+
+        let admin = Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let client = TaskEscrowContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &usdc_address);
+
+        Outcome {
+            name: name.into(),
+            token: TokenClient::new(&env, &usdc_address),
+            usdc_token: StellarAssetClient::new(&env, &usdc_address),
+            env,
+            client,
+            actors: BTreeMap::from([(ADMIN, admin)]),
+            tasks: BTreeMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Address of a named actor, generated on first use
+    pub fn actor(&mut self, name: &'static str) -> Address {
+        let env = &self.env;
+        self.actors
+            .entry(name)
+            .or_insert_with(|| Address::generate(env))
+            .clone()
+    }
+
+    /// Task ID of a named task: `c` followed by the name, padded to 25 characters
+    pub fn task(&mut self, name: &'static str) -> String {
+        assert!(
+            name.len() < 25 && name.chars().all(|c| c.is_ascii_alphanumeric()),
+            "task names must be short and alphanumeric: {name}"
+        );
+        let env = &self.env;
+        self.tasks
+            .entry(name)
+            .or_insert_with(|| {
+                String::from_str(env, &format!("c{:0<24}", name.to_ascii_lowercase()))
+            })
+            .clone()
+    }
+
+    /// USDC balance of a named actor
+    pub fn balance(&self, name: &str) -> i128 {
+        self.actors
+            .get(name)
+            .map(|actor| self.token.balance(actor))
+            .unwrap_or(0)
+    }
+
+    /// USDC held by the contract
+    pub fn contract_balance(&self) -> i128 {
+        self.token.balance(&self.client.address)
+    }
+
+    /// Stored escrow of a named task
+    pub fn escrow(&self, name: &str) -> TaskEscrow {
+        self.client.get_escrow(&self.tasks[name])
+    }
+
+    /// Contract events emitted by each step, as `(step number, event name)`
+    pub fn events(&self) -> Vec<(usize, &str)> {
+        self.events
+            .iter()
+            .map(|(step, event)| (*step, event.as_str()))
+            .collect()
+    }
+
+    /// Deterministic text rendering of balances, escrows and events
+    pub fn snapshot(&self) -> std::string::String {
+        let mut lines = vec![
+            format!("# scenario {}", self.name),
+            format!(
+                "time +{}",
+                self.env.ledger().timestamp() - SCENARIO_START_TIME
+            ),
+            format!("contract balance {}", self.contract_balance()),
+        ];
+        for name in self.actors.keys() {
+            lines.push(format!(
+                "actor {name} balance {} fees {}",
+                self.balance(name),
+                self.client.get_arbiter_balance(&self.actors[name])
+            ));
+        }
+        for name in self.tasks.keys() {
+            let Ok(Ok(escrow)) = self.client.try_get_escrow(&self.tasks[name]) else {
+                lines.push(format!("task {name} missing"));
+                continue;
+            };
+            let contributor = match &escrow.contributor {
+                Some(contributor) => self.actor_name(contributor),
+                None => "-",
+            };
+            lines.push(format!(
+                "task {name} {:?} bounty {} creator {} contributor {contributor} disputes {}",
+                escrow.status,
+                escrow.bounty_amount,
+                self.actor_name(&escrow.creator),
+                self.client.get_dispute_history(&self.tasks[name]).len(),
+            ));
+        }
+        for (step, event) in &self.events {
+            lines.push(format!("step {step} {event}"));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Compare the snapshot with `tests/fixtures/scenarios/<name>.txt`
+    pub fn assert_snapshot(&self) {
+        let path = format!("{SNAPSHOT_DIR}/{}.txt", self.name);
+        let snapshot = self.snapshot();
+        if std::env::var_os("UPDATE_SCENARIO_SNAPSHOTS").is_some() {
+            std::fs::create_dir_all(SNAPSHOT_DIR).unwrap();
+            std::fs::write(&path, &snapshot).unwrap();
+            return;
+        }
+        let expected =
+            std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing snapshot {path}"));
+        assert_eq!(
+            snapshot,
+            expected.replace("\r\n", "\n"),
+            "snapshot mismatch for scenario {}",
+            self.name
+        );
+    }
+
+    fn actor_name(&self, address: &Address) -> &'static str {
+        self.actors
+            .iter()
+            .find(|(_, actor)| *actor == address)
+            .map(|(name, _)| *name)
+            .unwrap_or("?")
+    }
+
+    fn take_events(&self) -> Vec<std::string::String> {
+        self.env
+            .events()
+            .all()
+            .iter()
+            .filter(|(address, _, _)| *address == self.client.address)
+            .map(|(_, topics, _)| {
+                let name: Symbol = topics.get(0).unwrap().try_into_val(&self.env).unwrap();
+                name.to_string()
+            })
+            .collect()
+    }
+
+    fn execute(&mut self, action: &Action) -> Result<(), Error> {
+        let client = TaskEscrowContractClient::new(&self.env, &self.client.address);
+        match action.clone() {
+            Action::CreateEscrow {
+                creator,
+                task,
+                amount,
+            } => {
+                let issue_url = String::from_str(
+                    &self.env,
+                    &format!(
+                        "https://github.com/owner/repo/issues/{}",
+                        self.tasks.len() + 1
+                    ),
+                );
+                let (creator, task) = (self.actor(creator), self.task(task));
+                result(client.try_create_escrow(&creator, &task, &issue_url, &amount))
+            }
+            Action::IncreaseBounty {
+                creator,
+                task,
+                amount,
+            } => {
+                let (creator, task) = (self.actor(creator), self.task(task));
+                result(client.try_increase_bounty(&creator, &task, &amount))
+            }
+            Action::DecreaseBounty {
+                creator,
+                task,
+                amount,
+            } => {
+                let (creator, task) = (self.actor(creator), self.task(task));
+                result(client.try_decrease_bounty(&creator, &task, &amount))
+            }
+            Action::AssignContributor { task, contributor } => {
+                let (task, contributor) = (self.task(task), self.actor(contributor));
+                result(client.try_assign_contributor(&task, &contributor))
+            }
+            Action::ApproveCompletion { task } => {
+                result(client.try_approve_completion(&self.task(task)))
+            }
+            Action::DisputeTask { party, task } => {
+                let reason = String::from_str(&self.env, "Deliverables do not match agreed scope");
+                let (party, task) = (self.actor(party), self.task(task));
+                result(client.try_dispute_task(&party, &task, &reason))
+            }
+            Action::WithdrawDispute { party, task } => {
+                let (party, task) = (self.actor(party), self.task(task));
+                result(client.try_withdraw_dispute(&party, &task))
+            }
+            Action::ResolveDispute { task, resolution } => {
+                result(client.try_resolve_dispute(&self.task(task), &resolution))
+            }
+            Action::ResolveMultiPayout { task, payouts } => {
+                let mut list = soroban_sdk::Vec::new(&self.env);
+                for (recipient, amount) in payouts {
+                    list.push_back(DisputePayout {
+                        recipient: self.actor(recipient),
+                        amount,
+                    });
+                }
+                let resolution = DisputeResolution::MultiPayout(list);
+                result(client.try_resolve_dispute(&self.task(task), &resolution))
+            }
+            Action::ResolveStaleDispute { task } => {
+                result(client.try_resolve_stale_dispute(&self.task(task)))
+            }
+            Action::Refund { task } => result(client.try_refund(&self.task(task))),
+            Action::ClaimArbiterFees { arbiter } => {
+                result(client.try_claim_arbiter_fees(&self.actor(arbiter)))
+            }
+            Action::SetArbitrationFee(fee_basis_points) => {
+                result(client.try_set_arbitration_fee(&fee_basis_points))
+            }
+            Action::SetDisputeTimeout(timeout, policy) => {
+                result(client.try_set_dispute_timeout(&timeout, &policy))
+            }
+            Action::SetPaused(paused) => {
+                let reason = String::from_str(&self.env, "Scheduled maintenance");
+                result(client.try_set_paused(&paused, &reason))
+            }
+            Action::AdvanceTime(seconds) => {
+                let timestamp = self.env.ledger().timestamp() + seconds;
+                self.env.ledger().set_timestamp(timestamp);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reduce a `try_` client result to the contract error, if any
+fn result<T, C: core::fmt::Debug>(
+    result: Result<Result<T, C>, Result<Error, InvokeError>>,
+) -> Result<(), Error> {
+    match result {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(err)) => panic!("return value failed to convert: {err:?}"),
+        Err(Ok(err)) => Err(err),
+        Err(Err(err)) => panic!("invocation aborted: {err:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scenario_harness_works() {
+        let outcome = Scenario::new("harness_self_check")
+            .actor("alice", 1000_0000000)
+            .step(create("alice", "task1", 1000_0000000))
+            .emits(&["escrow_created_event"])
+            .fails(
+                create("alice", "task1", 1000_0000000),
+                Error::TaskAlreadyExists,
+            )
+            .step(advance(60))
+            .run();
+
+        assert_eq!(outcome.balance("alice"), 0);
+        assert_eq!(outcome.contract_balance(), 1000_0000000);
+        assert_eq!(outcome.events(), [(1, "escrow_created_event")]);
+        assert_eq!(outcome.env.ledger().timestamp(), SCENARIO_START_TIME + 60);
+        assert_eq!(
+            outcome.escrow("task1").task_id.to_string(),
+            "ctask10000000000000000000"
+        );
+    }
+}
@@ -0,0 +1,536 @@
+//! Property-based tests of escrow accounting.
+//!
+//! Each case funds a few actors, then runs a random sequence of escrow
+//! operations and checks after every step that:
+//! - the contract holds exactly the bounties of non-terminal escrows plus
+//!   the arbitration fees credited but not yet claimed,
+//! - no USDC is created or destroyed across actors and the contract,
+//! - every escrow only moves along allowed status transitions, and
+//! - a rejected call changes neither storage nor balances.
+//!
+//! A failing case is shrunk to a minimal sequence of operations before it is
+//! reported. Set `ESCROW_PROPERTY_SEED` to replay a single case and
+//! `ESCROW_PROPERTY_CASES` to change how many cases run.
+
+use devasign_task_escrow::{
+    DisputePayout, DisputeResolution, TaskEscrow, TaskEscrowContract, TaskEscrowContractClient,
+    TaskStatus,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use soroban_sdk::{
+    testutils::{Address as _, EnvTestConfig, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+mod test_config;
+
+use test_config::TestConfig;
+
+const DEFAULT_CASES: u64 = 64;
+const STEPS_PER_CASE: usize = 40;
+const ACTORS: usize = 4;
+const TASKS: usize = 5;
+const STARTING_BALANCE: i128 = TestConfig::HUGE_AMOUNT;
+
+/// Operation with actors and tasks referred to by index
+#[derive(Clone, Debug)]
+enum Op {
+    Create {
+        creator: usize,
+        task: usize,
+        amount: i128,
+    },
+    Increase {
+        caller: usize,
+        task: usize,
+        amount: i128,
+    },
+    Decrease {
+        caller: usize,
+        task: usize,
+        amount: i128,
+    },
+    Assign {
+        task: usize,
+        contributor: usize,
+    },
+    Approve {
+        task: usize,
+    },
+    Dispute {
+        party: usize,
+        task: usize,
+    },
+    Resolve {
+        task: usize,
+        resolution: Resolution,
+    },
+    Refund {
+        task: usize,
+    },
+}
+
+/// `DisputeResolution` with payout recipients referred to by actor index
+#[derive(Clone, Debug)]
+enum Resolution {
+    PayContributor,
+    RefundCreator,
+    PartialPayment(i128),
+    PercentageSplit(u32),
+    SplitWithArbiterFee(u32, i128),
+    MultiPayout(Vec<(usize, i128)>),
+}
+
+/// Amounts around the validation and balance boundaries, weighted toward valid bounties
+fn amount(rng: &mut StdRng) -> i128 {
+    match rng.gen_range(0..10) {
+        0 => *[
+            0,
+            -1,
+            TestConfig::MIN_VALID_AMOUNT - 1,
+            STARTING_BALANCE * 2,
+        ]
+        .get(rng.gen_range(0..4))
+        .unwrap(),
+        1 => TestConfig::MIN_VALID_AMOUNT,
+        _ => rng.gen_range(TestConfig::MIN_VALID_AMOUNT..=TestConfig::LARGE_AMOUNT),
+    }
+}
+
+fn resolution(rng: &mut StdRng) -> Resolution {
+    match rng.gen_range(0..6) {
+        0 => Resolution::PayContributor,
+        1 => Resolution::RefundCreator,
+        2 => Resolution::PartialPayment(amount(rng)),
+        3 => Resolution::PercentageSplit(rng.gen_range(0..=10_500)),
+        4 => Resolution::SplitWithArbiterFee(rng.gen_range(0..=10_500), amount(rng) / 10),
+        _ => Resolution::MultiPayout(
+            (0..rng.gen_range(0..4))
+                .map(|_| (rng.gen_range(0..ACTORS), amount(rng) / 4))
+                .collect(),
+        ),
+    }
+}
+
+fn op(rng: &mut StdRng) -> Op {
+    let task = rng.gen_range(0..TASKS);
+    let actor = rng.gen_range(0..ACTORS);
+    match rng.gen_range(0..10) {
+        0 | 1 => Op::Create {
+            creator: actor,
+            task,
+            amount: amount(rng),
+        },
+        2 => Op::Increase {
+            caller: actor,
+            task,
+            amount: amount(rng),
+        },
+        3 => Op::Decrease {
+            caller: actor,
+            task,
+            amount: amount(rng),
+        },
+        4 => Op::Assign {
+            task,
+            contributor: actor,
+        },
+        5 => Op::Approve { task },
+        6 => Op::Dispute { party: actor, task },
+        7 | 8 => Op::Resolve {
+            task,
+            resolution: resolution(rng),
+        },
+        _ => Op::Refund { task },
+    }
+}
+
+/// Generate the arbitration fee and operations of a case
+fn generate(seed: u64) -> (u32, Vec<Op>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let fee_basis_points = *[0, 100, 1_000].get(rng.gen_range(0..3)).unwrap();
+    let ops = (0..STEPS_PER_CASE).map(|_| op(&mut rng)).collect();
+    (fee_basis_points, ops)
+}
+
+/// Description of a broken invariant
+type Violation = std::string::String;
+
+/// State observed before and after each operation
+#[derive(Clone, Debug, PartialEq)]
+struct Observation {
+    escrows: Vec<Option<TaskEscrow>>,
+    balances: Vec<i128>,
+    contract_balance: i128,
+    arbiter_fees: i128,
+}
+
+struct Harness {
+    env: Env,
+    admin: Address,
+    actors: Vec<Address>,
+    tasks: Vec<String>,
+    client: TaskEscrowContractClient<'static>,
+    token: TokenClient<'static>,
+}
+
+impl Harness {
+    fn new(fee_basis_points: u32) -> Self {
+        // Thousands of environments are created, so ledger snapshots are not written
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_700_000_000);
+
+        let admin = Address::generate(&env);
+        let usdc_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let usdc_token = StellarAssetClient::new(&env, &usdc_address);
+        let contract_id = env.register(TaskEscrowContract, (&admin,));
+        let client = TaskEscrowContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &usdc_address);
+        client.set_arbitration_fee(&fee_basis_points);
+
+        let actors: Vec<Address> = (0..ACTORS).map(|_| Address::generate(&env)).collect();
+        for actor in &actors {
+            usdc_token.mint(actor, &STARTING_BALANCE);
+        }
+        let tasks = (0..TASKS)
+            .map(|index| String::from_str(&env, &format!("cproperty{index:016}")))
+            .collect();
+
+        Harness {
+            token: TokenClient::new(&env, &usdc_address),
+            env,
+            admin,
+            actors,
+            tasks,
+            client,
+        }
+    }
+
+    fn observe(&self) -> Observation {
+        Observation {
+            escrows: self
+                .tasks
+                .iter()
+                .map(|task| self.client.try_get_escrow(task).ok().map(Result::unwrap))
+                .collect(),
+            balances: self
+                .actors
+                .iter()
+                .chain([&self.admin])
+                .map(|actor| self.token.balance(actor))
+                .collect(),
+            contract_balance: self.token.balance(&self.client.address),
+            arbiter_fees: self.client.get_arbiter_balance(&self.admin),
+        }
+    }
+
+    /// Apply an operation, returning whether the contract accepted it
+    fn apply(&self, op: &Op) -> bool {
+        let client = &self.client;
+        match op {
+            Op::Create {
+                creator,
+                task,
+                amount,
+            } => client
+                .try_create_escrow(
+                    &self.actors[*creator],
+                    &self.tasks[*task],
+                    &String::from_str(
+                        &self.env,
+                        &format!("https://github.com/owner/repo/issues/{}", task + 1),
+                    ),
+                    amount,
+                )
+                .is_ok(),
+            Op::Increase {
+                caller,
+                task,
+                amount,
+            } => client
+                .try_increase_bounty(&self.actors[*caller], &self.tasks[*task], amount)
+                .is_ok(),
+            Op::Decrease {
+                caller,
+                task,
+                amount,
+            } => client
+                .try_decrease_bounty(&self.actors[*caller], &self.tasks[*task], amount)
+                .is_ok(),
+            Op::Assign { task, contributor } => client
+                .try_assign_contributor(&self.tasks[*task], &self.actors[*contributor])
+                .is_ok(),
+            Op::Approve { task } => client.try_approve_completion(&self.tasks[*task]).is_ok(),
+            Op::Dispute { party, task } => client
+                .try_dispute_task(
+                    &self.actors[*party],
+                    &self.tasks[*task],
+                    &String::from_str(&self.env, TestConfig::QUALITY_DISPUTE),
+                )
+                .is_ok(),
+            Op::Resolve { task, resolution } => client
+                .try_resolve_dispute(&self.tasks[*task], &self.resolution(resolution))
+                .is_ok(),
+            Op::Refund { task } => client.try_refund(&self.tasks[*task]).is_ok(),
+        }
+    }
+
+    fn resolution(&self, resolution: &Resolution) -> DisputeResolution {
+        match resolution {
+            Resolution::PayContributor => DisputeResolution::PayContributor,
+            Resolution::RefundCreator => DisputeResolution::RefundCreator,
+            Resolution::PartialPayment(amount) => DisputeResolution::PartialPayment(*amount),
+            Resolution::PercentageSplit(bps) => DisputeResolution::PercentageSplit(*bps),
+            Resolution::SplitWithArbiterFee(bps, fee) => {
+                DisputeResolution::SplitWithArbiterFee(*bps, *fee)
+            }
+            Resolution::MultiPayout(payouts) => {
+                let mut list = soroban_sdk::Vec::new(&self.env);
+                for (recipient, amount) in payouts {
+                    list.push_back(DisputePayout {
+                        recipient: self.actors[*recipient].clone(),
+                        amount: *amount,
+                    });
+                }
+                DisputeResolution::MultiPayout(list)
+            }
+        }
+    }
+}
+
+fn is_terminal(status: &TaskStatus) -> bool {
+    matches!(status, TaskStatus::Completed | TaskStatus::Cancelled)
+}
+
+fn allowed_transition(from: &TaskStatus, to: &TaskStatus) -> bool {
+    use TaskStatus::*;
+    from == to
+        || matches!(
+            (from, to),
+            (Open, InProgress)
+                | (Open, Cancelled)
+                | (InProgress, Completed)
+                | (InProgress, Disputed)
+                | (Disputed, InProgress)
+                | (Disputed, Completed)
+        )
+}
+
+/// Check every invariant between two observations around an operation
+fn check_step(before: &Observation, after: &Observation, accepted: bool) -> Result<(), Violation> {
+    // Conservation: the contract holds open bounties plus unclaimed fees
+    let open_bounties: i128 = after
+        .escrows
+        .iter()
+        .flatten()
+        .filter(|escrow| !is_terminal(&escrow.status))
+        .map(|escrow| escrow.bounty_amount)
+        .sum();
+    if after.contract_balance != open_bounties + after.arbiter_fees {
+        return Err(format!(
+            "contract holds {} but open bounties are {open_bounties} and unclaimed fees {}",
+            after.contract_balance, after.arbiter_fees
+        ));
+    }
+
+    // Conservation: no USDC is minted or burned by the contract
+    let total = |observation: &Observation| {
+        observation.balances.iter().sum::<i128>() + observation.contract_balance
+    };
+    if total(before) != total(after) {
+        return Err(format!(
+            "total supply changed from {} to {}",
+            total(before),
+            total(after)
+        ));
+    }
+
+    if !accepted && before != after {
+        return Err("rejected call changed state".into());
+    }
+
+    for (index, (old, new)) in before.escrows.iter().zip(&after.escrows).enumerate() {
+        match (old, new) {
+            (Some(_), None) => return Err(format!("escrow {index} disappeared")),
+            (None, Some(escrow)) if escrow.status != TaskStatus::Open => {
+                return Err(format!("escrow {index} created as {:?}", escrow.status));
+            }
+            (Some(old), Some(new)) => {
+                if !allowed_transition(&old.status, &new.status) {
+                    return Err(format!(
+                        "escrow {index} moved from {:?} to {:?}",
+                        old.status, new.status
+                    ));
+                }
+                if is_terminal(&old.status) && old != new {
+                    return Err(format!("terminal escrow {index} changed"));
+                }
+                if old.status != TaskStatus::Open && old.bounty_amount != new.bounty_amount {
+                    return Err(format!("bounty of escrow {index} changed after assignment"));
+                }
+                if old.contributor.is_some() && old.contributor != new.contributor {
+                    return Err(format!("contributor of escrow {index} changed"));
+                }
+            }
+            _ => {}
+        }
+        if let Some(escrow) = new {
+            if escrow.bounty_amount <= 0 {
+                return Err(format!("escrow {index} has a non-positive bounty"));
+            }
+            let needs_contributor =
+                matches!(escrow.status, TaskStatus::InProgress | TaskStatus::Disputed);
+            if needs_contributor && escrow.contributor.is_none() {
+                return Err(format!(
+                    "escrow {index} is {:?} without contributor",
+                    escrow.status
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run a case and return the index and description of the first violation
+fn run_case(fee_basis_points: u32, ops: &[Op]) -> Result<usize, (usize, Violation)> {
+    let harness = Harness::new(fee_basis_points);
+    let mut before = harness.observe();
+    let mut accepted_count = 0;
+    for (index, op) in ops.iter().enumerate() {
+        let accepted = harness.apply(op);
+        let after = harness.observe();
+        check_step(&before, &after, accepted).map_err(|err| (index, err))?;
+        accepted_count += accepted as usize;
+        before = after;
+    }
+    Ok(accepted_count)
+}
+
+/// Drop operations one at a time while the case keeps failing
+fn shrink(fee_basis_points: u32, mut ops: Vec<Op>) -> Vec<Op> {
+    let mut index = 0;
+    while index < ops.len() {
+        let mut candidate = ops.clone();
+        candidate.remove(index);
+        if run_case(fee_basis_points, &candidate).is_err() {
+            ops = candidate;
+        } else {
+            index += 1;
+        }
+    }
+    ops
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok().map(|value| value.parse().unwrap())
+}
+
+#[test]
+fn test_accounting_invariants_hold_for_random_sequences() {
+    let seeds: Vec<u64> = match env_u64("ESCROW_PROPERTY_SEED") {
+        Some(seed) => vec![seed],
+        None => (0..env_u64("ESCROW_PROPERTY_CASES").unwrap_or(DEFAULT_CASES)).collect(),
+    };
+
+    let mut accepted = 0;
+    for seed in seeds {
+        let (fee_basis_points, ops) = generate(seed);
+        match run_case(fee_basis_points, &ops) {
+            Ok(count) => accepted += count,
+            Err((index, err)) => {
+                let minimal = shrink(fee_basis_points, ops[..=index].to_vec());
+                panic!(
+                    "seed {seed} (fee {fee_basis_points} bps) violated an invariant at step {index}: \
+                     {err}\nminimal sequence: {minimal:#?}"
+                );
+            }
+        }
+    }
+
+    // Guard against a generator that only produces rejected calls
+    assert!(accepted > 0);
+}
+
+#[test]
+fn test_invariant_checks_detect_violations() {
+    let harness = Harness::new(0);
+    let before = harness.observe();
+    assert!(harness.apply(&Op::Create {
+        creator: 0,
+        task: 0,
+        amount: TestConfig::MEDIUM_AMOUNT,
+    }));
+    let after = harness.observe();
+    assert_eq!(check_step(&before, &after, true), Ok(()));
+
+    // Leaked funds break conservation
+    let mut leaked = after.clone();
+    leaked.contract_balance -= 1;
+    assert!(check_step(&before, &leaked, true).is_err());
+
+    // A rejected call must leave state untouched
+    assert!(check_step(&before, &after, false).is_err());
+
+    // Escrows cannot skip from Open to Completed
+    let mut skipped = after.clone();
+    skipped.escrows[0].as_mut().unwrap().status = TaskStatus::Completed;
+    skipped.contract_balance -= TestConfig::MEDIUM_AMOUNT;
+    skipped.balances[1] += TestConfig::MEDIUM_AMOUNT;
+    assert_eq!(
+        check_step(&after, &skipped, true),
+        Err("escrow 0 moved from Open to Completed".into())
+    );
+}
+
+#[test]
+fn test_fixed_sequence_accepts_every_operation() {
+    let ops = vec![
+        Op::Create {
+            creator: 0,
+            task: 0,
+            amount: TestConfig::MEDIUM_AMOUNT,
+        },
+        Op::Increase {
+            caller: 0,
+            task: 0,
+            amount: TestConfig::SMALL_AMOUNT,
+        },
+        Op::Decrease {
+            caller: 0,
+            task: 0,
+            amount: TestConfig::SMALL_AMOUNT,
+        },
+        Op::Assign {
+            task: 0,
+            contributor: 1,
+        },
+        Op::Dispute { party: 1, task: 0 },
+        Op::Resolve {
+            task: 0,
+            resolution: Resolution::MultiPayout(vec![(1, TestConfig::SMALL_AMOUNT), (2, 1)]),
+        },
+        Op::Create {
+            creator: 2,
+            task: 1,
+            amount: TestConfig::SMALL_AMOUNT,
+        },
+        Op::Refund { task: 1 },
+        Op::Create {
+            creator: 3,
+            task: 2,
+            amount: TestConfig::SMALL_AMOUNT,
+        },
+        Op::Assign {
+            task: 2,
+            contributor: 0,
+        },
+        Op::Approve { task: 2 },
+    ];
+    assert_eq!(run_case(1_000, &ops), Ok(ops.len()));
+}
@@ -0,0 +1,218 @@
+use devasign_task_escrow::{DisputePayout, DisputeResolution, Error, TaskStatus};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+/// All-zero placeholder account the contract must never accept as a party
+fn null_address(env: &Env) -> Address {
+    Address::from_str(
+        env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    )
+}
+
+fn create_task(
+    env: &Env,
+    client: &devasign_task_escrow::TaskEscrowContractClient,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    creator: &Address,
+    prefix: &str,
+) -> String {
+    let task_id = TestValidation::generate_task_id(env, prefix, 1);
+    usdc_token.mint(creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    task_id
+}
+
+#[test]
+fn test_initialize_rejects_invalid_addresses() {
+    let (env, _admin, usdc_address, _usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Null admin
+    let result = client.try_initialize(&null_address(&env), &usdc_address);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    // Contract as its own admin
+    let result = client.try_initialize(&contract_id, &usdc_address);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    // Null token
+    let result = client.try_initialize(&Address::generate(&env), &null_address(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    // Nothing was stored by the rejected calls
+    assert_eq!(
+        client.try_get_admin().unwrap_err().unwrap(),
+        Error::ContractNotInitialized
+    );
+}
+
+#[test]
+fn test_set_admin_rejects_invalid_addresses() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let result = client.try_set_admin(&null_address(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    let result = client.try_set_admin(&contract_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_update_usdc_token_rejects_null_address() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let result = client.try_update_usdc_token(&null_address(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+    assert_eq!(client.get_usdc_token(), usdc_address);
+}
+
+#[test]
+fn test_create_escrow_rejects_invalid_creator() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let task_id = TestValidation::generate_task_id(&env, "null_creator", 1);
+    let result = client.try_create_escrow(
+        &null_address(&env),
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    // The contract cannot fund an escrow from its own holdings
+    let result = client.try_create_escrow(
+        &contract_id,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+    assert_eq!(
+        client.try_get_escrow(&task_id).unwrap_err().unwrap(),
+        Error::TaskNotFound
+    );
+}
+
+#[test]
+fn test_assign_contributor_rejects_invalid_contributor() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = create_task(&env, &client, &usdc_token, &creator, "assign_invalid");
+
+    let result = client.try_assign_contributor(&task_id, &null_address(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    let result = client.try_assign_contributor(&task_id, &contract_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    // Creators cannot pay themselves through their own task
+    let result = client.try_assign_contributor(&task_id, &creator);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.contributor, None);
+    assert_eq!(escrow.status, TaskStatus::Open);
+
+    // A different contributor is still accepted
+    client.assign_contributor(&task_id, &Address::generate(&env));
+    assert_eq!(client.get_escrow(&task_id).status, TaskStatus::InProgress);
+}
+
+#[test]
+fn test_dispute_and_withdraw_reject_null_party() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = create_task(&env, &client, &usdc_token, &creator, "dispute_null");
+    client.assign_contributor(&task_id, &Address::generate(&env));
+
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    let result = client.try_dispute_task(&null_address(&env), &task_id, &reason);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    client.dispute_task(&creator, &task_id, &reason);
+    let result = client.try_withdraw_dispute(&null_address(&env), &task_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+    assert_eq!(client.get_escrow(&task_id).status, TaskStatus::Disputed);
+}
+
+#[test]
+fn test_multi_payout_rejects_invalid_recipients() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = create_task(&env, &client, &usdc_token, &creator, "payout_invalid");
+    let contributor = Address::generate(&env);
+    client.assign_contributor(&task_id, &contributor);
+    client.dispute_task(
+        &contributor,
+        &task_id,
+        &TestValidation::generate_dispute_reason(&env, "quality"),
+    );
+
+    for recipient in [null_address(&env), contract_id.clone()] {
+        let payouts = vec![
+            &env,
+            DisputePayout {
+                recipient: contributor.clone(),
+                amount: TestConfig::SMALL_AMOUNT,
+            },
+            DisputePayout {
+                recipient,
+                amount: TestConfig::SMALL_AMOUNT,
+            },
+        ];
+        let result = client.try_resolve_dispute(&task_id, &DisputeResolution::MultiPayout(payouts));
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+    }
+
+    // Nothing was paid out by the rejected resolutions
+    assert_eq!(client.get_escrow(&task_id).status, TaskStatus::Disputed);
+    assert_eq!(
+        usdc_token_client.balance(&contract_id),
+        TestConfig::MEDIUM_AMOUNT
+    );
+}
+
+#[test]
+fn test_balance_queries_reject_null_address() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let result = client.try_get_usdc_balance(&null_address(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    let result =
+        client.try_has_sufficient_usdc_balance(&null_address(&env), &TestConfig::SMALL_AMOUNT);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+
+    let result = client.try_claim_arbiter_fees(&null_address(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+}
@@ -15,7 +15,7 @@ fn setup_completed_task(
     contributor: &Address,
     task_prefix: &str,
 ) -> (String, i128) {
-    let task_id = TestValidation::generate_task_id(&env, task_prefix, 1);
+    let task_id = TestValidation::generate_task_id(env, task_prefix, 1);
     let bounty_amount = TestConfig::MEDIUM_AMOUNT;
 
     // Fund creator and create escrow
@@ -23,7 +23,7 @@ fn setup_completed_task(
     client.create_escrow(
         creator,
         &task_id,
-        &TestValidation::dummy_issue_url(&env),
+        &TestValidation::dummy_issue_url(env),
         &bounty_amount,
     );
 
@@ -58,8 +58,7 @@ fn test_approve_completion_success() {
     let initial_contract_balance = usdc_token_client.balance(&contract_id);
 
     // Approve completion
-    let result = client.approve_completion(&task_id);
-    assert!(result == ());
+    client.approve_completion(&task_id);
 
     // Verify payment transfer
     let final_contributor_balance = usdc_token_client.balance(&contributor);
@@ -140,8 +139,7 @@ fn test_approve_completion_works_directly() {
     client.assign_contributor(&task_id, &contributor);
 
     // Try to approve. Works now because InProgress is the correct state
-    let result = client.approve_completion(&task_id);
-    assert!(result == ());
+    client.approve_completion(&task_id);
 }
 
 #[test]
@@ -191,14 +189,13 @@ fn test_approve_completion_already_resolved() {
     );
 
     // Approve completion first time
-    let result = client.approve_completion(&task_id);
-    assert!(result == ());
+    client.approve_completion(&task_id);
 
     // Try to approve again
     let result = client.try_approve_completion(&task_id);
-    // Should fail because status is now Completed, and approve requires InProgress
+    // Should fail because the task is now Completed
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
 }
 
 #[test]
@@ -211,7 +208,7 @@ fn test_approve_completion_multiple_amounts() {
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
-    let amounts = vec![
+    let amounts = [
         TestConfig::MIN_VALID_AMOUNT,
         TestConfig::SMALL_AMOUNT,
         TestConfig::MEDIUM_AMOUNT,
@@ -233,8 +230,7 @@ fn test_approve_completion_multiple_amounts() {
 
         let initial_balance = usdc_token_client.balance(&contributor);
 
-        let result = client.approve_completion(&task_id);
-        assert!(result == ());
+        client.approve_completion(&task_id);
 
         let final_balance = usdc_token_client.balance(&contributor);
         assert_eq!(final_balance, initial_balance + amount);
@@ -255,20 +251,20 @@ fn test_approve_completion_invalid_task_id() {
     client.initialize(&admin, &usdc_address);
 
     // Test empty task ID
-    let result = client.try_approve_completion(&String::from_str(&env, &"".to_string()));
+    let result = client.try_approve_completion(&String::from_str(&env, ""));
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), Error::EmptyTaskId);
 
-    // Test too short task ID
-    let result = client.try_approve_completion(&String::from_str(&env, &"ab".to_string()));
+    // Length limits only apply on creation, so a short ID is just unknown
+    let result = client.try_approve_completion(&String::from_str(&env, "ab"));
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 
-    // Test too long task ID
+    // Likewise for a long ID
     let long_id = "a".repeat(101);
     let result = client.try_approve_completion(&String::from_str(&env, &long_id));
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 }
 
 #[test]
@@ -326,18 +322,18 @@ fn test_has_sufficient_usdc_balance() {
 
     // Check insufficient balance
     let result = client.has_sufficient_usdc_balance(&user, &amount);
-    assert!(result == false);
+    assert!(!result);
 
     // Fund user
     usdc_token.mint(&user, &amount);
 
     // Check sufficient balance
     let result = client.has_sufficient_usdc_balance(&user, &amount);
-    assert!(result == true);
+    assert!(result);
 
     // Check more than balance
     let result = client.has_sufficient_usdc_balance(&user, &(amount + 1));
-    assert!(result == false);
+    assert!(!result);
 }
 
 #[test]
@@ -0,0 +1,219 @@
+use devasign_task_escrow::{DisputeResolution, Error, TaskEscrowContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+fn setup_disputed_task(
+    env: &Env,
+    client: &TaskEscrowContractClient,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    creator: &Address,
+    contributor: &Address,
+    task_prefix: &str,
+) -> (String, i128) {
+    let task_id = TestValidation::generate_task_id(env, task_prefix, 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator, create escrow and assign contributor
+    usdc_token.mint(creator, &bounty_amount);
+    client.create_escrow(
+        creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(env),
+        &bounty_amount,
+    );
+    client.assign_contributor(&task_id, contributor);
+
+    // Open dispute
+    let reason = TestValidation::generate_dispute_reason(env, "quality");
+    client.dispute_task(creator, &task_id, &reason);
+
+    (task_id, bounty_amount)
+}
+
+#[test]
+fn test_default_arbitration_fee() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    assert_eq!(client.get_arbitration_fee(), 0);
+    assert_eq!(client.get_arbiter_balance(&admin), 0);
+}
+
+#[test]
+fn test_set_arbitration_fee() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    client.set_arbitration_fee(&250);
+    assert_eq!(client.get_arbitration_fee(), 250);
+
+    // Fees above 10% are rejected
+    let result = client.try_set_arbitration_fee(&1_001);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidBasisPoints);
+}
+
+#[test]
+fn test_set_arbitration_fee_unauthorized() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Clear mocked auths to enforce strict checking
+    env.mock_auths(&[]);
+
+    let result = client.try_set_arbitration_fee(&100);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_resolve_dispute_credits_arbitration_fee() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract with a 2% arbitration fee
+    client.initialize(&admin, &usdc_address);
+    client.set_arbitration_fee(&200);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (task_id, bounty_amount) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "fee_pay",
+    );
+
+    client.resolve_dispute(&task_id, &DisputeResolution::PayContributor);
+
+    // Fee is held by the contract as a claimable balance
+    let fee = bounty_amount * 200 / 10_000;
+    assert_eq!(client.get_arbiter_balance(&admin), fee);
+    assert_eq!(usdc_token_client.balance(&contract_id), fee);
+
+    // Contributor receives the bounty less the fee
+    assert_eq!(usdc_token_client.balance(&contributor), bounty_amount - fee);
+}
+
+#[test]
+fn test_resolve_dispute_fee_applies_to_partial_payment_pool() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract with a 5% arbitration fee
+    client.initialize(&admin, &usdc_address);
+    client.set_arbitration_fee(&500);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (task_id, bounty_amount) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "fee_partial",
+    );
+
+    let partial_amount = 400_0000000;
+    client.resolve_dispute(&task_id, &DisputeResolution::PartialPayment(partial_amount));
+
+    let fee = bounty_amount * 500 / 10_000;
+    assert_eq!(usdc_token_client.balance(&contributor), partial_amount);
+    assert_eq!(
+        usdc_token_client.balance(&creator),
+        bounty_amount - fee - partial_amount
+    );
+    assert_eq!(client.get_arbiter_balance(&admin), fee);
+}
+
+#[test]
+fn test_claim_arbiter_fees() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract with a 1% arbitration fee
+    client.initialize(&admin, &usdc_address);
+    client.set_arbitration_fee(&100);
+
+    // Resolve two disputes to accumulate fees
+    let mut expected_fees = 0;
+    for prefix in ["fee_claim_a", "fee_claim_b"] {
+        let creator = Address::generate(&env);
+        let contributor = Address::generate(&env);
+        let (task_id, bounty_amount) =
+            setup_disputed_task(&env, &client, &usdc_token, &creator, &contributor, prefix);
+        client.resolve_dispute(&task_id, &DisputeResolution::RefundCreator);
+        expected_fees += bounty_amount * 100 / 10_000;
+    }
+    assert_eq!(client.get_arbiter_balance(&admin), expected_fees);
+
+    // Claim all accumulated fees
+    let claimed = client.claim_arbiter_fees(&admin);
+    assert_eq!(claimed, expected_fees);
+    assert_eq!(usdc_token_client.balance(&admin), expected_fees);
+    assert_eq!(client.get_arbiter_balance(&admin), 0);
+    assert_eq!(usdc_token_client.balance(&contract_id), 0);
+
+    // Nothing left to claim
+    let result = client.try_claim_arbiter_fees(&admin);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NoFeesToClaim);
+}
+
+#[test]
+fn test_claim_arbiter_fees_nothing_to_claim() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_claim_arbiter_fees(&stranger);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NoFeesToClaim);
+}
+
+#[test]
+fn test_claim_arbiter_fees_after_admin_change() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract with a 1% arbitration fee
+    client.initialize(&admin, &usdc_address);
+    client.set_arbitration_fee(&100);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (task_id, bounty_amount) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "fee_rotate",
+    );
+    client.resolve_dispute(&task_id, &DisputeResolution::PayContributor);
+
+    // Fees stay with the arbiter that earned them after admin rotation
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+
+    let fee = bounty_amount / 100;
+    assert_eq!(client.claim_arbiter_fees(&admin), fee);
+    assert_eq!(usdc_token_client.balance(&admin), fee);
+    assert_eq!(client.get_arbiter_balance(&new_admin), 0);
+}
@@ -33,8 +33,7 @@ fn test_increase_bounty_success() {
     );
 
     // Increase bounty
-    let result = client.increase_bounty(&creator, &task_id, &increase_amount);
-    assert!(result == ());
+    client.increase_bounty(&creator, &task_id, &increase_amount);
 
     // Verify escrow updated
     let escrow = client.get_escrow(&task_id);
@@ -76,8 +75,7 @@ fn test_decrease_bounty_success() {
     );
 
     // Decrease bounty
-    let result = client.decrease_bounty(&creator, &task_id, &decrease_amount);
-    assert!(result == ());
+    client.decrease_bounty(&creator, &task_id, &decrease_amount);
 
     // Verify escrow updated
     let escrow = client.get_escrow(&task_id);
@@ -295,7 +293,6 @@ fn test_decrease_bounty_invalid_status() {
     assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
 }
 
-
 #[test]
 fn test_increase_bounty_invalid_status() {
     let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
@@ -326,3 +323,32 @@ fn test_increase_bounty_invalid_status() {
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
 }
+
+#[test]
+fn test_bounty_changes_after_refund() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "test", 1);
+    let initial_bounty = TestConfig::MEDIUM_AMOUNT;
+
+    usdc_token.mint(&creator, &(initial_bounty * 2));
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &initial_bounty,
+    );
+
+    // Refund -> Status becomes Cancelled
+    client.refund(&task_id);
+
+    // Cancelled tasks are final
+    let result = client.try_increase_bounty(&creator, &task_id, &1_000_000);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
+    let result = client.try_decrease_bounty(&creator, &task_id, &1_000_000);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
+}
@@ -0,0 +1,344 @@
+use devasign_task_escrow::{
+    Difficulty, DisputePayout, DisputeResolution, DisputeTimeoutPolicy, EscrowOptions,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, String, Vec,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+/// Baseline of the resources metered for every entrypoint by `record_budgets`.
+/// Each line is `<entrypoint> <metric>=<value> ...`.
+/// The contract runs natively in tests, so Wasm instantiation and execution
+/// are not included; the numbers are for comparing changes, not for fees.
+/// Regenerate with `UPDATE_BUDGET_BASELINE=1 cargo test --test test_budget`
+/// after reviewing the regression report.
+const BASELINE_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/budget_baseline.txt"
+);
+
+/// Allowed growth of any metric over its baseline, in percent
+const TOLERANCE_PERCENT: i64 = 5;
+
+const METRICS: [&str; 6] = [
+    "cpu_instructions",
+    "memory_bytes",
+    "read_entries",
+    "write_entries",
+    "write_bytes",
+    "event_bytes",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+struct Budget {
+    entrypoint: std::string::String,
+    values: [i64; 6],
+}
+
+/// Record the resources metered during the last invocation
+fn take_budget(env: &Env, entrypoint: &str, recorded: &mut std::vec::Vec<Budget>) {
+    let resources = env.cost_estimate().resources();
+    recorded.push(Budget {
+        entrypoint: entrypoint.into(),
+        values: [
+            resources.instructions,
+            resources.mem_bytes,
+            (resources.disk_read_entries + resources.memory_read_entries) as i64,
+            resources.write_entries as i64,
+            resources.write_bytes as i64,
+            resources.contract_events_size_bytes as i64,
+        ],
+    });
+}
+
+/// Drive the contract through every state-changing entrypoint and the main
+/// getters, measuring each call
+fn record_budgets() -> std::vec::Vec<Budget> {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+    let mut recorded = std::vec::Vec::new();
+    env.ledger().set_timestamp(1_000);
+
+    client.initialize(&admin, &usdc_address);
+    take_budget(&env, "initialize", &mut recorded);
+    client.set_dispute_timeout(&TestConfig::ONE_WEEK, &DisputeTimeoutPolicy::SplitEvenly);
+    take_budget(&env, "set_dispute_timeout", &mut recorded);
+    client.set_arbitration_fee(&100);
+    take_budget(&env, "set_arbitration_fee", &mut recorded);
+    let config = client.get_config();
+    client.update_config(&config);
+    take_budget(&env, "update_config", &mut recorded);
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
+    take_budget(&env, "set_paused", &mut recorded);
+    client.set_paused(&false, &TestValidation::pause_reason(&env));
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    usdc_token.mint(&creator, &(TestConfig::LARGE_AMOUNT * 5));
+
+    // Bounty lifecycle
+    let task_id = TestValidation::generate_task_id(&env, "budget_paid", 1);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    take_budget(&env, "create_escrow", &mut recorded);
+    client.increase_bounty(&creator, &task_id, &TestConfig::SMALL_AMOUNT);
+    take_budget(&env, "increase_bounty", &mut recorded);
+    client.decrease_bounty(&creator, &task_id, &TestConfig::SMALL_AMOUNT);
+    take_budget(&env, "decrease_bounty", &mut recorded);
+    client.get_escrow(&task_id);
+    take_budget(&env, "get_escrow", &mut recorded);
+    client.get_escrow_by_issue(&TestValidation::dummy_issue_url(&env));
+    take_budget(&env, "get_escrow_by_issue", &mut recorded);
+    client.assign_contributor(&task_id, &contributor);
+    take_budget(&env, "assign_contributor", &mut recorded);
+    client.approve_completion(&task_id);
+    take_budget(&env, "approve_completion", &mut recorded);
+
+    // Disputes
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    let disputed_task = TestValidation::generate_task_id(&env, "budget_dispute", 1);
+    client.create_escrow(
+        &creator,
+        &disputed_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.assign_contributor(&disputed_task, &contributor);
+    client.dispute_task(&creator, &disputed_task, &reason);
+    take_budget(&env, "dispute_task", &mut recorded);
+    client.withdraw_dispute(&creator, &disputed_task);
+    take_budget(&env, "withdraw_dispute", &mut recorded);
+    client.dispute_task(&contributor, &disputed_task, &reason);
+    client.get_dispute_history(&disputed_task);
+    take_budget(&env, "get_dispute_history", &mut recorded);
+    client.resolve_dispute(&disputed_task, &DisputeResolution::PercentageSplit(7500));
+    take_budget(&env, "resolve_dispute", &mut recorded);
+    client.claim_arbiter_fees(&admin);
+    take_budget(&env, "claim_arbiter_fees", &mut recorded);
+
+    let multi_task = TestValidation::generate_task_id(&env, "budget_multi", 1);
+    client.create_escrow(
+        &creator,
+        &multi_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.assign_contributor(&multi_task, &contributor);
+    client.dispute_task(&contributor, &multi_task, &reason);
+    let mut payouts = Vec::new(&env);
+    for _ in 0..3 {
+        payouts.push_back(DisputePayout {
+            recipient: Address::generate(&env),
+            amount: TestConfig::SMALL_AMOUNT,
+        });
+    }
+    client.resolve_dispute(&multi_task, &DisputeResolution::MultiPayout(payouts));
+    take_budget(&env, "resolve_dispute_multi_payout", &mut recorded);
+
+    let stale_task = TestValidation::generate_task_id(&env, "budget_stale", 1);
+    client.create_escrow(
+        &creator,
+        &stale_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.assign_contributor(&stale_task, &contributor);
+    client.dispute_task(&contributor, &stale_task, &reason);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + TestConfig::ONE_WEEK);
+    client.resolve_stale_dispute(&stale_task);
+    take_budget(&env, "resolve_stale_dispute", &mut recorded);
+
+    // Refund
+    let refunded_task = TestValidation::generate_task_id(&env, "budget_refund", 1);
+    client.create_escrow(
+        &creator,
+        &refunded_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    let new_owner = Address::generate(&env);
+    client.transfer_task_ownership(&refunded_task, &new_owner);
+    take_budget(&env, "transfer_task_ownership", &mut recorded);
+    client.cancel_task_ownership_transfer(&refunded_task);
+    take_budget(&env, "cancel_task_ownership_transfer", &mut recorded);
+    client.transfer_task_ownership(&refunded_task, &new_owner);
+    client.accept_task_ownership(&refunded_task);
+    take_budget(&env, "accept_task_ownership", &mut recorded);
+    client.refund(&refunded_task);
+    take_budget(&env, "refund", &mut recorded);
+
+    // Repository metadata
+    let labelled_task = TestValidation::generate_task_id(&env, "budget_labels", 1);
+    client.create_escrow_with_options(
+        &creator,
+        &labelled_task,
+        &TestValidation::issue_url(&env, 2),
+        &TestConfig::MEDIUM_AMOUNT,
+        &EscrowOptions {
+            labels_hash: Some(BytesN::from_array(&env, &[7; 32])),
+            tags: vec![&env, symbol_short!("rust"), symbol_short!("soroban")],
+            difficulty: Difficulty::Intermediate,
+        },
+    );
+    take_budget(&env, "create_escrow_with_options", &mut recorded);
+    client.update_task_tags(
+        &creator,
+        &labelled_task,
+        &vec![&env, symbol_short!("rust"), symbol_short!("wasm")],
+        &Difficulty::Advanced,
+    );
+    take_budget(&env, "update_task_tags", &mut recorded);
+    client.update_task_metadata(
+        &creator,
+        &labelled_task,
+        &TestValidation::issue_url(&env, 3),
+        &Some(BytesN::from_array(&env, &[8; 32])),
+    );
+    take_budget(&env, "update_task_metadata", &mut recorded);
+    client.list_escrows_by_tag(&symbol_short!("rust"), &0);
+    take_budget(&env, "list_escrows_by_tag", &mut recorded);
+    client.list_escrows_by_repo(
+        &String::from_str(&env, "owner"),
+        &String::from_str(&env, "repo"),
+        &0,
+    );
+    take_budget(&env, "list_escrows_by_repo", &mut recorded);
+
+    recorded
+}
+
+fn load_baseline() -> std::vec::Vec<Budget> {
+    std::fs::read_to_string(BASELINE_PATH)
+        .expect("missing budget baseline")
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut parts = line.split(' ');
+            let entrypoint = parts.next().unwrap().into();
+            let mut values = [0; 6];
+            for (index, part) in parts.enumerate() {
+                let (metric, value) = part.split_once('=').unwrap();
+                assert_eq!(metric, METRICS[index], "unexpected metric in baseline");
+                values[index] = value.parse().unwrap();
+            }
+            Budget { entrypoint, values }
+        })
+        .collect()
+}
+
+fn write_baseline(budgets: &[Budget]) {
+    let mut contents = std::string::String::from(
+        "# Resource budget baseline: <entrypoint> <metric>=<value> ...\n",
+    );
+    for budget in budgets {
+        contents.push_str(&budget.entrypoint);
+        for (metric, value) in METRICS.iter().zip(budget.values) {
+            contents.push_str(&format!(" {metric}={value}"));
+        }
+        contents.push('\n');
+    }
+    std::fs::write(BASELINE_PATH, contents).unwrap();
+}
+
+/// Describe every metric that grew beyond the tolerance over its baseline
+fn regressions(baseline: &[Budget], measured: &[Budget]) -> std::vec::Vec<std::string::String> {
+    let mut found = std::vec::Vec::new();
+    for budget in measured {
+        let Some(expected) = baseline
+            .iter()
+            .find(|expected| expected.entrypoint == budget.entrypoint)
+        else {
+            found.push(format!("{}: missing from baseline", budget.entrypoint));
+            continue;
+        };
+        for (index, metric) in METRICS.iter().enumerate() {
+            let allowed = expected.values[index] * (100 + TOLERANCE_PERCENT) / 100;
+            if budget.values[index] > allowed {
+                found.push(format!(
+                    "{}: {metric} grew from {} to {}",
+                    budget.entrypoint, expected.values[index], budget.values[index]
+                ));
+            }
+        }
+    }
+    found
+}
+
+#[test]
+fn test_budget_within_baseline() {
+    let measured = record_budgets();
+
+    if std::env::var("UPDATE_BUDGET_BASELINE").is_ok() {
+        write_baseline(&measured);
+        return;
+    }
+
+    let baseline = load_baseline();
+    let names = |budgets: &[Budget]| {
+        budgets
+            .iter()
+            .map(|budget| budget.entrypoint.clone())
+            .collect::<std::vec::Vec<_>>()
+    };
+    assert_eq!(
+        names(&baseline),
+        names(&measured),
+        "measured entrypoints differ from the baseline"
+    );
+
+    let found = regressions(&baseline, &measured);
+    assert!(
+        found.is_empty(),
+        "resource usage regressed beyond {TOLERANCE_PERCENT}%:\n{}",
+        found.join("\n")
+    );
+}
+
+#[test]
+fn test_budget_measurements_are_deterministic() {
+    assert_eq!(record_budgets(), record_budgets());
+}
+
+#[test]
+fn test_budget_regressions_detected() {
+    let baseline = std::vec![Budget {
+        entrypoint: "create_escrow".into(),
+        values: [1_000_000, 100_000, 10, 4, 1_000, 200],
+    }];
+
+    // Growth within the tolerance and improvements pass
+    let mut measured = baseline.clone();
+    measured[0].values[0] = 1_050_000;
+    measured[0].values[1] = 50_000;
+    assert!(regressions(&baseline, &measured).is_empty());
+
+    // Any metric beyond the tolerance is reported
+    measured[0].values[0] = 1_050_001;
+    measured[0].values[3] = 5;
+    assert_eq!(
+        regressions(&baseline, &measured),
+        std::vec![
+            "create_escrow: cpu_instructions grew from 1000000 to 1050001",
+            "create_escrow: write_entries grew from 4 to 5",
+        ]
+    );
+
+    // Entrypoints without a baseline are reported
+    measured[0].entrypoint = "refund".into();
+    assert_eq!(
+        regressions(&baseline, &measured),
+        std::vec!["refund: missing from baseline"]
+    );
+}
@@ -0,0 +1,101 @@
+//! Tests for the deployer recorded by `__constructor`, which is the only
+//! account besides the new admin that can authorize `initialize`.
+
+use devasign_task_escrow::{Error, TaskEscrowContract, TaskEscrowContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+mod test_config;
+
+use test_config::{TestConfig, TestValidation};
+
+fn deploy(env: &Env, deployer: &Address) -> (Address, TaskEscrowContractClient<'static>) {
+    let usdc_address = env
+        .register_stellar_asset_contract_v2(deployer.clone())
+        .address();
+    let contract_id = env.register(TaskEscrowContract, (deployer,));
+    (
+        usdc_address,
+        TaskEscrowContractClient::new(env, &contract_id),
+    )
+}
+
+#[test]
+fn test_initialize_rejects_front_runner() {
+    let env = Env::default();
+    let deployer = Address::generate(&env);
+    let (usdc_address, client) = deploy(&env, &deployer);
+
+    // Another account signs for itself and tries to claim the contract
+    let attacker = Address::generate(&env);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "initialize",
+                args: (&attacker, &usdc_address).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_initialize(&attacker, &usdc_address);
+    assert!(result.is_err());
+
+    // The contract is still uninitialized
+    let result = client.try_get_admin();
+    assert_eq!(result.unwrap_err().unwrap(), Error::ContractNotInitialized);
+}
+
+#[test]
+fn test_initialize_requires_deployer_and_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let deployer = Address::generate(&env);
+    let (usdc_address, client) = deploy(&env, &deployer);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &usdc_address);
+
+    let signers: Vec<Address> = env
+        .auths()
+        .into_iter()
+        .map(|(address, _)| address)
+        .collect();
+    assert_eq!(signers, [deployer, admin.clone()]);
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_deployer_initializes_as_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (usdc_address, client) = deploy(&env, &admin);
+
+    client.initialize(&admin, &usdc_address);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_usdc_token(), usdc_address);
+    assert_eq!(client.get_arbitration_fee(), 0);
+
+    // The deployer cannot initialize a second time
+    let result = client.try_initialize(&admin, &usdc_address);
+    assert_eq!(result.unwrap_err().unwrap(), Error::AlreadyInitialized);
+
+    // The deployed contract is ready to hold escrows
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "constructor", 1);
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc_address)
+        .mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(
+        client.get_escrow(&task_id).bounty_amount,
+        TestConfig::MEDIUM_AMOUNT
+    );
+}
@@ -13,8 +13,9 @@ fn setup_escrow_for_assignment(
     usdc_token: &soroban_sdk::token::StellarAssetClient,
     creator: &Address,
     task_id: &str,
+    index: u32,
 ) -> String {
-    let task_id = TestValidation::generate_task_id(&env, task_id, 1);
+    let task_id = TestValidation::generate_task_id(env, task_id, index);
     let bounty_amount = TestConfig::MEDIUM_AMOUNT;
 
     // Fund creator and create escrow
@@ -22,7 +23,7 @@ fn setup_escrow_for_assignment(
     client.create_escrow(
         creator,
         &task_id,
-        &TestValidation::dummy_issue_url(&env),
+        &TestValidation::issue_url(env, index),
         &bounty_amount,
     );
 
@@ -39,18 +40,16 @@ fn test_assign_contributor_success() {
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
-    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "assign");
+    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "assign", 1);
 
     // Assign contributor
-    let result = client.assign_contributor(&task_id, &contributor);
-    assert!(result == ());
+    client.assign_contributor(&task_id, &contributor);
 
     // Verify assignment
     let escrow = client.get_escrow(&task_id);
     assert!(escrow.task_id == task_id);
 
-    assert_eq!(escrow.contributor, contributor);
-    assert_eq!(escrow.has_contributor, true);
+    assert_eq!(escrow.contributor, Some(contributor));
     assert_eq!(escrow.status, TaskStatus::InProgress);
 }
 
@@ -64,7 +63,7 @@ fn test_assign_contributor_unauthorized() {
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
-    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "unauth");
+    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "unauth", 1);
 
     // Try to assign contributor from unauthorized address
     env.mock_all_auths_allowing_non_root_auth();
@@ -102,11 +101,10 @@ fn test_assign_contributor_already_assigned() {
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
     let contributor2 = Address::generate(&env);
-    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "already");
+    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "already", 1);
 
     // Assign first contributor
-    let result = client.assign_contributor(&task_id, &contributor1);
-    assert!(result == ());
+    client.assign_contributor(&task_id, &contributor1);
 
     // Try to assign second contributor
     let result = client.try_assign_contributor(&task_id, &contributor2);
@@ -128,22 +126,20 @@ fn test_assign_contributor_invalid_task_id() {
     let contributor = Address::generate(&env);
 
     // Test empty task ID
-    let result =
-        client.try_assign_contributor(&String::from_str(&env, &"".to_string()), &contributor);
+    let result = client.try_assign_contributor(&String::from_str(&env, ""), &contributor);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), Error::EmptyTaskId);
 
-    // Test too short task ID
-    let result =
-        client.try_assign_contributor(&String::from_str(&env, &"ab".to_string()), &contributor);
+    // Length limits only apply on creation, so a short ID is just unknown
+    let result = client.try_assign_contributor(&String::from_str(&env, "ab"), &contributor);
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 
-    // Test too long task ID
+    // Likewise for a long ID
     let long_id = "a".repeat(101);
     let result = client.try_assign_contributor(&String::from_str(&env, &long_id), &contributor);
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 }
 
 #[test]
@@ -169,7 +165,7 @@ fn test_assign_multiple_contributors_different_tasks() {
     client.initialize(&admin, &usdc_address);
 
     let creator = Address::generate(&env);
-    let contributors = vec![
+    let contributors = [
         Address::generate(&env),
         Address::generate(&env),
         Address::generate(&env),
@@ -181,19 +177,18 @@ fn test_assign_multiple_contributors_different_tasks() {
             &client,
             &usdc_token,
             &creator,
-            &format!("multi{}", i),
+            "multi",
+            i as u32 + 1,
         );
 
         // Assign contributor
-        let result = client.assign_contributor(&task_id, contributor);
-        assert!(result == ());
+        client.assign_contributor(&task_id, contributor);
 
         // Verify assignment
         let escrow = client.get_escrow(&task_id);
         assert!(escrow.task_id == task_id);
 
-        assert_eq!(escrow.contributor, *contributor);
-        assert_eq!(escrow.has_contributor, true);
+        assert_eq!(escrow.contributor, Some(contributor.clone()));
         assert_eq!(escrow.status, TaskStatus::InProgress);
     }
 }
@@ -211,29 +206,21 @@ fn test_assign_same_contributor_multiple_tasks() {
 
     // Create multiple tasks and assign same contributor
     for i in 0..3 {
-        let task_id = setup_escrow_for_assignment(
-            &env,
-            &client,
-            &usdc_token,
-            &creator,
-            &format!("same{}", i),
-        );
+        let task_id =
+            setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "same", i + 1);
 
         // Assign same contributor to different tasks
-        let result = client.assign_contributor(&task_id, &contributor);
-        assert!(result == ());
+        client.assign_contributor(&task_id, &contributor);
 
         // Verify assignment
         let escrow = client.get_escrow(&task_id);
         assert!(escrow.task_id == task_id);
 
-        assert_eq!(escrow.contributor, contributor);
-        assert_eq!(escrow.has_contributor, true);
+        assert_eq!(escrow.contributor, Some(contributor.clone()));
         assert_eq!(escrow.status, TaskStatus::InProgress);
     }
 }
 
-
 #[test]
 fn test_assign_contributor_invalid_status() {
     let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
@@ -245,7 +232,7 @@ fn test_assign_contributor_invalid_status() {
     let creator = Address::generate(&env);
     let contributor1 = Address::generate(&env);
     let contributor2 = Address::generate(&env);
-    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "status");
+    let task_id = setup_escrow_for_assignment(&env, &client, &usdc_token, &creator, "status", 1);
 
     // Assign first contributor -> Status becomes InProgress
     client.assign_contributor(&task_id, &contributor1);
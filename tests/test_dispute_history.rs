@@ -0,0 +1,203 @@
+use devasign_task_escrow::{
+    DisputeOutcome, DisputeResolution, DisputeTimeoutPolicy, Error, TaskEscrowContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+fn setup_task_in_progress(
+    env: &Env,
+    client: &TaskEscrowContractClient,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    creator: &Address,
+    contributor: &Address,
+    task_prefix: &str,
+) -> String {
+    let task_id = TestValidation::generate_task_id(env, task_prefix, 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator, create escrow and assign contributor
+    usdc_token.mint(creator, &bounty_amount);
+    client.create_escrow(
+        creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(env),
+        &bounty_amount,
+    );
+    client.assign_contributor(&task_id, contributor);
+
+    task_id
+}
+
+#[test]
+fn test_dispute_history_empty() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = setup_task_in_progress(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "history_empty",
+    );
+
+    let history = client.get_dispute_history(&task_id);
+    assert!(history.is_empty());
+}
+
+#[test]
+fn test_dispute_history_pending_entry() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = setup_task_in_progress(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "history_pending",
+    );
+
+    env.ledger().set_timestamp(5000);
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    client.dispute_task(&creator, &task_id, &reason);
+
+    let history = client.get_dispute_history(&task_id);
+    assert_eq!(history.len(), 1);
+
+    let record = history.get(0).unwrap();
+    assert_eq!(record.index, 0);
+    assert_eq!(record.disputing_party, creator);
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.initiated_at, 5000);
+    assert_eq!(record.outcome, DisputeOutcome::Pending);
+    assert_eq!(record.resolved_by, None);
+    assert_eq!(record.closed_at, None);
+}
+
+#[test]
+fn test_dispute_history_withdraw_then_resolve() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = setup_task_in_progress(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "history_redispute",
+    );
+
+    // First dispute is opened and withdrawn by the creator
+    env.ledger().set_timestamp(1000);
+    let first_reason = TestValidation::generate_dispute_reason(&env, "quality");
+    client.dispute_task(&creator, &task_id, &first_reason);
+    env.ledger().set_timestamp(2000);
+    client.withdraw_dispute(&creator, &task_id);
+
+    // Second dispute is opened by the contributor and resolved by admin
+    env.ledger().set_timestamp(3000);
+    let second_reason = TestValidation::generate_dispute_reason(&env, "payment");
+    client.dispute_task(&contributor, &task_id, &second_reason);
+    env.ledger().set_timestamp(4000);
+    client.resolve_dispute(&task_id, &DisputeResolution::PercentageSplit(6000));
+
+    let history = client.get_dispute_history(&task_id);
+    assert_eq!(history.len(), 2);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.index, 0);
+    assert_eq!(first.disputing_party, creator.clone());
+    assert_eq!(first.reason, first_reason);
+    assert_eq!(first.initiated_at, 1000);
+    assert_eq!(first.outcome, DisputeOutcome::Withdrawn);
+    assert_eq!(first.resolved_by, Some(creator));
+    assert_eq!(first.closed_at, Some(2000));
+
+    let second = history.get(1).unwrap();
+    assert_eq!(second.index, 1);
+    assert_eq!(second.disputing_party, contributor);
+    assert_eq!(second.reason, second_reason);
+    assert_eq!(second.initiated_at, 3000);
+    assert_eq!(
+        second.outcome,
+        DisputeOutcome::Resolved(DisputeResolution::PercentageSplit(6000))
+    );
+    assert_eq!(second.resolved_by, Some(admin));
+    assert_eq!(second.closed_at, Some(4000));
+}
+
+#[test]
+fn test_dispute_history_stale_resolution() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+    client.set_dispute_timeout(&TestConfig::ONE_DAY, &DisputeTimeoutPolicy::RefundCreator);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = setup_task_in_progress(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "history_stale",
+    );
+
+    let reason = TestValidation::generate_dispute_reason(&env, "deadline");
+    client.dispute_task(&contributor, &task_id, &reason);
+
+    env.ledger().set_timestamp(TestConfig::ONE_DAY);
+    client.resolve_stale_dispute(&task_id);
+
+    let record = client.get_dispute_history(&task_id).get(0).unwrap();
+    assert_eq!(
+        record.outcome,
+        DisputeOutcome::Resolved(DisputeResolution::RefundCreator)
+    );
+    assert_eq!(record.resolved_by, Some(contract_id));
+    assert_eq!(record.closed_at, Some(TestConfig::ONE_DAY));
+}
+
+#[test]
+fn test_dispute_history_task_not_found() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let task_id = TestValidation::generate_task_id(&env, "history_missing", 1);
+
+    let result = client.try_get_dispute_history(&task_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+}
@@ -0,0 +1,212 @@
+use devasign_task_escrow::{
+    DisputeResolution, DisputeTimeoutPolicy, Error, TaskEscrowContractClient, TaskStatus,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+fn setup_disputed_task(
+    env: &Env,
+    client: &TaskEscrowContractClient,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    creator: &Address,
+    contributor: &Address,
+    task_prefix: &str,
+) -> (String, i128) {
+    let task_id = TestValidation::generate_task_id(env, task_prefix, 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator, create escrow and assign contributor
+    usdc_token.mint(creator, &bounty_amount);
+    client.create_escrow(
+        creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(env),
+        &bounty_amount,
+    );
+    client.assign_contributor(&task_id, contributor);
+
+    // Open dispute
+    let reason = TestValidation::generate_dispute_reason(env, "quality");
+    client.dispute_task(creator, &task_id, &reason);
+
+    (task_id, bounty_amount)
+}
+
+#[test]
+fn test_default_dispute_timeout() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let (timeout, policy) = client.get_dispute_timeout();
+    assert_eq!(timeout, 30 * TestConfig::ONE_DAY);
+    assert_eq!(policy, DisputeTimeoutPolicy::RefundCreator);
+}
+
+#[test]
+fn test_set_dispute_timeout() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    client.set_dispute_timeout(&TestConfig::ONE_WEEK, &DisputeTimeoutPolicy::SplitEvenly);
+
+    let (timeout, policy) = client.get_dispute_timeout();
+    assert_eq!(timeout, TestConfig::ONE_WEEK);
+    assert_eq!(policy, DisputeTimeoutPolicy::SplitEvenly);
+
+    // Zero timeout is rejected
+    let result = client.try_set_dispute_timeout(&0, &DisputeTimeoutPolicy::RefundCreator);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDisputeTimeout);
+}
+
+#[test]
+fn test_set_dispute_timeout_unauthorized() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Clear mocked auths to enforce strict checking
+    env.mock_auths(&[]);
+
+    let result =
+        client.try_set_dispute_timeout(&TestConfig::ONE_DAY, &DisputeTimeoutPolicy::PayContributor);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_resolve_stale_dispute_before_timeout() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+    client.set_dispute_timeout(&TestConfig::ONE_WEEK, &DisputeTimeoutPolicy::RefundCreator);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    env.ledger().set_timestamp(1000);
+    let (task_id, _) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "stale_early",
+    );
+
+    // Just before the timeout elapses
+    env.ledger().set_timestamp(1000 + TestConfig::ONE_WEEK - 1);
+    let result = client.try_resolve_stale_dispute(&task_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DisputeNotExpired);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, TaskStatus::Disputed);
+}
+
+#[test]
+fn test_resolve_stale_dispute_refund_creator() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+    client.set_dispute_timeout(&TestConfig::ONE_WEEK, &DisputeTimeoutPolicy::RefundCreator);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    env.ledger().set_timestamp(1000);
+    let (task_id, bounty_amount) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "stale_refund",
+    );
+
+    // Advance past the timeout
+    env.ledger().set_timestamp(1000 + TestConfig::ONE_WEEK);
+    client.resolve_stale_dispute(&task_id);
+
+    assert_eq!(usdc_token_client.balance(&creator), bounty_amount);
+    assert_eq!(usdc_token_client.balance(&contributor), 0);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, TaskStatus::Completed);
+}
+
+#[test]
+fn test_resolve_stale_dispute_split_evenly() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+    client.set_dispute_timeout(&TestConfig::ONE_DAY, &DisputeTimeoutPolicy::SplitEvenly);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (task_id, bounty_amount) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "stale_split",
+    );
+
+    // Advance past the timeout
+    env.ledger().set_timestamp(TestConfig::ONE_DAY + 1);
+    client.resolve_stale_dispute(&task_id);
+
+    let contributor_share = bounty_amount / 2;
+    assert_eq!(usdc_token_client.balance(&contributor), contributor_share);
+    assert_eq!(
+        usdc_token_client.balance(&creator),
+        bounty_amount - contributor_share
+    );
+    assert_eq!(usdc_token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_resolve_stale_dispute_not_disputed() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (task_id, _) = setup_disputed_task(
+        &env,
+        &client,
+        &usdc_token,
+        &creator,
+        &contributor,
+        "stale_twice",
+    );
+
+    // Admin resolves the dispute first
+    client.resolve_dispute(&task_id, &DisputeResolution::PayContributor);
+
+    // Stale resolution is no longer possible
+    env.ledger().set_timestamp(365 * TestConfig::ONE_DAY);
+    let result = client.try_resolve_stale_dispute(&task_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
+}
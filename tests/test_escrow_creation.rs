@@ -1,5 +1,7 @@
 use devasign_task_escrow::{Error, TaskStatus};
-use soroban_sdk::{testutils::Address as _, Address, String};
+use soroban_sdk::testutils::{Address as _, IssuerFlags};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, String};
 
 mod test_config;
 mod test_setup;
@@ -24,13 +26,12 @@ fn test_create_escrow_success() {
     usdc_token.mint(&creator, &bounty_amount);
 
     // Create escrow
-    let result = client.create_escrow(
+    client.create_escrow(
         &creator,
         &task_id,
         &TestValidation::dummy_issue_url(&env),
         &bounty_amount,
     );
-    assert!(result == ());
 
     // Verify escrow was created
     let escrow = client.get_escrow(&task_id);
@@ -42,7 +43,9 @@ fn test_create_escrow_success() {
     assert_eq!(escrow_data.issue_url, TestValidation::dummy_issue_url(&env));
     assert_eq!(escrow_data.bounty_amount, bounty_amount);
     assert_eq!(escrow_data.status, TaskStatus::Open);
-    assert_eq!(escrow_data.has_contributor, false);
+    assert_eq!(escrow_data.contributor, None);
+    assert_eq!(escrow_data.completed_at, None);
+    assert_eq!(escrow_data.disputed_at, None);
 }
 
 #[test]
@@ -63,13 +66,12 @@ fn test_create_escrow_multiple_amounts() {
         usdc_token.mint(&creator, &amount);
 
         // Create escrow
-        let result = client.create_escrow(
+        client.create_escrow(
             &creator,
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i as u32 + 1),
             &amount,
         );
-        assert!(result == ());
 
         // Verify escrow
         let escrow = client.get_escrow(&task_id);
@@ -96,13 +98,12 @@ fn test_create_escrow_boundary_amounts() {
         usdc_token.mint(&creator, &amount);
 
         // Create escrow
-        let result = client.create_escrow(
+        client.create_escrow(
             &creator,
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i as u32 + 1),
             &amount,
         );
-        assert!(result == ());
     }
 }
 
@@ -136,7 +137,7 @@ fn test_create_escrow_invalid_amounts() {
         let error = result.unwrap_err().unwrap();
         assert!(matches!(
             error,
-            Error::InvalidAmount | Error::InvalidTokenAmount
+            Error::InvalidAmount | Error::AmountTooSmall | Error::InvalidTokenAmount
         ));
     }
 }
@@ -157,13 +158,12 @@ fn test_create_escrow_duplicate_task_id() {
     usdc_token.mint(&creator, &(bounty_amount * 2));
 
     // Create first escrow
-    let result = client.create_escrow(
+    client.create_escrow(
         &creator,
         &task_id,
         &TestValidation::dummy_issue_url(&env),
         &bounty_amount,
     );
-    assert!(result == ());
 
     // Try to create duplicate
     let result = client.try_create_escrow(
@@ -193,7 +193,7 @@ fn test_create_escrow_invalid_task_ids() {
     // Test empty task ID
     let result = client.try_create_escrow(
         &creator,
-        &String::from_str(&env, &"".to_string()),
+        &String::from_str(&env, ""),
         &TestValidation::dummy_issue_url(&env),
         &bounty_amount,
     );
@@ -203,12 +203,12 @@ fn test_create_escrow_invalid_task_ids() {
     // Test too short task ID
     let result = client.try_create_escrow(
         &creator,
-        &String::from_str(&env, &"ab".to_string()),
+        &String::from_str(&env, "ab"),
         &TestValidation::dummy_issue_url(&env),
         &bounty_amount,
     );
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskIdTooShort);
 
     // Test too long task ID
     let long_id = "a".repeat(101);
@@ -219,7 +219,125 @@ fn test_create_escrow_invalid_task_ids() {
         &bounty_amount,
     );
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskIdTooLong);
+}
+
+#[test]
+fn test_create_escrow_invalid_task_id_characters() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // Spaces, punctuation and non-ASCII characters are rejected
+    for task_id in [
+        "clinvalid 000000000000001",
+        "clinvalid/000000000000001",
+        "clinvalid.000000000000001",
+        "clinvalidé00000000000001",
+    ] {
+        let result = client.try_create_escrow(
+            &creator,
+            &String::from_str(&env, task_id),
+            &TestValidation::dummy_issue_url(&env),
+            &bounty_amount,
+        );
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskIdCharacters);
+    }
+
+    // Letters, digits, hyphens and underscores are accepted
+    let task_id = String::from_str(&env, "clValid-Task_000000000001");
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+    assert_eq!(client.get_escrow(&task_id).task_id, task_id);
+}
+
+#[test]
+fn test_create_escrow_malformed_issue_urls() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "malformed_url", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+    usdc_token.mint(&creator, &bounty_amount);
+
+    for url in [
+        "not a url",
+        "ftp://github.com/owner/repo/issues/1",
+        "https://gitlab.com/owner/repo/issues/1",
+        "https://github.com/owner/repo",
+        "https://github.com/owner/repo/pull/1",
+        "https://github.com/owner/repo/issues/",
+        "https://github.com/owner/repo/issues/0",
+        "https://github.com/owner/repo/issues/012",
+        "https://github.com/owner/repo/issues/12a",
+        "https://github.com/owner/repo/issues/1#issuecomment-1",
+        "https://github.com//repo/issues/1",
+        "https://github.com/own_er/repo/issues/1",
+        "https://github.com/owner/re po/issues/1",
+    ] {
+        let result = client.try_create_escrow(
+            &creator,
+            &task_id,
+            &String::from_str(&env, url),
+            &bounty_amount,
+        );
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            Error::InvalidIssueUrl,
+            "{url} should be rejected"
+        );
+    }
+
+    // Repository names may contain dots and underscores
+    let issue_url = String::from_str(
+        &env,
+        "https://github.com/dev-asign/soroban_contract.rs/issues/42",
+    );
+    client.create_escrow(&creator, &task_id, &issue_url, &bounty_amount);
+    assert_eq!(client.get_escrow(&task_id).issue_url, issue_url);
+}
+
+#[test]
+fn test_create_escrow_token_transfer_failed() {
+    let (env, admin, _usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Use an asset whose issuer can revoke authorization
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let usdc_token = StellarAssetClient::new(&env, &sac.address());
+
+    // Initialize contract
+    client.initialize(&admin, &sac.address());
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "frozen", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // A deauthorized account holds the balance but cannot transfer it
+    usdc_token.set_authorized(&creator, &false);
+    let result = client.try_create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::TokenTransferFailed);
 }
 
 #[test]
@@ -289,10 +407,10 @@ fn test_get_escrow_invalid_task_id() {
     // Initialize contract
     client.initialize(&admin, &usdc_address);
 
-    // Try to get escrow with invalid task ID
-    let result = client.try_get_escrow(&String::from_str(&env, &"ab".to_string()));
+    // Length limits only apply on creation, so a short ID is just unknown
+    let result = client.try_get_escrow(&String::from_str(&env, "ab"));
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 }
 
 #[test]
@@ -312,7 +430,7 @@ fn test_create_escrow_invalid_url() {
     let result = client.try_create_escrow(
         &creator,
         &task_id,
-        &String::from_str(&env, &"".to_string()),
+        &String::from_str(&env, ""),
         &bounty_amount,
     );
     assert!(result.is_err());
@@ -0,0 +1,300 @@
+use devasign_task_escrow::{
+    Difficulty, DisputeResolution, DisputeTimeoutPolicy, TaskEscrowContract, EVENT_SCHEMA_VERSION,
+};
+use soroban_sdk::{
+    contracttype, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    xdr::{Limits, ReadXdr, ScVal, WriteXdr},
+    Address, BytesN, Env, String, Symbol, TryFromVal, TryIntoVal, Val, Vec,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+/// Fixture holding the raw XDR of every event emitted by `record_scenario`.
+/// Each line is `<event_name> <topics_base64> <data_base64>`.
+/// The contract version topic is checked live and left out of the fixture so
+/// that releases do not invalidate it.
+/// Regenerate with `UPDATE_EVENT_FIXTURES=1 cargo test --test test_event_schema`
+/// only after bumping `EVENT_SCHEMA_VERSION`.
+const FIXTURE_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/event_schema_v4.txt"
+);
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscrowCreatedEvent {
+    pub task_id: String,
+    pub issue_url: String,
+    pub labels_hash: Option<BytesN<32>>,
+    pub creator: Address,
+    pub bounty_amount: i128,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeResolvedEvent {
+    pub task_id: String,
+    pub resolution: DisputeResolution,
+    pub resolved_by: Address,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct RawEvent {
+    name: std::string::String,
+    topics: std::string::String,
+    data: std::string::String,
+}
+
+/// Check the version topics and encode the contract events of the last
+/// invocation as raw XDR
+fn take_events(env: &Env, contract_id: &Address, recorded: &mut std::vec::Vec<RawEvent>) {
+    for (address, topics, data) in env.events().all().iter() {
+        if address != *contract_id {
+            continue;
+        }
+        let name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
+
+        // Every event carries the schema and contract versions after its name
+        assert_eq!(topics.len(), 3);
+        let schema_version: u32 = topics.get(1).unwrap().try_into_val(env).unwrap();
+        let contract_version: u64 = topics.get(2).unwrap().try_into_val(env).unwrap();
+        assert_eq!(schema_version, EVENT_SCHEMA_VERSION);
+        assert_eq!(contract_version, TaskEscrowContract::version());
+
+        let stable_topics = topics.slice(0..2);
+        let topics = ScVal::try_from_val(env, &stable_topics.to_val()).unwrap();
+        let data = ScVal::try_from_val(env, &data).unwrap();
+        recorded.push(RawEvent {
+            name: name.to_string(),
+            topics: topics.to_xdr_base64(Limits::none()).unwrap(),
+            data: data.to_xdr_base64(Limits::none()).unwrap(),
+        });
+    }
+}
+
+/// Drive the contract through a scenario that emits every reachable event
+fn record_scenario() -> std::vec::Vec<RawEvent> {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    let mut recorded = std::vec::Vec::new();
+    env.ledger().set_timestamp(1_000);
+
+    // Configuration events
+    client.initialize(&admin, &usdc_address);
+    take_events(&env, &contract_id, &mut recorded);
+    client.set_dispute_timeout(&TestConfig::ONE_WEEK, &DisputeTimeoutPolicy::SplitEvenly);
+    take_events(&env, &contract_id, &mut recorded);
+    client.set_arbitration_fee(&100);
+    take_events(&env, &contract_id, &mut recorded);
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
+    take_events(&env, &contract_id, &mut recorded);
+    client.set_paused(&false, &TestValidation::pause_reason(&env));
+    take_events(&env, &contract_id, &mut recorded);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    usdc_token.mint(&creator, &(TestConfig::LARGE_AMOUNT * 3));
+
+    // Task lifecycle events
+    let paid_task = TestValidation::generate_task_id(&env, "schema_paid", 1);
+    client.create_escrow(
+        &creator,
+        &paid_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    take_events(&env, &contract_id, &mut recorded);
+    client.increase_bounty(&creator, &paid_task, &TestConfig::SMALL_AMOUNT);
+    take_events(&env, &contract_id, &mut recorded);
+    client.decrease_bounty(&creator, &paid_task, &TestConfig::SMALL_AMOUNT);
+    take_events(&env, &contract_id, &mut recorded);
+    client.update_task_tags(
+        &creator,
+        &paid_task,
+        &soroban_sdk::vec![&env, symbol_short!("rust")],
+        &Difficulty::Beginner,
+    );
+    take_events(&env, &contract_id, &mut recorded);
+    client.update_task_metadata(
+        &creator,
+        &paid_task,
+        &TestValidation::dummy_issue_url(&env),
+        &Some(BytesN::from_array(&env, &[7; 32])),
+    );
+    take_events(&env, &contract_id, &mut recorded);
+    client.assign_contributor(&paid_task, &contributor);
+    take_events(&env, &contract_id, &mut recorded);
+    client.approve_completion(&paid_task);
+    take_events(&env, &contract_id, &mut recorded);
+
+    // Dispute events
+    let disputed_task = TestValidation::generate_task_id(&env, "schema_dispute", 1);
+    client.create_escrow(
+        &creator,
+        &disputed_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    take_events(&env, &contract_id, &mut recorded);
+    client.assign_contributor(&disputed_task, &contributor);
+    take_events(&env, &contract_id, &mut recorded);
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    client.dispute_task(&creator, &disputed_task, &reason);
+    take_events(&env, &contract_id, &mut recorded);
+    client.withdraw_dispute(&creator, &disputed_task);
+    take_events(&env, &contract_id, &mut recorded);
+    client.dispute_task(&contributor, &disputed_task, &reason);
+    take_events(&env, &contract_id, &mut recorded);
+    client.resolve_dispute(&disputed_task, &DisputeResolution::PercentageSplit(7500));
+    take_events(&env, &contract_id, &mut recorded);
+    client.claim_arbiter_fees(&admin);
+    take_events(&env, &contract_id, &mut recorded);
+
+    // Refund event
+    let refunded_task = TestValidation::generate_task_id(&env, "schema_refund", 1);
+    client.create_escrow(
+        &creator,
+        &refunded_task,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    take_events(&env, &contract_id, &mut recorded);
+    let new_owner = Address::generate(&env);
+    client.transfer_task_ownership(&refunded_task, &new_owner);
+    take_events(&env, &contract_id, &mut recorded);
+    client.cancel_task_ownership_transfer(&refunded_task);
+    take_events(&env, &contract_id, &mut recorded);
+    client.transfer_task_ownership(&refunded_task, &new_owner);
+    client.accept_task_ownership(&refunded_task);
+    take_events(&env, &contract_id, &mut recorded);
+    client.refund(&refunded_task);
+    take_events(&env, &contract_id, &mut recorded);
+
+    // Administrative events
+    let new_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.update_usdc_token(&new_token);
+    take_events(&env, &contract_id, &mut recorded);
+    client.set_admin(&Address::generate(&env));
+    take_events(&env, &contract_id, &mut recorded);
+    let mut config = client.get_config();
+    config.task_id_max_length = 36;
+    client.update_config(&config);
+    take_events(&env, &contract_id, &mut recorded);
+
+    recorded
+}
+
+fn load_fixture() -> std::vec::Vec<RawEvent> {
+    std::fs::read_to_string(FIXTURE_PATH)
+        .expect("missing event schema fixture")
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut parts = line.split(' ');
+            RawEvent {
+                name: parts.next().unwrap().into(),
+                topics: parts.next().unwrap().into(),
+                data: parts.next().unwrap().into(),
+            }
+        })
+        .collect()
+}
+
+fn write_fixture(events: &[RawEvent]) {
+    let mut contents = format!(
+        "# Event schema v{EVENT_SCHEMA_VERSION} fixture: <event_name> <topics_xdr_base64> <data_xdr_base64>\n"
+    );
+    for event in events {
+        contents.push_str(&format!("{} {} {}\n", event.name, event.topics, event.data));
+    }
+    std::fs::write(FIXTURE_PATH, contents).unwrap();
+}
+
+/// Decode a base64 XDR value from the fixture into a contract type
+fn decode_fixture_value<T: TryFromVal<Env, Val>>(env: &Env, xdr: &str) -> T {
+    let sc_val = ScVal::from_xdr_base64(xdr, Limits::none()).unwrap();
+    let val = Val::try_from_val(env, &sc_val).unwrap();
+    T::try_from_val(env, &val).unwrap_or_else(|_| panic!("fixture does not decode"))
+}
+
+#[test]
+fn test_event_payloads_match_fixture() {
+    let recorded = record_scenario();
+
+    if std::env::var("UPDATE_EVENT_FIXTURES").is_ok() {
+        write_fixture(&recorded);
+    }
+
+    let fixture = load_fixture();
+    assert_eq!(recorded.len(), fixture.len());
+    for (actual, expected) in recorded.iter().zip(fixture.iter()) {
+        assert_eq!(
+            actual, expected,
+            "event layout changed; bump EVENT_SCHEMA_VERSION"
+        );
+    }
+}
+
+#[test]
+fn test_fixture_topics_decode() {
+    let env = Env::default();
+
+    for event in load_fixture() {
+        let topics: Vec<Val> = decode_fixture_value(&env, &event.topics);
+        assert_eq!(topics.len(), 2);
+
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        assert_eq!(name, Symbol::new(&env, &event.name));
+
+        let schema_version: u32 = topics.get(1).unwrap().try_into_val(&env).unwrap();
+        assert_eq!(schema_version, EVENT_SCHEMA_VERSION);
+    }
+}
+
+#[test]
+fn test_fixture_data_decodes_into_event_structs() {
+    let env = Env::default();
+    let fixture = load_fixture();
+
+    let created = fixture
+        .iter()
+        .find(|event| event.name == "escrow_created_event")
+        .unwrap();
+    let created: EscrowCreatedEvent = decode_fixture_value(&env, &created.data);
+    assert_eq!(
+        created.task_id,
+        TestValidation::generate_task_id(&env, "schema_paid", 1)
+    );
+    assert_eq!(created.issue_url, TestValidation::dummy_issue_url(&env));
+    assert_eq!(created.labels_hash, None);
+    assert_eq!(created.bounty_amount, TestConfig::MEDIUM_AMOUNT);
+    assert!(created.tags.is_empty());
+    assert_eq!(created.difficulty, Difficulty::Unrated);
+    assert_eq!(created.timestamp, 1_000);
+
+    let resolved = fixture
+        .iter()
+        .find(|event| event.name == "dispute_resolved_event")
+        .unwrap();
+    let resolved: DisputeResolvedEvent = decode_fixture_value(&env, &resolved.data);
+    assert_eq!(
+        resolved.task_id,
+        TestValidation::generate_task_id(&env, "schema_dispute", 1)
+    );
+    assert_eq!(
+        resolved.resolution,
+        DisputeResolution::PercentageSplit(7500)
+    );
+    assert_eq!(resolved.timestamp, 1_000);
+}
@@ -1,8 +1,10 @@
-use devasign_task_escrow::DisputeResolution;
+use devasign_task_escrow::{
+    Config, Difficulty, DisputeResolution, DisputeTimeoutPolicy, EscrowOptions,
+};
 use soroban_sdk::{
-    contracttype,
+    contracttype, symbol_short,
     testutils::{Address as _, Events, Ledger},
-    Address, String, Symbol, TryIntoVal, Val,
+    vec, Address, BytesN, String, Symbol, TryIntoVal, Val, Vec,
 };
 
 mod test_config;
@@ -18,8 +20,12 @@ use test_setup::create_test_env;
 #[derive(Clone, Debug, PartialEq)]
 pub struct EscrowCreatedEvent {
     pub task_id: String,
+    pub issue_url: String,
+    pub labels_hash: Option<BytesN<32>>,
     pub creator: Address,
     pub bounty_amount: i128,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
     pub timestamp: u64,
 }
 
@@ -31,6 +37,55 @@ pub struct ContributorAssignedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskMetadataUpdatedEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub old_issue_url: String,
+    pub new_issue_url: String,
+    pub old_labels_hash: Option<BytesN<32>>,
+    pub new_labels_hash: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskOwnershipProposedEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub pending_creator: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskOwnershipTransferredEvent {
+    pub task_id: String,
+    pub old_creator: Address,
+    pub new_creator: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskOwnershipCancelledEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub pending_creator: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskTagsUpdatedEvent {
+    pub task_id: String,
+    pub creator: Address,
+    pub tags: Vec<Symbol>,
+    pub difficulty: Difficulty,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct FundsReleasedEvent {
@@ -49,6 +104,14 @@ pub struct DisputeInitiatedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeWithdrawnEvent {
+    pub task_id: String,
+    pub disputing_party: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct DisputeResolvedEvent {
@@ -58,6 +121,14 @@ pub struct DisputeResolvedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArbiterFeesClaimedEvent {
+    pub arbiter: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct RefundProcessedEvent {
@@ -87,6 +158,65 @@ pub struct BountyDecreasedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializedEvent {
+    pub admin: Address,
+    pub usdc_token: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminChangedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseStateChangedEvent {
+    pub paused: bool,
+    pub actor: Address,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenUpdatedEvent {
+    pub old_token: Address,
+    pub new_token: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeTimeoutUpdatedEvent {
+    pub timeout: u64,
+    pub policy: DisputeTimeoutPolicy,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArbitrationFeeUpdatedEvent {
+    pub fee_basis_points: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigUpdatedEvent {
+    pub config: Config,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
 #[test]
 fn test_escrow_created_event() {
     let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
@@ -131,8 +261,12 @@ fn test_escrow_created_event() {
     // Verify event data
     let expected_event = EscrowCreatedEvent {
         task_id,
+        issue_url: TestValidation::dummy_issue_url(&env),
+        labels_hash: None,
         creator,
         bounty_amount,
+        tags: Vec::new(&env),
+        difficulty: Difficulty::Unrated,
         timestamp,
     };
 
@@ -140,6 +274,248 @@ fn test_escrow_created_event() {
     assert_eq!(actual_event, expected_event);
 }
 
+#[test]
+fn test_escrow_created_event_with_options() {
+    let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "event_options", 1);
+    let labels_hash = Some(BytesN::from_array(&env, &[3; 32]));
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+        &EscrowOptions {
+            labels_hash: labels_hash.clone(),
+            tags: vec![&env, symbol_short!("rust")],
+            difficulty: Difficulty::Beginner,
+        },
+    );
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .filter(|e| e.0 == contract_id)
+        .last()
+        .unwrap();
+    let actual_event: EscrowCreatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event.labels_hash, labels_hash);
+    assert_eq!(actual_event.tags, vec![&env, symbol_short!("rust")]);
+    assert_eq!(actual_event.difficulty, Difficulty::Beginner);
+}
+
+#[test]
+fn test_task_metadata_updated_event() {
+    let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 16000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Create test data
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "metadata_evt", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator with USDC
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // Create escrow
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+
+    // Point the task at another issue
+    let new_issue_url = TestValidation::issue_url(&env, 2);
+    let labels_hash = Some(BytesN::from_array(&env, &[4; 32]));
+    client.update_task_metadata(&creator, &task_id, &new_issue_url, &labels_hash);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "task_metadata_updated_event"));
+
+    // Verify event data
+    let expected_event = TaskMetadataUpdatedEvent {
+        task_id,
+        creator,
+        old_issue_url: TestValidation::dummy_issue_url(&env),
+        new_issue_url,
+        old_labels_hash: None,
+        new_labels_hash: labels_hash,
+        timestamp,
+    };
+
+    let actual_event: TaskMetadataUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_task_ownership_events() {
+    let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 17000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Create test data
+    let creator = Address::generate(&env);
+    let new_creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership_evt", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator with USDC
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // Create escrow
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+
+    // Nominate the new creator
+    client.transfer_task_ownership(&task_id, &new_creator);
+
+    let events = env.events().all();
+    let event = events.iter().filter(|e| e.0 == contract_id).last().unwrap();
+    let topic_sym: Symbol = event.1.iter().next().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(
+        topic_sym,
+        Symbol::new(&env, "task_ownership_proposed_event")
+    );
+    let actual_event: TaskOwnershipProposedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(
+        actual_event,
+        TaskOwnershipProposedEvent {
+            task_id: task_id.clone(),
+            creator: creator.clone(),
+            pending_creator: new_creator.clone(),
+            timestamp,
+        }
+    );
+
+    // Withdraw the nomination
+    client.cancel_task_ownership_transfer(&task_id);
+
+    let events = env.events().all();
+    let event = events.iter().filter(|e| e.0 == contract_id).last().unwrap();
+    let topic_sym: Symbol = event.1.iter().next().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(
+        topic_sym,
+        Symbol::new(&env, "task_ownership_cancelled_event")
+    );
+    let actual_event: TaskOwnershipCancelledEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(
+        actual_event,
+        TaskOwnershipCancelledEvent {
+            task_id: task_id.clone(),
+            creator: creator.clone(),
+            pending_creator: new_creator.clone(),
+            timestamp,
+        }
+    );
+
+    // Nominate again and accept the nomination
+    client.transfer_task_ownership(&task_id, &new_creator);
+    client.accept_task_ownership(&task_id);
+
+    let events = env.events().all();
+    let event = events.iter().filter(|e| e.0 == contract_id).last().unwrap();
+    let topic_sym: Symbol = event.1.iter().next().unwrap().try_into_val(&env).unwrap();
+    assert_eq!(
+        topic_sym,
+        Symbol::new(&env, "task_ownership_transferred_event")
+    );
+    let actual_event: TaskOwnershipTransferredEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(
+        actual_event,
+        TaskOwnershipTransferredEvent {
+            task_id,
+            old_creator: creator,
+            new_creator,
+            timestamp,
+        }
+    );
+}
+
+#[test]
+fn test_task_tags_updated_event() {
+    let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 15000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Create test data
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "tags_evt", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator with USDC
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // Create escrow
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+
+    // Tag the task
+    let tags = vec![&env, symbol_short!("rust"), symbol_short!("wasm")];
+    client.update_task_tags(&creator, &task_id, &tags, &Difficulty::Intermediate);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "task_tags_updated_event"));
+
+    // Verify event data
+    let expected_event = TaskTagsUpdatedEvent {
+        task_id,
+        creator,
+        tags,
+        difficulty: Difficulty::Intermediate,
+        timestamp,
+    };
+
+    let actual_event: TaskTagsUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
 #[test]
 fn test_contributor_assigned_event() {
     let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
@@ -421,6 +797,63 @@ fn test_dispute_initiated_event() {
     assert_eq!(actual_event, expected_event);
 }
 
+#[test]
+fn test_dispute_withdrawn_event() {
+    let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 65000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Create test data
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "withdraw_evt", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator with USDC
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // Create escrow
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+
+    // Assign contributor, initiate and withdraw dispute
+    client.assign_contributor(&task_id, &contributor);
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    client.dispute_task(&contributor, &task_id, &reason);
+    client.withdraw_dispute(&contributor, &task_id);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "dispute_withdrawn_event"));
+
+    // Verify event data
+    let expected_event = DisputeWithdrawnEvent {
+        task_id,
+        disputing_party: contributor,
+        timestamp,
+    };
+
+    let actual_event: DisputeWithdrawnEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
 #[test]
 fn test_dispute_resolved_event() {
     let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
@@ -481,6 +914,65 @@ fn test_dispute_resolved_event() {
     assert_eq!(actual_event, expected_event);
 }
 
+#[test]
+fn test_arbiter_fees_claimed_event() {
+    let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
+
+    // Initialize contract with a 1% arbitration fee
+    client.initialize(&admin, &usdc_address);
+    client.set_arbitration_fee(&100);
+
+    // Set ledger timestamp
+    let timestamp = 75000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Create test data
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "claim_evt", 1);
+    let bounty_amount = TestConfig::MEDIUM_AMOUNT;
+
+    // Fund creator with USDC
+    usdc_token.mint(&creator, &bounty_amount);
+
+    // Create escrow
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &bounty_amount,
+    );
+
+    // Assign contributor, dispute, resolve and claim the fee
+    client.assign_contributor(&task_id, &contributor);
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    client.dispute_task(&creator, &task_id, &reason);
+    client.resolve_dispute(&task_id, &DisputeResolution::PayContributor);
+    client.claim_arbiter_fees(&admin);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "arbiter_fees_claimed_event"));
+
+    // Verify event data
+    let expected_event = ArbiterFeesClaimedEvent {
+        arbiter: admin,
+        amount: bounty_amount / 100,
+        timestamp,
+    };
+
+    let actual_event: ArbiterFeesClaimedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
 #[test]
 fn test_refund_processed_event() {
     let (env, admin, usdc_address, usdc_token, _, contract_id, client) = create_test_env();
@@ -534,3 +1026,275 @@ fn test_refund_processed_event() {
     let actual_event: RefundProcessedEvent = event.2.try_into_val(&env).unwrap();
     assert_eq!(actual_event, expected_event);
 }
+
+#[test]
+fn test_initialized_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Set ledger timestamp
+    let timestamp = 90000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "initialized_event"));
+
+    // Verify event data
+    let expected_event = InitializedEvent {
+        admin,
+        usdc_token: usdc_address,
+        timestamp,
+    };
+
+    let actual_event: InitializedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_admin_changed_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 100000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Transfer admin privileges
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "admin_changed_event"));
+
+    // Verify event data
+    let expected_event = AdminChangedEvent {
+        old_admin: admin,
+        new_admin,
+        timestamp,
+    };
+
+    let actual_event: AdminChangedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_pause_state_changed_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 110000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Pause contract with a reason
+    let reason = String::from_str(&env, "Investigating token incident");
+    client.set_paused(&true, &reason);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "pause_state_changed_event"));
+
+    // Verify event data
+    let expected_event = PauseStateChangedEvent {
+        paused: true,
+        actor: admin,
+        reason,
+        timestamp,
+    };
+
+    let actual_event: PauseStateChangedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_token_updated_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 120000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Point the contract at a new token
+    let new_token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.update_usdc_token(&new_token);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "token_updated_event"));
+
+    // Verify event data
+    let expected_event = TokenUpdatedEvent {
+        old_token: usdc_address,
+        new_token,
+        admin,
+        timestamp,
+    };
+
+    let actual_event: TokenUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_dispute_timeout_updated_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 130000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Update dispute timeout
+    client.set_dispute_timeout(&TestConfig::ONE_WEEK, &DisputeTimeoutPolicy::SplitEvenly);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(
+        topic_sym,
+        Symbol::new(&env, "dispute_timeout_updated_event")
+    );
+
+    // Verify event data
+    let expected_event = DisputeTimeoutUpdatedEvent {
+        timeout: TestConfig::ONE_WEEK,
+        policy: DisputeTimeoutPolicy::SplitEvenly,
+        admin,
+        timestamp,
+    };
+
+    let actual_event: DisputeTimeoutUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_arbitration_fee_updated_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 140000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Update arbitration fee
+    client.set_arbitration_fee(&250);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(
+        topic_sym,
+        Symbol::new(&env, "arbitration_fee_updated_event")
+    );
+
+    // Verify event data
+    let expected_event = ArbitrationFeeUpdatedEvent {
+        fee_basis_points: 250,
+        admin,
+        timestamp,
+    };
+
+    let actual_event: ArbitrationFeeUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn test_config_updated_event() {
+    let (env, admin, usdc_address, _, _, contract_id, client) = create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Set ledger timestamp
+    let timestamp = 150000;
+    env.ledger().set_timestamp(timestamp);
+
+    // Update validation limits
+    let mut config = client.get_config();
+    config.task_id_max_length = 36;
+    client.update_config(&config);
+
+    // Get all events and filter for contract events
+    let events = env.events().all();
+    let contract_events: std::vec::Vec<_> = events.iter().filter(|e| e.0 == contract_id).collect();
+
+    let event = contract_events.last().unwrap();
+
+    // Verify event topic (event name)
+    let topics = &event.1;
+    let topic_val = topics.iter().next().unwrap();
+    let topic_sym: Symbol = topic_val.try_into_val(&env).unwrap();
+    assert_eq!(topic_sym, Symbol::new(&env, "config_updated_event"));
+
+    // Verify event data
+    let expected_event = ConfigUpdatedEvent {
+        config,
+        admin,
+        timestamp,
+    };
+
+    let actual_event: ConfigUpdatedEvent = event.2.try_into_val(&env).unwrap();
+    assert_eq!(actual_event, expected_event);
+}
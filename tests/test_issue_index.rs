@@ -0,0 +1,146 @@
+use devasign_task_escrow::{DisputeResolution, Error, TaskStatus};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+#[test]
+fn test_issue_url_is_stored_in_canonical_form() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "canonical", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+
+    // Scheme, host, owner and repository casing, `www.` and a trailing slash
+    // are normalized away
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &String::from_str(
+            &env,
+            "HTTP://WWW.GitHub.com/DevAsign/Soroban-Contract/issues/42/",
+        ),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    let canonical = String::from_str(
+        &env,
+        "https://github.com/devasign/soroban-contract/issues/42",
+    );
+    assert_eq!(client.get_escrow(&task_id).issue_url, canonical);
+    assert_eq!(client.get_escrow_by_issue(&canonical).task_id, task_id);
+}
+
+#[test]
+fn test_duplicate_issue_rejected_while_active() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let other_creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "duplicate_issue", 1);
+    let duplicate_id = TestValidation::generate_task_id(&env, "duplicate_issue", 2);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    usdc_token.mint(&other_creator, &TestConfig::MEDIUM_AMOUNT);
+
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    // Another spelling of the same issue, by any creator, is rejected while the
+    // first escrow is open, in progress or disputed
+    let spelling = String::from_str(&env, "https://www.github.com/Owner/Repo/issues/1");
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    for step in 0..3 {
+        match step {
+            1 => client.assign_contributor(&task_id, &contributor),
+            2 => client.dispute_task(&creator, &task_id, &reason),
+            _ => {}
+        }
+        let result = client.try_create_escrow(
+            &other_creator,
+            &duplicate_id,
+            &spelling,
+            &TestConfig::MEDIUM_AMOUNT,
+        );
+        assert_eq!(result.unwrap_err().unwrap(), Error::IssueAlreadyEscrowed);
+    }
+
+    // The rejected escrow locked no funds
+    assert!(client.try_get_escrow(&duplicate_id).is_err());
+    assert_eq!(
+        client.get_usdc_balance(&other_creator),
+        TestConfig::MEDIUM_AMOUNT
+    );
+
+    // Once the dispute is resolved the issue can be funded again
+    client.resolve_dispute(&task_id, &DisputeResolution::RefundCreator);
+    client.create_escrow(
+        &other_creator,
+        &duplicate_id,
+        &spelling,
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    let escrow = client.get_escrow_by_issue(&TestValidation::dummy_issue_url(&env));
+    assert_eq!(escrow.task_id, duplicate_id);
+    assert_eq!(escrow.creator, other_creator);
+}
+
+#[test]
+fn test_issue_can_be_funded_again_after_refund() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "refund_issue", 1);
+    let new_task_id = TestValidation::generate_task_id(&env, "refund_issue", 2);
+    let issue_url = TestValidation::dummy_issue_url(&env);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+
+    client.create_escrow(&creator, &task_id, &issue_url, &TestConfig::MEDIUM_AMOUNT);
+    client.refund(&task_id);
+
+    // The refunded escrow stays readable by task ID and the issue points at
+    // the new one
+    client.create_escrow(
+        &creator,
+        &new_task_id,
+        &issue_url,
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(client.get_escrow(&task_id).status, TaskStatus::Cancelled);
+    assert_eq!(client.get_escrow_by_issue(&issue_url).task_id, new_task_id);
+}
+
+#[test]
+fn test_get_escrow_by_issue_errors() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let result = client.try_get_escrow_by_issue(&TestValidation::dummy_issue_url(&env));
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+
+    let result = client.try_get_escrow_by_issue(&String::from_str(&env, "not a url"));
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidIssueUrl);
+}
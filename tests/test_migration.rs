@@ -0,0 +1,554 @@
+use devasign_task_escrow::{
+    DataKey, Difficulty, Error, Forge, IssueRef, LegacyTaskEscrow, TaskEscrow, TaskEscrowV5,
+    TaskEscrowV6, TaskStatus,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, vec, Address, Env, Map, String, Symbol, Val,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+/// All-zero placeholder written by older versions for unassigned tasks
+fn null_address(env: &Env) -> Address {
+    Address::from_str(
+        env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    )
+}
+
+/// Store an escrow in the pre-migration layout and lock its bounty
+fn store_legacy_escrow(
+    env: &Env,
+    contract_id: &Address,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    task_id: &String,
+    creator: &Address,
+    contributor: Option<&Address>,
+) {
+    let legacy = LegacyTaskEscrow {
+        task_id: task_id.clone(),
+        issue_url: TestValidation::dummy_issue_url(env),
+        creator: creator.clone(),
+        contributor: contributor.cloned().unwrap_or_else(|| null_address(env)),
+        has_contributor: contributor.is_some(),
+        bounty_amount: TestConfig::MEDIUM_AMOUNT,
+        status: if contributor.is_some() {
+            TaskStatus::InProgress
+        } else {
+            TaskStatus::Open
+        },
+        created_at: env.ledger().timestamp(),
+        completed_at: 0,
+        disputed_at: 0,
+    };
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &legacy);
+    });
+    usdc_token.mint(contract_id, &TestConfig::MEDIUM_AMOUNT);
+}
+
+/// Store an escrow in the version 5 layout, without issue metadata
+fn store_v5_escrow(
+    env: &Env,
+    contract_id: &Address,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    task_id: &String,
+    issue_url: &str,
+) {
+    let escrow = TaskEscrowV5 {
+        task_id: task_id.clone(),
+        issue_url: String::from_str(env, issue_url),
+        creator: Address::generate(env),
+        contributor: None,
+        bounty_amount: TestConfig::MEDIUM_AMOUNT,
+        status: TaskStatus::Open,
+        created_at: env.ledger().timestamp(),
+        completed_at: None,
+        disputed_at: None,
+    };
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+    });
+    usdc_token.mint(contract_id, &TestConfig::MEDIUM_AMOUNT);
+}
+
+/// Store an escrow in the version 6 layout, without tags or difficulty
+fn store_v6_escrow(
+    env: &Env,
+    contract_id: &Address,
+    usdc_token: &soroban_sdk::token::StellarAssetClient,
+    task_id: &String,
+    creator: &Address,
+) {
+    let escrow = TaskEscrowV6 {
+        task_id: task_id.clone(),
+        issue_url: TestValidation::dummy_issue_url(env),
+        creator: creator.clone(),
+        contributor: None,
+        bounty_amount: TestConfig::MEDIUM_AMOUNT,
+        status: TaskStatus::Open,
+        created_at: env.ledger().timestamp(),
+        completed_at: None,
+        disputed_at: None,
+        issue: IssueRef {
+            forge: Forge::GitHub,
+            owner: String::from_str(env, "owner"),
+            repo: String::from_str(env, "repo"),
+            number: 1,
+            labels_hash: None,
+        },
+    };
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TaskEscrow(task_id.clone()), &escrow);
+    });
+    usdc_token.mint(contract_id, &TestConfig::MEDIUM_AMOUNT);
+}
+
+/// Whether the stored entry lacks tags
+fn is_v6(env: &Env, contract_id: &Address, task_id: &String) -> bool {
+    env.as_contract(contract_id, || {
+        let stored: Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TaskEscrow(task_id.clone()))
+            .unwrap();
+        !stored.contains_key(Symbol::new(env, "tags"))
+    })
+}
+
+/// Whether the stored entry lacks issue metadata
+fn is_v5(env: &Env, contract_id: &Address, task_id: &String) -> bool {
+    env.as_contract(contract_id, || {
+        let stored: Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TaskEscrow(task_id.clone()))
+            .unwrap();
+        !stored.contains_key(Symbol::new(env, "issue"))
+    })
+}
+
+/// Whether the stored entry still uses the legacy layout
+fn is_legacy(env: &Env, contract_id: &Address, task_id: &String) -> bool {
+    env.as_contract(contract_id, || {
+        let stored: Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TaskEscrow(task_id.clone()))
+            .unwrap();
+        stored.contains_key(Symbol::new(env, "has_contributor"))
+    })
+}
+
+/// Stored value, decoded with the current layout
+fn stored_escrow(env: &Env, contract_id: &Address, task_id: &String) -> TaskEscrow {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TaskEscrow(task_id.clone()))
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_get_escrow_reads_legacy_layout() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let open_id = TestValidation::generate_task_id(&env, "legacy_open", 1);
+    let assigned_id = TestValidation::generate_task_id(&env, "legacy_assigned", 1);
+    store_legacy_escrow(&env, &contract_id, &usdc_token, &open_id, &creator, None);
+    store_legacy_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &assigned_id,
+        &creator,
+        Some(&contributor),
+    );
+
+    let escrow = client.get_escrow(&open_id);
+    assert_eq!(escrow.status, TaskStatus::Open);
+    assert_eq!(escrow.contributor, None);
+    assert_eq!(escrow.completed_at, None);
+    assert_eq!(escrow.disputed_at, None);
+
+    let escrow = client.get_escrow(&assigned_id);
+    assert_eq!(escrow.status, TaskStatus::InProgress);
+    assert_eq!(escrow.contributor, Some(contributor));
+}
+
+#[test]
+fn test_legacy_escrows_complete_their_lifecycle() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let open_id = TestValidation::generate_task_id(&env, "legacy_open", 2);
+    let assigned_id = TestValidation::generate_task_id(&env, "legacy_assigned", 2);
+    store_legacy_escrow(&env, &contract_id, &usdc_token, &open_id, &creator, None);
+    store_legacy_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &assigned_id,
+        &creator,
+        Some(&contributor),
+    );
+
+    // The placeholder is not mistaken for an assigned contributor
+    client.assign_contributor(&open_id, &contributor);
+    assert_eq!(
+        client.get_escrow(&open_id).contributor,
+        Some(contributor.clone())
+    );
+
+    client.approve_completion(&assigned_id);
+    let escrow = client.get_escrow(&assigned_id);
+    assert_eq!(escrow.status, TaskStatus::Completed);
+    assert!(escrow.completed_at.is_some());
+    assert_eq!(
+        usdc_token_client.balance(&contributor),
+        TestConfig::MEDIUM_AMOUNT
+    );
+
+    // Every write goes out in the current layout
+    assert!(!is_legacy(&env, &contract_id, &open_id));
+    assert!(!is_legacy(&env, &contract_id, &assigned_id));
+}
+
+#[test]
+fn test_migrate_escrows() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let open_id = TestValidation::generate_task_id(&env, "migrate_open", 1);
+    let assigned_id = TestValidation::generate_task_id(&env, "migrate_assigned", 1);
+    let current_id = TestValidation::generate_task_id(&env, "migrate_current", 1);
+    store_legacy_escrow(&env, &contract_id, &usdc_token, &open_id, &creator, None);
+    store_legacy_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &assigned_id,
+        &creator,
+        Some(&contributor),
+    );
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &current_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    let before = client.get_escrow(&assigned_id);
+
+    let task_ids = vec![&env, open_id.clone(), assigned_id.clone(), current_id];
+    assert_eq!(client.migrate_escrows(&task_ids), 2);
+
+    assert!(!is_legacy(&env, &contract_id, &open_id));
+    let stored = stored_escrow(&env, &contract_id, &assigned_id);
+    assert_eq!(stored, before);
+    assert_eq!(stored.contributor, Some(contributor));
+    assert_eq!(
+        stored_escrow(&env, &contract_id, &open_id).contributor,
+        None
+    );
+
+    // Running the migration again is a no-op
+    assert_eq!(client.migrate_escrows(&task_ids), 0);
+}
+
+#[test]
+fn test_migrate_escrows_unknown_task() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let task_ids = vec![&env, TestValidation::generate_task_id(&env, "missing", 1)];
+    let result = client.try_migrate_escrows(&task_ids);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+}
+
+#[test]
+fn test_migrate_escrows_requires_admin() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let task_id = TestValidation::generate_task_id(&env, "migrate_auth", 1);
+    store_legacy_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &task_id,
+        &Address::generate(&env),
+        None,
+    );
+
+    // Clear mocked auths so the admin signature is missing
+    env.mock_auths(&[]);
+    let result = client.try_migrate_escrows(&vec![&env, task_id.clone()]);
+    assert!(result.is_err());
+    assert!(is_legacy(&env, &contract_id, &task_id));
+}
+
+#[test]
+fn test_v5_escrows_gain_issue_metadata() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let parsed_id = TestValidation::generate_task_id(&env, "v5_parsed", 1);
+    let unparsed_id = TestValidation::generate_task_id(&env, "v5_unparsed", 1);
+    store_v5_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &parsed_id,
+        "https://github.com/DevAsign/Contracts/issues/12",
+    );
+    store_v5_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &unparsed_id,
+        "https://github.com/devasign/contracts/pull/3",
+    );
+
+    // Metadata is parsed from the stored URL, which is left as it was
+    let escrow = client.get_escrow(&parsed_id);
+    assert_eq!(escrow.issue.forge, Forge::GitHub);
+    assert_eq!(escrow.issue.owner, String::from_str(&env, "devasign"));
+    assert_eq!(escrow.issue.repo, String::from_str(&env, "contracts"));
+    assert_eq!(escrow.issue.number, 12);
+    assert_eq!(
+        escrow.issue_url,
+        String::from_str(&env, "https://github.com/DevAsign/Contracts/issues/12")
+    );
+
+    // URLs stored before validation may not name an issue
+    let escrow = client.get_escrow(&unparsed_id);
+    assert_eq!(escrow.issue.forge, Forge::Unknown);
+    assert_eq!(escrow.issue.number, 0);
+
+    let task_ids = vec![&env, parsed_id.clone(), unparsed_id.clone()];
+    assert_eq!(client.migrate_escrows(&task_ids), 2);
+    assert!(!is_v5(&env, &contract_id, &parsed_id));
+    assert!(!is_v5(&env, &contract_id, &unparsed_id));
+    assert_eq!(
+        stored_escrow(&env, &contract_id, &parsed_id).issue.number,
+        12
+    );
+    assert_eq!(client.migrate_escrows(&task_ids), 0);
+
+    // Migrated escrows store the canonical URL and join the repository index
+    let canonical_url = String::from_str(&env, "https://github.com/devasign/contracts/issues/12");
+    assert_eq!(client.get_escrow(&parsed_id).issue_url, canonical_url);
+    assert_eq!(
+        client.get_escrow_by_issue(&canonical_url).task_id,
+        parsed_id
+    );
+    let listed = client.list_escrows_by_repo(
+        &String::from_str(&env, "devasign"),
+        &String::from_str(&env, "contracts"),
+        &0,
+    );
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().task_id, parsed_id);
+}
+
+#[test]
+fn test_migrate_escrows_backfills_issue_index() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let legacy_id = TestValidation::generate_task_id(&env, "backfill_legacy", 1);
+    let resolved_id = TestValidation::generate_task_id(&env, "backfill_resolved", 1);
+    let duplicate_id = TestValidation::generate_task_id(&env, "backfill_duplicate", 1);
+    let issue_url = TestValidation::issue_url(&env, 7);
+    store_v5_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &legacy_id,
+        "https://github.com/owner/repo/issues/7",
+    );
+
+    // A completed escrow already indexed for the issue does not hold it
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+    client.create_escrow(
+        &creator,
+        &resolved_id,
+        &issue_url,
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.refund(&resolved_id);
+
+    client.migrate_escrows(&vec![&env, legacy_id.clone()]);
+
+    // The active legacy escrow now holds its issue
+    assert_eq!(client.get_escrow_by_issue(&issue_url).task_id, legacy_id);
+    let result = client.try_create_escrow(
+        &creator,
+        &duplicate_id,
+        &issue_url,
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::IssueAlreadyEscrowed);
+
+    // Both escrows are listed for the repository
+    let listed = client.list_escrows_by_repo(
+        &String::from_str(&env, "owner"),
+        &String::from_str(&env, "repo"),
+        &0,
+    );
+    assert_eq!(listed.len(), 2);
+}
+
+#[test]
+fn test_writes_index_legacy_escrows_before_migration() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let legacy_id = TestValidation::generate_task_id(&env, "written_legacy", 1);
+    let duplicate_id = TestValidation::generate_task_id(&env, "written_duplicate", 1);
+    store_v5_escrow(
+        &env,
+        &contract_id,
+        &usdc_token,
+        &legacy_id,
+        "https://github.com/DevAsign/Contracts/issues/12",
+    );
+
+    // Assigning stores the escrow in the current layout and indexes it
+    client.assign_contributor(&legacy_id, &contributor);
+    assert!(!is_v5(&env, &contract_id, &legacy_id));
+    let canonical_url = String::from_str(&env, "https://github.com/devasign/contracts/issues/12");
+    assert_eq!(
+        stored_escrow(&env, &contract_id, &legacy_id).issue_url,
+        canonical_url
+    );
+
+    // There is nothing left to migrate
+    assert_eq!(client.migrate_escrows(&vec![&env, legacy_id.clone()]), 0);
+
+    // The issue cannot be escrowed twice and the repository lists the task
+    assert_eq!(
+        client.get_escrow_by_issue(&canonical_url).task_id,
+        legacy_id
+    );
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    let result = client.try_create_escrow(
+        &creator,
+        &duplicate_id,
+        &canonical_url,
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::IssueAlreadyEscrowed);
+    let listed = client.list_escrows_by_repo(
+        &String::from_str(&env, "devasign"),
+        &String::from_str(&env, "contracts"),
+        &0,
+    );
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().task_id, legacy_id);
+}
+
+#[test]
+fn test_escrows_with_older_task_ids_stay_reachable() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    // Earlier versions accepted any 25-character task ID
+    let approved_id = String::from_str(&env, "legacy.task:0 00000000001");
+    let refunded_id = String::from_str(&env, "legacy-tâsk-0000000000001");
+    for (task_id, number) in [(&approved_id, 1), (&refunded_id, 2)] {
+        store_v5_escrow(
+            &env,
+            &contract_id,
+            &usdc_token,
+            task_id,
+            &std::format!("https://github.com/owner/repo/issues/{}", number),
+        );
+    }
+
+    let contributor = Address::generate(&env);
+    client.assign_contributor(&approved_id, &contributor);
+    client.approve_completion(&approved_id);
+    assert_eq!(
+        client.get_escrow(&approved_id).status,
+        TaskStatus::Completed
+    );
+    assert_eq!(
+        usdc_token_client.balance(&contributor),
+        TestConfig::MEDIUM_AMOUNT
+    );
+
+    client.refund(&refunded_id);
+    assert_eq!(
+        client.get_escrow(&refunded_id).status,
+        TaskStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_v6_escrows_become_untagged() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let migrated_id = TestValidation::generate_task_id(&env, "v6_migrated", 1);
+    let tagged_id = TestValidation::generate_task_id(&env, "v6_tagged", 1);
+    store_v6_escrow(&env, &contract_id, &usdc_token, &migrated_id, &creator);
+    store_v6_escrow(&env, &contract_id, &usdc_token, &tagged_id, &creator);
+
+    let escrow = client.get_escrow(&migrated_id);
+    assert!(escrow.tags.is_empty());
+    assert_eq!(escrow.difficulty, Difficulty::Unrated);
+    assert_eq!(escrow.issue.number, 1);
+
+    // Editing the tags of a version 6 escrow stores it in the current layout
+    let tags = vec![&env, symbol_short!("rust")];
+    client.update_task_tags(&creator, &tagged_id, &tags, &Difficulty::Beginner);
+    assert!(!is_v6(&env, &contract_id, &tagged_id));
+    assert_eq!(stored_escrow(&env, &contract_id, &tagged_id).tags, tags);
+    assert_eq!(
+        client.list_escrows_by_tag(&symbol_short!("rust"), &0).len(),
+        1
+    );
+
+    let task_ids = vec![&env, migrated_id.clone(), tagged_id.clone()];
+    assert_eq!(client.migrate_escrows(&task_ids), 1);
+    assert!(!is_v6(&env, &contract_id, &migrated_id));
+    assert_eq!(
+        stored_escrow(&env, &contract_id, &migrated_id).difficulty,
+        Difficulty::Unrated
+    );
+    assert_eq!(client.migrate_escrows(&task_ids), 0);
+}
@@ -9,19 +9,17 @@ use test_setup::create_test_env;
 
 #[test]
 fn test_pause_unpause_by_admin() {
-    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
         create_test_env();
 
     // Initialize contract
     client.initialize(&admin, &usdc_address);
 
     // Pause contract
-    let result = client.set_paused(&true);
-    assert!(result == ());
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
 
     // Unpause contract
-    let result = client.set_paused(&false);
-    assert!(result == ());
+    client.set_paused(&false, &TestValidation::pause_reason(&env));
 }
 
 #[test]
@@ -36,7 +34,9 @@ fn test_pause_unauthorized() {
     env.mock_all_auths_allowing_non_root_auth();
 
     // Try to set pause from unauthorized address
-    let result = client.mock_auths(&[]).try_set_paused(&mut true);
+    let result = client
+        .mock_auths(&[])
+        .try_set_paused(&true, &TestValidation::pause_reason(&env));
     assert!(result.is_err());
 }
 
@@ -57,7 +57,7 @@ fn test_operations_fail_when_paused() {
     usdc_token.mint(&creator, &bounty_amount);
 
     // Pause contract
-    client.set_paused(&true);
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
 
     // 1. Try create_escrow
     let result = client.try_create_escrow(
@@ -70,7 +70,7 @@ fn test_operations_fail_when_paused() {
     assert_eq!(result.unwrap_err().unwrap(), Error::ContractPaused);
 
     // Unpause to create setup
-    client.set_paused(&false);
+    client.set_paused(&false, &TestValidation::pause_reason(&env));
     client.create_escrow(
         &creator,
         &task_id,
@@ -79,7 +79,7 @@ fn test_operations_fail_when_paused() {
     );
 
     // Pause again
-    client.set_paused(&true);
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
 
     // 2. Try increase_bounty
     let result = client.try_increase_bounty(&creator, &task_id, &TestConfig::SMALL_AMOUNT);
@@ -92,11 +92,11 @@ fn test_operations_fail_when_paused() {
     assert_eq!(result.unwrap_err().unwrap(), Error::ContractPaused);
 
     // Unpause to assign
-    client.set_paused(&false);
+    client.set_paused(&false, &TestValidation::pause_reason(&env));
     client.assign_contributor(&task_id, &contributor);
 
     // Pause again
-    client.set_paused(&true);
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
 
     // 4. Try approve_completion
     let result = client.try_approve_completion(&task_id);
@@ -110,16 +110,16 @@ fn test_operations_fail_when_paused() {
     assert_eq!(result.unwrap_err().unwrap(), Error::ContractPaused);
 
     // 6. Try refund (need a new task for this as regular flow has contributor)
-    client.set_paused(&false);
+    client.set_paused(&false, &TestValidation::pause_reason(&env));
     let task_id_2 = TestValidation::generate_task_id(&env, "pause_refund", 2);
     usdc_token.mint(&creator, &bounty_amount);
     client.create_escrow(
         &creator,
         &task_id_2,
-        &TestValidation::dummy_issue_url(&env),
+        &TestValidation::issue_url(&env, 2),
         &bounty_amount,
     );
-    client.set_paused(&true);
+    client.set_paused(&true, &TestValidation::pause_reason(&env));
 
     let result = client.try_refund(&task_id_2);
     assert!(result.is_err());
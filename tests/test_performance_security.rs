@@ -32,13 +32,13 @@ fn test_gas_usage_escrow_creation() {
         usdc_token.mint(&creator, &bounty_amount);
 
         // Reset ledger for consistent measurement
-        env.ledger().set_sequence_number(100 + i as u32);
+        env.ledger().set_sequence_number(100 + i);
 
         let initial_gas = env.cost_estimate().budget().cpu_instruction_cost();
         client.create_escrow(
             &creator,
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i + 1),
             &bounty_amount,
         );
         let final_gas = env.cost_estimate().budget().cpu_instruction_cost();
@@ -110,7 +110,7 @@ fn test_performance_large_amounts() {
 
     let creator = Address::generate(&env);
     let contributor = Address::generate(&env);
-    let large_amounts = vec![
+    let large_amounts = [
         TestConfig::LARGE_AMOUNT,
         TestConfig::HUGE_AMOUNT,
         TestConfig::MAX_VALID_AMOUNT,
@@ -179,7 +179,7 @@ fn test_performance_concurrent_operations() {
         client.create_escrow(
             &creators[i as usize],
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i + 1),
             &bounty_amount,
         );
         client.assign_contributor(&task_id, &contributors[i as usize]);
@@ -214,7 +214,7 @@ fn test_memory_efficiency_large_scale() {
         client.create_escrow(
             &creator,
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i + 1),
             &bounty_amount,
         );
 
@@ -327,7 +327,7 @@ fn test_reentrancy_protection() {
     // Try to approve again (should fail due to status change)
     let result = client.try_approve_completion(&task_id);
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
 
     // Verify final state is correct
     let escrow = client.get_escrow(&task_id);
@@ -356,12 +356,12 @@ fn test_input_validation_security() {
     usdc_token.mint(&creator, &bounty_amount);
     let result = client.try_create_escrow(
         &creator,
-        &malicious_task_id,
+        malicious_task_id,
         &TestValidation::dummy_issue_url(&env),
         &bounty_amount,
     );
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskIdTooLong);
 
     // 2. Extremely long dispute reason
     let valid_task_id = TestValidation::generate_task_id(&env, "security", 1);
@@ -376,7 +376,7 @@ fn test_input_validation_security() {
     client.assign_contributor(&valid_task_id, &contributor);
 
     let malicious_reason = &String::from_str(&env, &"a".repeat(1000));
-    let result = client.try_dispute_task(&creator, &valid_task_id, &malicious_reason);
+    let result = client.try_dispute_task(&creator, &valid_task_id, malicious_reason);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDisputeReason);
 
@@ -415,7 +415,7 @@ fn test_state_consistency_under_stress() {
         client.create_escrow(
             &creator,
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i + 1),
             &bounty_amount,
         );
 
@@ -544,13 +544,12 @@ fn test_edge_case_boundary_conditions() {
     let min_task_id = TestValidation::generate_task_id(&env, "min_amount", 1);
     usdc_token.mint(&creator, &TestConfig::MIN_VALID_AMOUNT);
 
-    let result = client.create_escrow(
+    client.create_escrow(
         &creator,
         &min_task_id,
         &TestValidation::dummy_issue_url(&env),
         &TestConfig::MIN_VALID_AMOUNT,
     );
-    assert!(result == ());
 
     // Complete workflow with minimum amount
     client.assign_contributor(&min_task_id, &contributor);
@@ -563,13 +562,12 @@ fn test_edge_case_boundary_conditions() {
     let max_task_id = TestValidation::generate_task_id(&env, "max_amount", 1);
     usdc_token.mint(&creator, &TestConfig::MAX_VALID_AMOUNT);
 
-    let result = client.create_escrow(
+    client.create_escrow(
         &creator,
         &max_task_id,
         &TestValidation::dummy_issue_url(&env),
         &TestConfig::MAX_VALID_AMOUNT,
     );
-    assert!(result == ());
 
     // Verify escrow was created with max amount
     let escrow = client.get_escrow(&max_task_id);
@@ -583,9 +581,8 @@ fn test_edge_case_boundary_conditions() {
 
     // Test 1% partial payment (very small percentage)
     let tiny_partial = TestConfig::MAX_VALID_AMOUNT / 100;
-    let result = client.resolve_dispute(
+    client.resolve_dispute(
         &max_task_id,
         &DisputeResolution::PartialPayment(tiny_partial),
     );
-    assert!(result == ());
 }
@@ -16,7 +16,7 @@ fn setup_task_for_refund(
     task_prefix: &str,
     assign_contributor: bool,
 ) -> (String, i128) {
-    let task_id = TestValidation::generate_task_id(&env, task_prefix, 1);
+    let task_id = TestValidation::generate_task_id(env, task_prefix, 1);
     let bounty_amount = TestConfig::MEDIUM_AMOUNT;
 
     // Fund creator and create escrow
@@ -24,7 +24,7 @@ fn setup_task_for_refund(
     client.create_escrow(
         creator,
         &task_id,
-        &TestValidation::dummy_issue_url(&env),
+        &TestValidation::dummy_issue_url(env),
         &bounty_amount,
     );
 
@@ -59,8 +59,7 @@ fn test_refund_success_no_contributor() {
     let initial_balance = usdc_token_client.balance(&creator);
 
     // Process refund
-    let result = client.refund(&task_id);
-    assert!(result == ());
+    client.refund(&task_id);
 
     // Verify refund
     let final_balance = usdc_token_client.balance(&creator);
@@ -94,7 +93,7 @@ fn test_refund_success_with_contributor() {
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err().unwrap(),
-        Error::ContributorAlreadyAssigned
+        Error::CannotRefundWithContributor
     );
 }
 
@@ -125,8 +124,7 @@ fn test_refund_multiple_amounts() {
         let initial_balance = usdc_token_client.balance(&creator);
 
         // Process refund
-        let result = client.refund(&task_id);
-        assert!(result == ());
+        client.refund(&task_id);
 
         // Verify refund
         let final_balance = usdc_token_client.balance(&creator);
@@ -161,8 +159,7 @@ fn test_refund_boundary_amounts() {
         let initial_balance = usdc_token_client.balance(&creator);
 
         // Process refund
-        let result = client.refund(&task_id);
-        assert!(result == ());
+        client.refund(&task_id);
 
         // Verify refund
         let final_balance = usdc_token_client.balance(&creator);
@@ -213,20 +210,20 @@ fn test_refund_invalid_task_id() {
     client.initialize(&admin, &usdc_address);
 
     // Test empty task ID
-    let result = client.try_refund(&String::from_str(&env, &"".to_string()));
+    let result = client.try_refund(&String::from_str(&env, ""));
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), Error::EmptyTaskId);
 
-    // Test too short task ID
-    let result = client.try_refund(&String::from_str(&env, &"ab".to_string()));
+    // Length limits only apply on creation, so a short ID is just unknown
+    let result = client.try_refund(&String::from_str(&env, "ab"));
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 
-    // Test too long task ID
+    // Likewise for a long ID
     let long_id = "a".repeat(101);
     let result = client.try_refund(&String::from_str(&env, &long_id));
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskId);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
 }
 
 #[test]
@@ -248,13 +245,12 @@ fn test_refund_task_already_refunded() {
     );
 
     // Process first refund
-    let result = client.refund(&task_id);
-    assert!(result == ());
+    client.refund(&task_id);
 
     // Try to refund again
     let result = client.try_refund(&task_id);
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
 }
 
 #[test]
@@ -293,8 +289,7 @@ fn test_refund_contract_balance_verification() {
     assert_eq!(contract_balance_before, bounty_amount);
 
     // Process refund
-    let result = client.refund(&task_id);
-    assert!(result == ());
+    client.refund(&task_id);
 
     // Verify contract balance after refund
     let contract_balance_after = client.get_contract_usdc_balance();
@@ -321,7 +316,7 @@ fn test_refund_multiple_tasks_same_creator() {
         client.create_escrow(
             &creator,
             &task_id,
-            &TestValidation::dummy_issue_url(&env),
+            &TestValidation::issue_url(&env, i + 1),
             &bounty_amount,
         );
         task_ids.push(task_id);
@@ -332,8 +327,7 @@ fn test_refund_multiple_tasks_same_creator() {
 
     // Refund all tasks
     for task_id in &task_ids {
-        let result = client.refund(task_id);
-        assert!(result == ());
+        client.refund(task_id);
     }
 
     // Verify total refund
@@ -364,8 +358,7 @@ fn test_refund_timestamp_verification() {
     let escrow_before = client.get_escrow(&task_id);
 
     // Process refund
-    let result = client.refund(&task_id);
-    assert!(result == ());
+    client.refund(&task_id);
 
     // Verify escrow after refund
     let escrow_after = client.get_escrow(&task_id);
@@ -0,0 +1,235 @@
+use devasign_task_escrow::{Difficulty, Error, EscrowOptions, Forge, IssueRef, TaskStatus};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, String, Vec};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+#[test]
+fn test_issue_metadata_parsed_at_creation() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "metadata", 1);
+    let labelled_id = TestValidation::generate_task_id(&env, "metadata", 2);
+    let labels_hash = BytesN::from_array(&env, &[7; 32]);
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &String::from_str(
+            &env,
+            "https://github.com/DevAsign/Soroban-Contract/issues/4242",
+        ),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    assert_eq!(
+        client.get_escrow(&task_id).issue,
+        IssueRef {
+            forge: Forge::GitHub,
+            owner: String::from_str(&env, "devasign"),
+            repo: String::from_str(&env, "soroban-contract"),
+            number: 4242,
+            labels_hash: None,
+        }
+    );
+
+    // The labels hash is supplied by the creator
+    client.create_escrow_with_options(
+        &creator,
+        &labelled_id,
+        &TestValidation::repo_url(&env, "soroban-contract", 7),
+        &TestConfig::MEDIUM_AMOUNT,
+        &EscrowOptions {
+            labels_hash: Some(labels_hash.clone()),
+            tags: Vec::new(&env),
+            difficulty: Difficulty::Unrated,
+        },
+    );
+    let issue = client.get_escrow(&labelled_id).issue;
+    assert_eq!(issue.number, 7);
+    assert_eq!(issue.labels_hash, Some(labels_hash));
+}
+
+#[test]
+fn test_create_escrow_with_options_validates_like_create_escrow() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "options", 1);
+    let options = EscrowOptions {
+        labels_hash: Some(BytesN::from_array(&env, &[1; 32])),
+        tags: Vec::new(&env),
+        difficulty: Difficulty::Unrated,
+    };
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+
+    let result = client.try_create_escrow_with_options(
+        &creator,
+        &task_id,
+        &String::from_str(&env, "https://gitlab.com/owner/repo/issues/1"),
+        &TestConfig::MEDIUM_AMOUNT,
+        &options,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidIssueUrl);
+
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+        &options,
+    );
+    let result = client.try_create_escrow_with_options(
+        &creator,
+        &TestValidation::generate_task_id(&env, "options", 2),
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+        &options,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::IssueAlreadyEscrowed);
+}
+
+#[test]
+fn test_list_escrows_by_repo_pages() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    usdc_token.mint(&creator, &(TestConfig::SMALL_AMOUNT * 30));
+
+    // 22 escrows in one repository and one in another
+    for index in 1..=22 {
+        client.create_escrow(
+            &creator,
+            &TestValidation::generate_task_id(&env, "repo_page", index),
+            &TestValidation::repo_url(&env, "contracts", index),
+            &TestConfig::SMALL_AMOUNT,
+        );
+    }
+    client.create_escrow(
+        &creator,
+        &TestValidation::generate_task_id(&env, "repo_other", 1),
+        &TestValidation::repo_url(&env, "website", 1),
+        &TestConfig::SMALL_AMOUNT,
+    );
+
+    // Names are matched case-insensitively
+    let owner = String::from_str(&env, "DevAsign");
+    let repo = String::from_str(&env, "Contracts");
+    let first = client.list_escrows_by_repo(&owner, &repo, &0);
+    assert_eq!(first.len(), 20);
+    assert_eq!(
+        first.get(0).unwrap().task_id,
+        TestValidation::generate_task_id(&env, "repo_page", 1)
+    );
+    let second = client.list_escrows_by_repo(&owner, &repo, &1);
+    assert_eq!(second.len(), 2);
+    assert_eq!(
+        second.get(1).unwrap().task_id,
+        TestValidation::generate_task_id(&env, "repo_page", 22)
+    );
+    assert!(client.list_escrows_by_repo(&owner, &repo, &2).is_empty());
+    assert!(client
+        .list_escrows_by_repo(&owner, &repo, &u32::MAX)
+        .is_empty());
+
+    let website = String::from_str(&env, "website");
+    assert_eq!(client.list_escrows_by_repo(&owner, &website, &0).len(), 1);
+    let unknown = String::from_str(&env, "unknown");
+    assert!(client.list_escrows_by_repo(&owner, &unknown, &0).is_empty());
+}
+
+#[test]
+fn test_list_escrows_by_repo_keeps_resolved_escrows() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "repo_refund", 1);
+    let new_task_id = TestValidation::generate_task_id(&env, "repo_refund", 2);
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+
+    // Funding the same issue again adds a second entry
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.refund(&task_id);
+    client.create_escrow(
+        &creator,
+        &new_task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    let escrows = client.list_escrows_by_repo(
+        &String::from_str(&env, "owner"),
+        &String::from_str(&env, "repo"),
+        &0,
+    );
+    assert_eq!(escrows.len(), 2);
+    assert_eq!(escrows.get(0).unwrap().status, TaskStatus::Cancelled);
+    assert_eq!(escrows.get(1).unwrap().task_id, new_task_id);
+}
+
+#[test]
+fn test_list_escrows_by_repo_rejects_invalid_names() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let valid = "devasign";
+    let long_owner = "o".repeat(40);
+    let long_repo = "r".repeat(101);
+    for (owner, repo) in [
+        ("", valid),
+        (valid, ""),
+        ("dev_asign", valid),
+        ("dev/asign", valid),
+        (valid, "soroban contract"),
+        (long_owner.as_str(), valid),
+        (valid, long_repo.as_str()),
+    ] {
+        let result = client.try_list_escrows_by_repo(
+            &String::from_str(&env, owner),
+            &String::from_str(&env, repo),
+            &0,
+        );
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidRepository);
+    }
+}
+
+#[test]
+fn test_list_escrows_by_repo_not_initialized() {
+    let (env, _admin, _usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    let result = client.try_list_escrows_by_repo(
+        &String::from_str(&env, "owner"),
+        &String::from_str(&env, "repo"),
+        &0,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::ContractNotInitialized);
+}
@@ -0,0 +1,289 @@
+use devasign_task_escrow::{DisputeResolution, DisputeTimeoutPolicy, Error, TaskStatus};
+
+mod scenario_harness;
+mod test_config;
+
+use scenario_harness::*;
+use test_config::{TestConfig, TestScenarios};
+
+const BASIS_POINTS: i128 = 10_000;
+
+fn share(amount: i128, basis_points: u32) -> i128 {
+    amount * basis_points as i128 / BASIS_POINTS
+}
+
+#[test]
+fn test_scenario_happy_path() {
+    let amount = TestConfig::MEDIUM_AMOUNT;
+    let outcome = Scenario::new("happy_path")
+        .actor("alice", amount)
+        .step(create("alice", "task1", amount))
+        .emits(&["escrow_created_event"])
+        .step(assign("task1", "bob"))
+        .emits(&["contributor_assigned_event"])
+        .step(approve("task1"))
+        .fails(approve("task1"), Error::TaskAlreadyResolved)
+        .fails(refund("task1"), Error::TaskAlreadyResolved)
+        .run();
+
+    assert_eq!(outcome.balance("bob"), amount);
+    assert_eq!(outcome.escrow("task1").status, TaskStatus::Completed);
+    outcome.assert_snapshot();
+}
+
+#[test]
+fn test_scenario_dispute_split_with_fee() {
+    let amount = TestConfig::LARGE_AMOUNT;
+    let outcome = Scenario::new("dispute_split_with_fee")
+        .actor("alice", amount)
+        .step(set_fee(300))
+        .step(create("alice", "task1", amount))
+        .step(assign("task1", "bob"))
+        .fails(dispute("mallory", "task1"), Error::OnlyCreatorOrContributor)
+        .step(dispute("bob", "task1"))
+        .emits(&["dispute_initiated_event"])
+        .fails(approve("task1"), Error::InvalidTaskStatus)
+        .step(resolve("task1", DisputeResolution::PercentageSplit(7500)))
+        .step(claim_fees(ADMIN))
+        .emits(&["arbiter_fees_claimed_event"])
+        .fails(claim_fees(ADMIN), Error::NoFeesToClaim)
+        .run();
+
+    let fee = share(amount, 300);
+    assert_eq!(outcome.balance(ADMIN), fee);
+    assert_eq!(outcome.balance("bob"), share(amount - fee, 7500));
+    assert_eq!(outcome.contract_balance(), 0);
+    outcome.assert_snapshot();
+}
+
+#[test]
+fn test_scenario_withdraw_and_redispute() {
+    let amount = TestConfig::SMALL_AMOUNT;
+    let outcome = Scenario::new("withdraw_and_redispute")
+        .actor("alice", amount)
+        .step(create("alice", "task1", amount))
+        .step(assign("task1", "bob"))
+        .step(dispute("alice", "task1"))
+        .fails(withdraw("bob", "task1"), Error::NotDisputingParty)
+        .step(withdraw("alice", "task1"))
+        .emits(&["dispute_withdrawn_event"])
+        .step(advance(TestConfig::ONE_DAY))
+        .step(dispute("bob", "task1"))
+        .step(resolve("task1", DisputeResolution::RefundCreator))
+        .run();
+
+    assert_eq!(outcome.balance("alice"), amount);
+    assert_eq!(outcome.balance("bob"), 0);
+    outcome.assert_snapshot();
+}
+
+#[test]
+fn test_scenario_stale_dispute_split_evenly() {
+    let amount = TestConfig::MEDIUM_AMOUNT;
+    let outcome = Scenario::new("stale_dispute_split_evenly")
+        .actor("alice", amount)
+        .step(set_timeout(
+            TestConfig::ONE_WEEK,
+            DisputeTimeoutPolicy::SplitEvenly,
+        ))
+        .step(create("alice", "task1", amount))
+        .step(assign("task1", "bob"))
+        .step(dispute("alice", "task1"))
+        .step(advance(TestConfig::ONE_WEEK - 1))
+        .fails(resolve_stale("task1"), Error::DisputeNotExpired)
+        .step(advance(1))
+        .step(resolve_stale("task1"))
+        .emits(&["dispute_resolved_event"])
+        .run();
+
+    assert_eq!(outcome.balance("alice"), amount / 2);
+    assert_eq!(outcome.balance("bob"), amount / 2);
+    outcome.assert_snapshot();
+}
+
+#[test]
+fn test_scenario_multi_payout() {
+    let amount = TestConfig::LARGE_AMOUNT;
+    let outcome = Scenario::new("multi_payout")
+        .actor("alice", amount)
+        .step(create("alice", "task1", amount))
+        .step(assign("task1", "bob"))
+        .step(dispute("bob", "task1"))
+        .fails(
+            resolve_multi("task1", &[("bob", amount), ("carol", 1)]),
+            Error::InvalidTokenAmount,
+        )
+        .step(resolve_multi(
+            "task1",
+            &[("bob", amount / 2), ("carol", amount / 4)],
+        ))
+        .run();
+
+    assert_eq!(outcome.balance("bob"), amount / 2);
+    assert_eq!(outcome.balance("carol"), amount / 4);
+    assert_eq!(outcome.balance("alice"), amount / 4);
+    outcome.assert_snapshot();
+}
+
+#[test]
+fn test_scenario_paused_contract() {
+    let amount = TestConfig::SMALL_AMOUNT;
+    let outcome = Scenario::new("paused_contract")
+        .actor("alice", amount * 2)
+        .step(create("alice", "task1", amount))
+        .step(pause())
+        .emits(&["pause_state_changed_event"])
+        .fails(create("alice", "task2", amount), Error::ContractPaused)
+        .fails(assign("task1", "bob"), Error::ContractPaused)
+        .fails(refund("task1"), Error::ContractPaused)
+        .step(unpause())
+        .step(create("alice", "task2", amount))
+        .step(refund("task1"))
+        .run();
+
+    assert_eq!(outcome.balance("alice"), amount);
+    assert_eq!(outcome.escrow("task1").status, TaskStatus::Cancelled);
+    assert_eq!(outcome.escrow("task2").status, TaskStatus::Open);
+    outcome.assert_snapshot();
+}
+
+/// Lifecycle paths of the scenario matrix
+#[derive(Clone, Debug)]
+enum Path {
+    Approve,
+    Refund,
+    Resolve(&'static str, u32),
+    WithdrawThenApprove(&'static str),
+    Stale(DisputeTimeoutPolicy),
+    AdjustThenApprove,
+}
+
+const PATHS: [Path; 14] = [
+    Path::Approve,
+    Path::Refund,
+    Path::Resolve("alice", 10_000),
+    Path::Resolve("alice", 0),
+    Path::Resolve("alice", 2_500),
+    Path::Resolve("bob", 10_000),
+    Path::Resolve("bob", 0),
+    Path::Resolve("bob", 2_500),
+    Path::WithdrawThenApprove("alice"),
+    Path::WithdrawThenApprove("bob"),
+    Path::Stale(DisputeTimeoutPolicy::PayContributor),
+    Path::Stale(DisputeTimeoutPolicy::RefundCreator),
+    Path::Stale(DisputeTimeoutPolicy::SplitEvenly),
+    Path::AdjustThenApprove,
+];
+
+/// Build a scenario for one point of the matrix and the balances it must end with
+fn matrix_scenario(path: Path, amount: i128, fee_bps: u32) -> (Scenario, [i128; 3], TaskStatus) {
+    let scenario = Scenario::new(format!("{path:?}_{amount}_{fee_bps}"))
+        .actor("alice", amount * 2)
+        .step(set_fee(fee_bps))
+        .step(create("alice", "task1", amount));
+    let assigned = |scenario: Scenario| scenario.step(assign("task1", "bob"));
+
+    // Expected balances of alice, bob and the arbiter
+    let fee = share(amount, fee_bps);
+    match path {
+        Path::Approve => (
+            assigned(scenario).step(approve("task1")),
+            [amount, amount, 0],
+            TaskStatus::Completed,
+        ),
+        Path::Refund => (
+            scenario
+                .step(refund("task1"))
+                .fails(assign("task1", "bob"), Error::TaskAlreadyResolved),
+            [amount * 2, 0, 0],
+            TaskStatus::Cancelled,
+        ),
+        Path::Resolve(party, contributor_bps) => {
+            let resolution = match contributor_bps {
+                10_000 => DisputeResolution::PayContributor,
+                0 => DisputeResolution::RefundCreator,
+                bps => DisputeResolution::PercentageSplit(bps),
+            };
+            let pool = amount - fee;
+            let contributor = share(pool, contributor_bps);
+            (
+                assigned(scenario)
+                    .step(dispute(party, "task1"))
+                    .fails(dispute(party, "task1"), Error::InvalidTaskStatus)
+                    .step(resolve("task1", resolution))
+                    .fails(resolve_stale("task1"), Error::TaskAlreadyResolved),
+                [amount + pool - contributor, contributor, fee],
+                TaskStatus::Completed,
+            )
+        }
+        Path::WithdrawThenApprove(party) => (
+            assigned(scenario)
+                .step(dispute(party, "task1"))
+                .step(withdraw(party, "task1"))
+                .fails(withdraw(party, "task1"), Error::TaskNotDisputed)
+                .step(approve("task1")),
+            [amount, amount, 0],
+            TaskStatus::Completed,
+        ),
+        Path::Stale(policy) => {
+            let contributor = match policy {
+                DisputeTimeoutPolicy::PayContributor => amount,
+                DisputeTimeoutPolicy::RefundCreator => 0,
+                DisputeTimeoutPolicy::SplitEvenly => share(amount, 5_000),
+            };
+            (
+                assigned(scenario)
+                    .step(set_timeout(TestConfig::ONE_DAY, policy))
+                    .step(dispute("bob", "task1"))
+                    .fails(resolve_stale("task1"), Error::DisputeNotExpired)
+                    .step(advance(TestConfig::ONE_DAY))
+                    .step(resolve_stale("task1")),
+                [amount * 2 - contributor, contributor, 0],
+                TaskStatus::Completed,
+            )
+        }
+        Path::AdjustThenApprove => (
+            scenario
+                .step(increase("alice", "task1", amount))
+                .fails(decrease("alice", "task1", amount * 2), Error::InvalidAmount)
+                .step(decrease("alice", "task1", amount / 2))
+                .step(assign("task1", "bob"))
+                .fails(increase("bob", "task1", amount), Error::NotTaskCreator)
+                .step(approve("task1")),
+            [amount / 2, amount + amount / 2, 0],
+            TaskStatus::Completed,
+        ),
+    }
+}
+
+#[test]
+fn test_scenario_matrix() {
+    let mut count = 0;
+    for amount in TestScenarios::standard_amounts() {
+        for fee_bps in [0, 250] {
+            for path in PATHS {
+                let context = format!("{path:?} amount {amount} fee {fee_bps}");
+                let (scenario, [alice, bob, arbiter_fee], status) =
+                    matrix_scenario(path, amount, fee_bps);
+                let outcome = scenario.run();
+
+                assert_eq!(outcome.escrow("task1").status, status, "{context}");
+                assert_eq!(outcome.balance("alice"), alice, "{context}");
+                assert_eq!(outcome.balance("bob"), bob, "{context}");
+                assert_eq!(
+                    outcome
+                        .client
+                        .get_arbiter_balance(&outcome.client.get_admin()),
+                    arbiter_fee,
+                    "{context}"
+                );
+
+                // Nothing but unclaimed fees stays in the contract once the task is closed
+                assert_eq!(outcome.contract_balance(), arbiter_fee, "{context}");
+                assert_eq!(alice + bob + arbiter_fee, amount * 2, "{context}");
+                count += 1;
+            }
+        }
+    }
+    assert_eq!(count, 140);
+}
@@ -21,7 +21,7 @@ pub fn create_test_env() -> (
     let usdc_address = sac.address();
     let usdc_token = StellarAssetClient::new(&env, &usdc_address);
     let usdc_token_client = TokenClient::new(&env, &usdc_address);
-    let contract_id = env.register(TaskEscrowContract, ());
+    let contract_id = env.register(TaskEscrowContract, (&admin,));
     let client = TaskEscrowContractClient::new(&env, &contract_id);
 
     (
@@ -44,6 +44,5 @@ mod tests {
         let (_env, _admin, _usdc_address, _usdc_token, _usdc_token_client, _contract_id, _client) =
             create_test_env();
         // Basic test to ensure setup works
-        assert!(true);
     }
 }
@@ -0,0 +1,258 @@
+use devasign_task_escrow::{Error, EscrowOptions, Forge, IssueRef};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, BytesN, String, Symbol, TryIntoVal, Val,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+#[test]
+fn test_update_task_metadata_success() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "metadata_edit", 1);
+    let labels_hash = BytesN::from_array(&env, &[3; 32]);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 1),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    // The new URL is canonicalized and parsed like at creation
+    client.update_task_metadata(
+        &creator,
+        &task_id,
+        &String::from_str(&env, "https://www.github.com/DevAsign/Website/issues/9/"),
+        &Some(labels_hash.clone()),
+    );
+
+    // The event carries the old and new values
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| event.0 == contract_id)
+        .last()
+        .unwrap();
+    let data: soroban_sdk::Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let field = |name: &str| data.get(Symbol::new(&env, name)).unwrap();
+    let old_url: String = field("old_issue_url").try_into_val(&env).unwrap();
+    let new_url: String = field("new_issue_url").try_into_val(&env).unwrap();
+    let old_hash: Option<BytesN<32>> = field("old_labels_hash").try_into_val(&env).unwrap();
+    let new_hash: Option<BytesN<32>> = field("new_labels_hash").try_into_val(&env).unwrap();
+    assert_eq!(old_url, TestValidation::repo_url(&env, "contracts", 1));
+    assert_eq!(new_url, TestValidation::repo_url(&env, "website", 9));
+    assert_eq!(old_hash, None);
+    assert_eq!(new_hash, Some(labels_hash.clone()));
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(
+        escrow.issue_url,
+        TestValidation::repo_url(&env, "website", 9)
+    );
+    assert_eq!(
+        escrow.issue,
+        IssueRef {
+            forge: Forge::GitHub,
+            owner: String::from_str(&env, "devasign"),
+            repo: String::from_str(&env, "website"),
+            number: 9,
+            labels_hash: Some(labels_hash),
+        }
+    );
+}
+
+#[test]
+fn test_update_task_metadata_moves_indexes() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "metadata_move", 1);
+    let other_id = TestValidation::generate_task_id(&env, "metadata_move", 2);
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 1),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    client.update_task_metadata(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "website", 2),
+        &None,
+    );
+
+    // The issue lookup follows the task and the old issue is released
+    assert_eq!(
+        client
+            .get_escrow_by_issue(&TestValidation::repo_url(&env, "website", 2))
+            .task_id,
+        task_id
+    );
+    let result = client.try_get_escrow_by_issue(&TestValidation::repo_url(&env, "contracts", 1));
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+    client.create_escrow(
+        &creator,
+        &other_id,
+        &TestValidation::repo_url(&env, "contracts", 1),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    // The old repository no longer lists the task
+    let owner = String::from_str(&env, "devasign");
+    let contracts = String::from_str(&env, "contracts");
+    let website = String::from_str(&env, "website");
+    let listed = client.list_escrows_by_repo(&owner, &contracts, &0);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().task_id, other_id);
+    assert_eq!(client.list_escrows_by_repo(&owner, &website, &0).len(), 1);
+
+    // Moving back lists the task once
+    client.update_task_metadata(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 3),
+        &None,
+    );
+    client.update_task_metadata(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 4),
+        &None,
+    );
+    assert_eq!(client.list_escrows_by_repo(&owner, &contracts, &0).len(), 2);
+    assert!(client.list_escrows_by_repo(&owner, &website, &0).is_empty());
+}
+
+#[test]
+fn test_update_task_metadata_rejects_escrowed_issue() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "metadata_dup", 1);
+    let other_id = TestValidation::generate_task_id(&env, "metadata_dup", 2);
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+    for (id, number) in [(&task_id, 1), (&other_id, 2)] {
+        client.create_escrow(
+            &creator,
+            id,
+            &TestValidation::repo_url(&env, "contracts", number),
+            &TestConfig::MEDIUM_AMOUNT,
+        );
+    }
+
+    let result = client.try_update_task_metadata(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 2),
+        &None,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::IssueAlreadyEscrowed);
+
+    // Once the other escrow is refunded its issue can be taken over
+    client.refund(&other_id);
+    client.update_task_metadata(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 2),
+        &None,
+    );
+    assert_eq!(
+        client
+            .get_escrow_by_issue(&TestValidation::repo_url(&env, "contracts", 2))
+            .task_id,
+        task_id
+    );
+
+    // Keeping the same issue only replaces the labels hash
+    let labels_hash = Some(BytesN::from_array(&env, &[5; 32]));
+    client.update_task_metadata(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 2),
+        &labels_hash,
+    );
+    assert_eq!(client.get_escrow(&task_id).issue.labels_hash, labels_hash);
+}
+
+#[test]
+fn test_update_task_metadata_validation() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "metadata_invalid", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::repo_url(&env, "contracts", 1),
+        &TestConfig::MEDIUM_AMOUNT,
+        &EscrowOptions {
+            labels_hash: Some(BytesN::from_array(&env, &[1; 32])),
+            tags: soroban_sdk::Vec::new(&env),
+            difficulty: devasign_task_escrow::Difficulty::Unrated,
+        },
+    );
+    let new_url = TestValidation::repo_url(&env, "contracts", 2);
+
+    let result = client.try_update_task_metadata(
+        &creator,
+        &task_id,
+        &String::from_str(&env, "https://github.com/devasign/contracts/pull/2"),
+        &None,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidIssueUrl);
+    let result = client.try_update_task_metadata(&contributor, &task_id, &new_url, &None);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NotTaskCreator);
+    let result = client.try_update_task_metadata(
+        &creator,
+        &TestValidation::generate_task_id(&env, "metadata_missing", 1),
+        &new_url,
+        &None,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+
+    // Metadata is frozen once work starts
+    client.assign_contributor(&task_id, &contributor);
+    let result = client.try_update_task_metadata(&creator, &task_id, &new_url, &None);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+    client.approve_completion(&task_id);
+    let result = client.try_update_task_metadata(&creator, &task_id, &new_url, &None);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(
+        escrow.issue_url,
+        TestValidation::repo_url(&env, "contracts", 1)
+    );
+    assert_eq!(
+        escrow.issue.labels_hash,
+        Some(BytesN::from_array(&env, &[1; 32]))
+    );
+}
@@ -0,0 +1,298 @@
+use devasign_task_escrow::{DisputeResolution, Error, TaskStatus};
+use soroban_sdk::{testutils::Address as _, Address};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+#[test]
+fn test_transfer_task_ownership_success() {
+    let (env, admin, usdc_address, usdc_token, usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let new_creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    usdc_token.mint(&new_creator, &TestConfig::SMALL_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    // The current creator keeps control until the nominee accepts
+    client.transfer_task_ownership(&task_id, &new_creator);
+    assert_eq!(client.get_pending_task_owner(&task_id), new_creator);
+    assert_eq!(client.get_escrow(&task_id).creator, creator);
+
+    client.accept_task_ownership(&task_id);
+    assert_eq!(client.get_escrow(&task_id).creator, new_creator);
+    let result = client.try_get_pending_task_owner(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+
+    // Only the new creator can adjust the bounty
+    let result = client.try_increase_bounty(&creator, &task_id, &TestConfig::SMALL_AMOUNT);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NotTaskCreator);
+    client.increase_bounty(&new_creator, &task_id, &TestConfig::SMALL_AMOUNT);
+
+    // The whole bounty is refunded to the new creator
+    client.refund(&task_id);
+    assert_eq!(
+        usdc_token_client.balance(&new_creator),
+        TestConfig::MEDIUM_AMOUNT + TestConfig::SMALL_AMOUNT
+    );
+    assert_eq!(usdc_token_client.balance(&creator), 0);
+}
+
+#[test]
+fn test_transfer_task_ownership_replaces_nominee() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership_renominate", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.assign_contributor(&task_id, &contributor);
+
+    // A later nomination replaces the earlier one
+    client.transfer_task_ownership(&task_id, &first);
+    client.transfer_task_ownership(&task_id, &second);
+    assert_eq!(client.get_pending_task_owner(&task_id), second);
+
+    // In-progress tasks change hands and the new creator approves the work
+    client.accept_task_ownership(&task_id);
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.creator, second);
+    assert_eq!(escrow.contributor, Some(contributor));
+    client.approve_completion(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, TaskStatus::Completed);
+}
+
+#[test]
+fn test_transfer_task_ownership_validation() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership_invalid", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.assign_contributor(&task_id, &contributor);
+
+    for new_creator in [&creator, &contributor, &contract_id] {
+        let result = client.try_transfer_task_ownership(&task_id, new_creator);
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+    }
+    let result = client.try_transfer_task_ownership(
+        &TestValidation::generate_task_id(&env, "ownership_missing", 1),
+        &Address::generate(&env),
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+    let result = client.try_accept_task_ownership(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+}
+
+#[test]
+fn test_transfer_task_ownership_nominee_cannot_be_contributor() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let nominee = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership_self_assign", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    // The nominee cannot be assigned the task it is about to own
+    client.transfer_task_ownership(&task_id, &nominee);
+    let result = client.try_assign_contributor(&task_id, &nominee);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidAddress);
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.status, TaskStatus::Open);
+    assert_eq!(escrow.contributor, None);
+
+    // Anyone else can still be assigned and the nominee still accepts
+    client.assign_contributor(&task_id, &contributor);
+    client.accept_task_ownership(&task_id);
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.creator, nominee);
+    assert_eq!(escrow.contributor, Some(contributor));
+}
+
+#[test]
+fn test_transfer_task_ownership_blocked_by_dispute_and_resolution() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let new_creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership_dispute", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    client.assign_contributor(&task_id, &contributor);
+
+    // A nomination made before a dispute cannot be accepted during it
+    client.transfer_task_ownership(&task_id, &new_creator);
+    let reason = TestValidation::generate_dispute_reason(&env, "quality");
+    client.dispute_task(&contributor, &task_id, &reason);
+    let result = client.try_accept_task_ownership(&task_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+    let result = client.try_transfer_task_ownership(&task_id, &new_creator);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+
+    // Resolving the task drops the nomination
+    client.resolve_dispute(&task_id, &DisputeResolution::RefundCreator);
+    let result = client.try_get_pending_task_owner(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+    let result = client.try_accept_task_ownership(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+    let result = client.try_transfer_task_ownership(&task_id, &new_creator);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
+    assert_eq!(client.get_escrow(&task_id).creator, creator);
+}
+
+#[test]
+fn test_cancel_task_ownership_transfer() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let new_creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "ownership_cancel", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow(
+        &creator,
+        &task_id,
+        &TestValidation::dummy_issue_url(&env),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+
+    // Nothing to withdraw yet
+    let result = client.try_cancel_task_ownership_transfer(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+
+    // A withdrawn nomination can no longer be accepted
+    client.transfer_task_ownership(&task_id, &new_creator);
+    client.cancel_task_ownership_transfer(&task_id);
+    let result = client.try_get_pending_task_owner(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+    let result = client.try_accept_task_ownership(&task_id);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        Error::NoPendingOwnershipTransfer
+    );
+    assert_eq!(client.get_escrow(&task_id).creator, creator);
+
+    // The nominee can also be assigned once the nomination is withdrawn
+    client.assign_contributor(&task_id, &new_creator);
+    assert_eq!(client.get_escrow(&task_id).contributor, Some(new_creator));
+}
+
+#[test]
+fn test_resolution_drops_pending_task_owner() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let new_creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let completed_id = TestValidation::generate_task_id(&env, "ownership_completed", 1);
+    let refunded_id = TestValidation::generate_task_id(&env, "ownership_refunded", 1);
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+    for (task_id, number) in [(&completed_id, 1), (&refunded_id, 2)] {
+        client.create_escrow(
+            &creator,
+            task_id,
+            &TestValidation::issue_url(&env, number),
+            &TestConfig::MEDIUM_AMOUNT,
+        );
+        client.transfer_task_ownership(task_id, &new_creator);
+    }
+
+    client.assign_contributor(&completed_id, &contributor);
+    client.approve_completion(&completed_id);
+    client.refund(&refunded_id);
+
+    for task_id in [&completed_id, &refunded_id] {
+        let result = client.try_get_pending_task_owner(task_id);
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            Error::NoPendingOwnershipTransfer
+        );
+        let result = client.try_cancel_task_ownership_transfer(task_id);
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            Error::NoPendingOwnershipTransfer
+        );
+    }
+}
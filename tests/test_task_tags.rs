@@ -0,0 +1,294 @@
+use devasign_task_escrow::{Difficulty, Error, EscrowOptions, TaskStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, Symbol, TryIntoVal, Val, Vec,
+};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+fn tagged(env: &Env, tags: &[&str], difficulty: Difficulty) -> EscrowOptions {
+    let mut symbols = Vec::new(env);
+    for tag in tags {
+        symbols.push_back(Symbol::new(env, tag));
+    }
+    EscrowOptions {
+        labels_hash: None,
+        tags: symbols,
+        difficulty,
+    }
+}
+
+#[test]
+fn test_tags_and_difficulty_stored_at_creation() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "tags", 1);
+    let untagged_id = TestValidation::generate_task_id(&env, "tags", 2);
+    usdc_token.mint(&creator, &(TestConfig::MEDIUM_AMOUNT * 2));
+
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::issue_url(&env, 1),
+        &TestConfig::MEDIUM_AMOUNT,
+        &tagged(&env, &["rust", "soroban"], Difficulty::Intermediate),
+    );
+
+    // The creation event carries the tags and difficulty
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| event.0 == contract_id)
+        .last()
+        .unwrap();
+    let data: soroban_sdk::Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let tags: Vec<Symbol> = data
+        .get(symbol_short!("tags"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(
+        tags,
+        vec![&env, symbol_short!("rust"), symbol_short!("soroban")]
+    );
+
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.tags, tags);
+    assert_eq!(escrow.difficulty, Difficulty::Intermediate);
+
+    // Escrows created without options are untagged and unrated
+    client.create_escrow(
+        &creator,
+        &untagged_id,
+        &TestValidation::issue_url(&env, 2),
+        &TestConfig::MEDIUM_AMOUNT,
+    );
+    let escrow = client.get_escrow(&untagged_id);
+    assert!(escrow.tags.is_empty());
+    assert_eq!(escrow.difficulty, Difficulty::Unrated);
+}
+
+#[test]
+fn test_create_escrow_rejects_invalid_tags() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "bad_tags", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+
+    for tags in [
+        &["a", "b", "c", "d", "e", "f"][..],
+        &["rust", "rust"],
+        &["Rust"],
+        &["toolongtag"],
+    ] {
+        let result = client.try_create_escrow_with_options(
+            &creator,
+            &task_id,
+            &TestValidation::issue_url(&env, 1),
+            &TestConfig::MEDIUM_AMOUNT,
+            &tagged(&env, tags, Difficulty::Beginner),
+        );
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTags);
+    }
+
+    // Five distinct tags of up to nine characters are accepted
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::issue_url(&env, 1),
+        &TestConfig::MEDIUM_AMOUNT,
+        &tagged(
+            &env,
+            &["a", "b", "c", "d", "nine_char"],
+            Difficulty::Beginner,
+        ),
+    );
+}
+
+#[test]
+fn test_update_task_tags_while_open() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "edit_tags", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::issue_url(&env, 1),
+        &TestConfig::MEDIUM_AMOUNT,
+        &tagged(&env, &["rust"], Difficulty::Beginner),
+    );
+
+    // Only the creator may edit the tags
+    let tags = vec![&env, symbol_short!("wasm")];
+    let result = client.try_update_task_tags(&contributor, &task_id, &tags, &Difficulty::Advanced);
+    assert_eq!(result.unwrap_err().unwrap(), Error::NotTaskCreator);
+    let result = client.try_update_task_tags(
+        &creator,
+        &task_id,
+        &vec![&env, symbol_short!("wasm"), symbol_short!("wasm")],
+        &Difficulty::Advanced,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTags);
+
+    client.update_task_tags(&creator, &task_id, &tags, &Difficulty::Advanced);
+    let escrow = client.get_escrow(&task_id);
+    assert_eq!(escrow.tags, tags);
+    assert_eq!(escrow.difficulty, Difficulty::Advanced);
+
+    // Tags are frozen once work starts
+    client.assign_contributor(&task_id, &contributor);
+    let result = client.try_update_task_tags(&creator, &task_id, &tags, &Difficulty::Beginner);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTaskStatus);
+    client.approve_completion(&task_id);
+    assert_eq!(client.get_escrow(&task_id).status, TaskStatus::Completed);
+    let result = client.try_update_task_tags(&creator, &task_id, &tags, &Difficulty::Beginner);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskAlreadyResolved);
+}
+
+#[test]
+fn test_update_task_tags_task_not_found() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let result = client.try_update_task_tags(
+        &Address::generate(&env),
+        &TestValidation::generate_task_id(&env, "missing", 1),
+        &Vec::new(&env),
+        &Difficulty::Unrated,
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskNotFound);
+}
+
+#[test]
+fn test_list_escrows_by_tag_pages() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    usdc_token.mint(&creator, &(TestConfig::SMALL_AMOUNT * 30));
+
+    // 22 escrows tagged rust, every other one also tagged docs
+    for index in 1..=22 {
+        let tags: &[&str] = if index % 2 == 0 {
+            &["rust", "docs"]
+        } else {
+            &["rust"]
+        };
+        client.create_escrow_with_options(
+            &creator,
+            &TestValidation::generate_task_id(&env, "tag_page", index),
+            &TestValidation::issue_url(&env, index),
+            &TestConfig::SMALL_AMOUNT,
+            &tagged(&env, tags, Difficulty::Unrated),
+        );
+    }
+
+    let rust = symbol_short!("rust");
+    let first = client.list_escrows_by_tag(&rust, &0);
+    assert_eq!(first.len(), 20);
+    assert_eq!(
+        first.get(0).unwrap().task_id,
+        TestValidation::generate_task_id(&env, "tag_page", 1)
+    );
+    let second = client.list_escrows_by_tag(&rust, &1);
+    assert_eq!(second.len(), 2);
+    assert_eq!(
+        second.get(1).unwrap().task_id,
+        TestValidation::generate_task_id(&env, "tag_page", 22)
+    );
+    assert!(client.list_escrows_by_tag(&rust, &u32::MAX).is_empty());
+    assert_eq!(
+        client.list_escrows_by_tag(&symbol_short!("docs"), &0).len(),
+        11
+    );
+    assert!(client
+        .list_escrows_by_tag(&symbol_short!("go"), &0)
+        .is_empty());
+}
+
+#[test]
+fn test_list_escrows_by_tag_follows_edits() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let creator = Address::generate(&env);
+    let task_id = TestValidation::generate_task_id(&env, "tag_edit", 1);
+    usdc_token.mint(&creator, &TestConfig::MEDIUM_AMOUNT);
+    client.create_escrow_with_options(
+        &creator,
+        &task_id,
+        &TestValidation::issue_url(&env, 1),
+        &TestConfig::MEDIUM_AMOUNT,
+        &tagged(&env, &["rust"], Difficulty::Unrated),
+    );
+
+    // Removed tags no longer list the task
+    let wasm = vec![&env, symbol_short!("wasm")];
+    client.update_task_tags(&creator, &task_id, &wasm, &Difficulty::Unrated);
+    assert!(client
+        .list_escrows_by_tag(&symbol_short!("rust"), &0)
+        .is_empty());
+    assert_eq!(
+        client.list_escrows_by_tag(&symbol_short!("wasm"), &0).len(),
+        1
+    );
+
+    // Re-adding a tag lists the task once
+    let both = vec![&env, symbol_short!("rust"), symbol_short!("wasm")];
+    client.update_task_tags(&creator, &task_id, &both, &Difficulty::Unrated);
+    client.update_task_tags(&creator, &task_id, &both, &Difficulty::Unrated);
+    assert_eq!(
+        client.list_escrows_by_tag(&symbol_short!("rust"), &0).len(),
+        1
+    );
+    assert_eq!(
+        client.list_escrows_by_tag(&symbol_short!("wasm"), &0).len(),
+        1
+    );
+}
+
+#[test]
+fn test_list_escrows_by_tag_rejects_invalid_tag() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    for tag in ["", "Rust", "toolongtag"] {
+        let result = client.try_list_escrows_by_tag(&Symbol::new(&env, tag), &0);
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTags);
+    }
+}
@@ -0,0 +1,212 @@
+use devasign_task_escrow::{Config, DisputeResolution, Error};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+mod test_config;
+mod test_setup;
+
+use test_config::{TestConfig, TestValidation};
+use test_setup::create_test_env;
+
+fn default_config() -> Config {
+    Config {
+        min_amount: 100_000,
+        max_amount: 1_000_000_000 * 10_000_000,
+        task_id_min_length: 25,
+        task_id_max_length: 25,
+        dispute_reason_min_length: 10,
+        dispute_reason_max_length: 500,
+        issue_url_max_length: 192,
+    }
+}
+
+#[test]
+fn test_default_config() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    assert_eq!(client.get_config(), default_config());
+}
+
+#[test]
+fn test_update_config() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let config = Config {
+        min_amount: 1,
+        max_amount: 1_000_000_000_000,
+        task_id_min_length: 8,
+        task_id_max_length: 36,
+        dispute_reason_min_length: 1,
+        dispute_reason_max_length: 1_000,
+        issue_url_max_length: 150,
+    };
+    client.update_config(&config);
+    assert_eq!(client.get_config(), config);
+}
+
+#[test]
+fn test_update_config_rejects_inconsistent_limits() {
+    let (_env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    let invalid_configs = [
+        Config {
+            min_amount: 0,
+            ..default_config()
+        },
+        Config {
+            max_amount: 99_999,
+            ..default_config()
+        },
+        Config {
+            task_id_min_length: 0,
+            ..default_config()
+        },
+        Config {
+            task_id_max_length: 24,
+            ..default_config()
+        },
+        Config {
+            dispute_reason_min_length: 0,
+            ..default_config()
+        },
+        Config {
+            dispute_reason_min_length: 501,
+            ..default_config()
+        },
+        Config {
+            issue_url_max_length: 0,
+            ..default_config()
+        },
+        Config {
+            issue_url_max_length: 193,
+            ..default_config()
+        },
+    ];
+
+    for config in invalid_configs.iter() {
+        let result = client.try_update_config(config);
+        assert_eq!(result.unwrap_err().unwrap(), Error::InvalidConfig);
+    }
+
+    // Rejected updates leave the limits unchanged
+    assert_eq!(client.get_config(), default_config());
+}
+
+#[test]
+fn test_update_config_unauthorized() {
+    let (env, admin, usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract
+    client.initialize(&admin, &usdc_address);
+
+    // Clear mocked auths to enforce strict checking
+    env.mock_auths(&[]);
+
+    let result = client.try_update_config(&Config {
+        task_id_max_length: 36,
+        ..default_config()
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_update_config_not_initialized() {
+    let (_env, _admin, _usdc_address, _usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    let result = client.try_update_config(&default_config());
+    assert_eq!(result.unwrap_err().unwrap(), Error::ContractNotInitialized);
+}
+
+#[test]
+fn test_custom_limits_are_enforced() {
+    let (env, admin, usdc_address, usdc_token, _usdc_token_client, _contract_id, client) =
+        create_test_env();
+
+    // Initialize contract with UUID task IDs, short URLs and a 100 USDC minimum
+    client.initialize(&admin, &usdc_address);
+    client.update_config(&Config {
+        min_amount: TestConfig::SMALL_AMOUNT,
+        max_amount: TestConfig::LARGE_AMOUNT,
+        task_id_min_length: 36,
+        task_id_max_length: 36,
+        dispute_reason_min_length: 20,
+        dispute_reason_max_length: 40,
+        issue_url_max_length: 60,
+    });
+
+    let creator = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    usdc_token.mint(&creator, &(TestConfig::LARGE_AMOUNT * 2));
+    let issue_url = TestValidation::dummy_issue_url(&env);
+
+    // The default cuid length no longer fits
+    let cuid = TestValidation::generate_task_id(&env, "limits", 1);
+    let result = client.try_create_escrow(&creator, &cuid, &issue_url, &TestConfig::SMALL_AMOUNT);
+    assert_eq!(result.unwrap_err().unwrap(), Error::TaskIdTooShort);
+
+    let task_id = String::from_str(&env, "4f9c1a2e-8b7d-4c3f-9e6a-1d2b3c4d5e6f");
+
+    // Amounts outside the configured range
+    let result = client.try_create_escrow(
+        &creator,
+        &task_id,
+        &issue_url,
+        &(TestConfig::SMALL_AMOUNT - 1),
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::AmountTooSmall);
+    let result = client.try_create_escrow(
+        &creator,
+        &task_id,
+        &issue_url,
+        &(TestConfig::LARGE_AMOUNT + 1),
+    );
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidTokenAmount);
+
+    // URLs longer than the configured maximum
+    let long_url = String::from_str(
+        &env,
+        "https://github.com/devasign/task-escrow-contract/issues/1234567",
+    );
+    let result = client.try_create_escrow(&creator, &task_id, &long_url, &TestConfig::SMALL_AMOUNT);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidIssueUrl);
+
+    client.create_escrow(&creator, &task_id, &issue_url, &TestConfig::SMALL_AMOUNT);
+    client.assign_contributor(&task_id, &contributor);
+
+    // Dispute reasons outside the configured range
+    for (reason, error) in [
+        ("Too short to count", Error::DisputeReasonTooShort),
+        (
+            "This dispute reason is far too long for the configured limit",
+            Error::InvalidDisputeReason,
+        ),
+    ] {
+        let result = client.try_dispute_task(&creator, &task_id, &String::from_str(&env, reason));
+        assert_eq!(result.unwrap_err().unwrap(), error);
+    }
+    client.dispute_task(
+        &creator,
+        &task_id,
+        &String::from_str(&env, "Work does not match the issue"),
+    );
+
+    // Length limits only apply on creation, so narrowing the range leaves
+    // escrows created under the old limits reachable
+    client.update_config(&default_config());
+    assert_eq!(client.get_escrow(&task_id).task_id, task_id);
+    client.resolve_dispute(&task_id, &DisputeResolution::RefundCreator);
+    assert_eq!(usdc_token.balance(&creator), TestConfig::LARGE_AMOUNT * 2);
+}